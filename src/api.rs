@@ -0,0 +1,594 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Typed DynamoDB control plane APIs that can be used when dynein is embedded as a library.
+//!
+//! Unlike functions for the `dy` command, functions in this module don't depend on dynein's configuration files,
+//! don't print anything, and never exit the process. They take an explicit [`SdkConfig`] (i.e. region and credentials)
+//! and return results of the APIs as they are.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = dynein::api::sdk_config("us-west-2", Some("my-profile")).await;
+//! for table_name in dynein::api::list_tables(&config).await? {
+//!     let desc = dynein::api::describe_table(&config, &table_name).await?;
+//!     println!("{}: {:?}", table_name, desc.table_status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use aws_config::{meta::region::RegionProviderChain, BehaviorVersion, Region, SdkConfig};
+use aws_sdk_dynamodb::{
+    error::SdkError,
+    operation::{
+        create_backup::{CreateBackupError, CreateBackupInput},
+        create_table::{CreateTableError, CreateTableInput},
+        delete_backup::{DeleteBackupError, DeleteBackupInput},
+        describe_backup::DescribeBackupError,
+        describe_continuous_backups::DescribeContinuousBackupsError,
+        describe_contributor_insights::DescribeContributorInsightsError,
+        describe_table::DescribeTableError,
+        describe_time_to_live::DescribeTimeToLiveError,
+        list_backups::ListBackupsError,
+        list_tables::ListTablesError,
+        list_tags_of_resource::ListTagsOfResourceError,
+        restore_table_from_backup::{RestoreTableFromBackupError, RestoreTableFromBackupInput},
+        restore_table_to_point_in_time::{
+            RestoreTableToPointInTimeError, RestoreTableToPointInTimeInput,
+        },
+        tag_resource::{TagResourceError, TagResourceInput},
+        update_continuous_backups::{UpdateContinuousBackupsError, UpdateContinuousBackupsInput},
+        update_contributor_insights::{
+            UpdateContributorInsightsError, UpdateContributorInsightsInput,
+        },
+        update_time_to_live::{UpdateTimeToLiveError, UpdateTimeToLiveInput},
+    },
+    primitives::DateTime,
+    types::{
+        AttributeDefinition, BackupDescription, BackupDetails, BackupSummary, BillingMode,
+        ContinuousBackupsDescription, ContributorInsightsAction, ContributorInsightsStatus,
+        GlobalSecondaryIndex, KeySchemaElement, PointInTimeRecoverySpecification,
+        ProvisionedThroughput, SseSpecification, TableDescription, Tag, TimeToLiveDescription,
+        TimeToLiveSpecification,
+    },
+    Client as DynamoDbSdkClient,
+};
+
+/// Readable summary of a table printed by `dy desc`, which can be built from the result of [`describe_table`] by [`table_summary`].
+pub use crate::ddb::table::{
+    table_summary, IncompleteDescriptionError, Mode, PrintCapacity, PrintCapacityUnits,
+    PrintDescribeTable, PrintPrimaryKeys, PrintSecondaryIndex,
+};
+
+/* =================================================
+Public functions
+================================================= */
+
+/// Loads SdkConfig for the given region, optionally with an AWS named profile.
+/// Credentials are resolved by the default credential provider chain of the AWS SDK.
+pub async fn sdk_config(region: &str, profile: Option<&str>) -> SdkConfig {
+    let provider = RegionProviderChain::first_try(Region::new(region.to_owned()));
+    let mut loader = aws_config::defaults(BehaviorVersion::v2024_03_28()).region(provider);
+    if let Some(profile) = profile {
+        loader = loader.profile_name(profile);
+    }
+    loader.load().await
+}
+
+/// Returns names of all tables in the region. [API: ListTables]
+/// As ListTables API returns up to 100 tables at once, it is called repeatedly until all tables are retrieved.
+pub async fn list_tables(config: &SdkConfig) -> Result<Vec<String>, SdkError<ListTablesError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    let mut table_names = Vec::<String>::new();
+    let mut exclusive_start_table_name: Option<String> = None;
+    loop {
+        let res = ddb
+            .list_tables()
+            .set_exclusive_start_table_name(exclusive_start_table_name)
+            .send()
+            .await?;
+        // ListTables API returns blank array even if no table exists in a region.
+        table_names.append(&mut res.table_names.unwrap_or_default());
+        match res.last_evaluated_table_name {
+            None => return Ok(table_names),
+            last_evaluated_table_name => exclusive_start_table_name = last_evaluated_table_name,
+        }
+    }
+}
+
+/// Returns TableDescription of the table. [API: DescribeTable]
+pub async fn describe_table(
+    config: &SdkConfig,
+    table_name: &str,
+) -> Result<TableDescription, SdkError<DescribeTableError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.describe_table()
+        .table_name(table_name)
+        .send()
+        .await
+        .map(|res| {
+            res.table
+                .expect("Table Description returned from API should be valid.")
+        })
+}
+
+/// Creates a table with the given key schema. [API: CreateTable]
+/// The table is created in Provisioned mode when provisioned_throughput is given, otherwise in OnDemand mode.
+/// Unless sse_specification is given, the table is encrypted with an AWS owned key (i.e. the default of DynamoDB).
+/// KeySchemaElement(s) and AttributeDefinition(s) can be generated by `ddb::table::generate_essential_key_definitions`.
+pub async fn create_table(
+    config: &SdkConfig,
+    table_name: &str,
+    key_schema: Vec<KeySchemaElement>,
+    attribute_definitions: Vec<AttributeDefinition>,
+    provisioned_throughput: Option<ProvisionedThroughput>,
+    sse_specification: Option<SseSpecification>,
+    tags: Option<Vec<Tag>>,
+) -> Result<TableDescription, SdkError<CreateTableError>> {
+    let input = create_table_input(
+        table_name,
+        key_schema,
+        attribute_definitions,
+        provisioned_throughput,
+        sse_specification,
+        tags,
+    );
+    create_table_with_input(config, input).await
+}
+
+/// Generates CreateTableInput sent by [`create_table`], e.g. to review the request before creating the table.
+pub fn create_table_input(
+    table_name: &str,
+    key_schema: Vec<KeySchemaElement>,
+    attribute_definitions: Vec<AttributeDefinition>,
+    provisioned_throughput: Option<ProvisionedThroughput>,
+    sse_specification: Option<SseSpecification>,
+    tags: Option<Vec<Tag>>,
+) -> CreateTableInput {
+    let billing_mode = if provisioned_throughput.is_some() {
+        BillingMode::Provisioned
+    } else {
+        BillingMode::PayPerRequest
+    };
+    CreateTableInput::builder()
+        .table_name(table_name)
+        .billing_mode(billing_mode)
+        .set_provisioned_throughput(provisioned_throughput)
+        .set_sse_specification(sse_specification)
+        .set_tags(tags)
+        .set_key_schema(Some(key_schema))
+        .set_attribute_definitions(Some(attribute_definitions))
+        .build()
+        .expect("CreateTableInput should be valid")
+}
+
+/// Creates a table with the given CreateTableInput, e.g. one generated from an existing table's description. [API: CreateTable]
+/// Key schema, indexes, capacity, stream and SSE settings, and tags in the input are used.
+pub async fn create_table_with_input(
+    config: &SdkConfig,
+    input: CreateTableInput,
+) -> Result<TableDescription, SdkError<CreateTableError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.create_table()
+        .set_table_name(input.table_name)
+        .set_billing_mode(input.billing_mode)
+        .set_provisioned_throughput(input.provisioned_throughput)
+        .set_key_schema(input.key_schema)
+        .set_attribute_definitions(input.attribute_definitions)
+        .set_global_secondary_indexes(input.global_secondary_indexes)
+        .set_local_secondary_indexes(input.local_secondary_indexes)
+        .set_stream_specification(input.stream_specification)
+        .set_sse_specification(input.sse_specification)
+        .set_tags(input.tags)
+        .send()
+        .await
+        .map(|res| {
+            res.table_description
+                .expect("Table Description returned from API should be valid.")
+        })
+}
+
+/// Starts creating an on-demand backup of the table. [API: CreateBackup]
+pub async fn create_backup(
+    config: &SdkConfig,
+    table_name: &str,
+    backup_name: &str,
+) -> Result<BackupDetails, SdkError<CreateBackupError>> {
+    let input = create_backup_input(table_name, backup_name);
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.create_backup()
+        .set_table_name(input.table_name)
+        .set_backup_name(input.backup_name)
+        .send()
+        .await
+        .map(|res| res.backup_details.expect("should have some details"))
+}
+
+/// Generates CreateBackupInput sent by [`create_backup`].
+pub fn create_backup_input(table_name: &str, backup_name: &str) -> CreateBackupInput {
+    CreateBackupInput::builder()
+        .table_name(table_name)
+        .backup_name(backup_name)
+        .build()
+        .expect("CreateBackupInput should be valid")
+}
+
+/// Deletes the backup. [API: DeleteBackup]
+pub async fn delete_backup(
+    config: &SdkConfig,
+    backup_arn: &str,
+) -> Result<BackupDescription, SdkError<DeleteBackupError>> {
+    let input = delete_backup_input(backup_arn);
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.delete_backup()
+        .set_backup_arn(input.backup_arn)
+        .send()
+        .await
+        .map(|res| {
+            res.backup_description
+                .expect("Backup Description returned from API should be valid.")
+        })
+}
+
+/// Generates DeleteBackupInput sent by [`delete_backup`].
+pub fn delete_backup_input(backup_arn: &str) -> DeleteBackupInput {
+    DeleteBackupInput::builder()
+        .backup_arn(backup_arn)
+        .build()
+        .expect("DeleteBackupInput should be valid")
+}
+
+/// Returns backups of the table, or all backups in the region when table_name is None. [API: ListBackups]
+/// ListBackups API is called repeatedly until all backups are retrieved.
+pub async fn list_backups(
+    config: &SdkConfig,
+    table_name: Option<&str>,
+) -> Result<Vec<BackupSummary>, SdkError<ListBackupsError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    let mut backups = Vec::<BackupSummary>::new();
+    let mut exclusive_start_backup_arn: Option<String> = None;
+    loop {
+        let res = ddb
+            .list_backups()
+            .set_table_name(table_name.map(String::from))
+            .set_exclusive_start_backup_arn(exclusive_start_backup_arn)
+            .send()
+            .await?;
+        backups.append(&mut res.backup_summaries.unwrap_or_default());
+        match res.last_evaluated_backup_arn {
+            None => return Ok(backups),
+            last_evaluated_backup_arn => exclusive_start_backup_arn = last_evaluated_backup_arn,
+        }
+    }
+}
+
+/// Returns details of the backup, including settings of the source table such as GSIs. [API: DescribeBackup]
+pub async fn describe_backup(
+    config: &SdkConfig,
+    backup_arn: &str,
+) -> Result<BackupDescription, SdkError<DescribeBackupError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.describe_backup()
+        .backup_arn(backup_arn)
+        .send()
+        .await
+        .map(|res| {
+            res.backup_description
+                .expect("Backup Description returned from API should be valid.")
+        })
+}
+
+/// Starts restoring a new table from the backup. [API: RestoreTableFromBackup]
+/// Billing mode and capacity of the restored table are the same as the source table unless overrides are given.
+/// Note that when overriding capacity of a table with GSIs, DynamoDB requires global_secondary_index_override as well.
+pub async fn restore_table_from_backup(
+    config: &SdkConfig,
+    backup_arn: &str,
+    target_table_name: &str,
+    billing_mode_override: Option<BillingMode>,
+    provisioned_throughput_override: Option<ProvisionedThroughput>,
+    global_secondary_index_override: Option<Vec<GlobalSecondaryIndex>>,
+) -> Result<TableDescription, SdkError<RestoreTableFromBackupError>> {
+    let input = restore_table_from_backup_input(
+        backup_arn,
+        target_table_name,
+        billing_mode_override,
+        provisioned_throughput_override,
+        global_secondary_index_override,
+    );
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.restore_table_from_backup()
+        .set_backup_arn(input.backup_arn)
+        .set_target_table_name(input.target_table_name)
+        .set_billing_mode_override(input.billing_mode_override)
+        .set_provisioned_throughput_override(input.provisioned_throughput_override)
+        .set_global_secondary_index_override(input.global_secondary_index_override)
+        .send()
+        .await
+        .map(|res| {
+            res.table_description
+                .expect("Table Description returned from API should be valid.")
+        })
+}
+
+/// Generates RestoreTableFromBackupInput sent by [`restore_table_from_backup`].
+pub fn restore_table_from_backup_input(
+    backup_arn: &str,
+    target_table_name: &str,
+    billing_mode_override: Option<BillingMode>,
+    provisioned_throughput_override: Option<ProvisionedThroughput>,
+    global_secondary_index_override: Option<Vec<GlobalSecondaryIndex>>,
+) -> RestoreTableFromBackupInput {
+    RestoreTableFromBackupInput::builder()
+        .backup_arn(backup_arn)
+        .target_table_name(target_table_name)
+        .set_billing_mode_override(billing_mode_override)
+        .set_provisioned_throughput_override(provisioned_throughput_override)
+        .set_global_secondary_index_override(global_secondary_index_override)
+        .build()
+        .expect("RestoreTableFromBackupInput should be valid")
+}
+
+/// Starts restoring a new table from the source table as of restore_date_time, or the latest restorable time when it's None.
+/// PITR (point-in-time recovery) must be enabled on the source table. [API: RestoreTableToPointInTime]
+pub async fn restore_table_to_point_in_time(
+    config: &SdkConfig,
+    source_table_name: &str,
+    target_table_name: &str,
+    restore_date_time: Option<DateTime>,
+) -> Result<TableDescription, SdkError<RestoreTableToPointInTimeError>> {
+    let input = restore_table_to_point_in_time_input(
+        source_table_name,
+        target_table_name,
+        restore_date_time,
+    );
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.restore_table_to_point_in_time()
+        .set_source_table_name(input.source_table_name)
+        .set_target_table_name(input.target_table_name)
+        .set_restore_date_time(input.restore_date_time)
+        .set_use_latest_restorable_time(input.use_latest_restorable_time)
+        .send()
+        .await
+        .map(|res| {
+            res.table_description
+                .expect("Table Description returned from API should be valid.")
+        })
+}
+
+/// Generates RestoreTableToPointInTimeInput sent by [`restore_table_to_point_in_time`].
+pub fn restore_table_to_point_in_time_input(
+    source_table_name: &str,
+    target_table_name: &str,
+    restore_date_time: Option<DateTime>,
+) -> RestoreTableToPointInTimeInput {
+    RestoreTableToPointInTimeInput::builder()
+        .source_table_name(source_table_name)
+        .target_table_name(target_table_name)
+        .set_restore_date_time(restore_date_time)
+        .use_latest_restorable_time(restore_date_time.is_none())
+        .build()
+        .expect("RestoreTableToPointInTimeInput should be valid")
+}
+
+/// Returns continuous backups settings of the table, which include the status of PITR. [API: DescribeContinuousBackups]
+pub async fn describe_continuous_backups(
+    config: &SdkConfig,
+    table_name: &str,
+) -> Result<ContinuousBackupsDescription, SdkError<DescribeContinuousBackupsError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.describe_continuous_backups()
+        .table_name(table_name)
+        .send()
+        .await
+        .map(|res| {
+            res.continuous_backups_description
+                .expect("Continuous Backups Description returned from API should be valid.")
+        })
+}
+
+/// Enables or disables PITR (point-in-time recovery) of the table. [API: UpdateContinuousBackups]
+pub async fn update_continuous_backups(
+    config: &SdkConfig,
+    table_name: &str,
+    enabled: bool,
+) -> Result<ContinuousBackupsDescription, SdkError<UpdateContinuousBackupsError>> {
+    let input = update_continuous_backups_input(table_name, enabled);
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.update_continuous_backups()
+        .set_table_name(input.table_name)
+        .set_point_in_time_recovery_specification(input.point_in_time_recovery_specification)
+        .send()
+        .await
+        .map(|res| {
+            res.continuous_backups_description
+                .expect("Continuous Backups Description returned from API should be valid.")
+        })
+}
+
+/// Generates UpdateContinuousBackupsInput sent by [`update_continuous_backups`].
+pub fn update_continuous_backups_input(
+    table_name: &str,
+    enabled: bool,
+) -> UpdateContinuousBackupsInput {
+    let spec = PointInTimeRecoverySpecification::builder()
+        .point_in_time_recovery_enabled(enabled)
+        .build()
+        .unwrap();
+    UpdateContinuousBackupsInput::builder()
+        .table_name(table_name)
+        .point_in_time_recovery_specification(spec)
+        .build()
+        .expect("UpdateContinuousBackupsInput should be valid")
+}
+
+/// Returns the status of CloudWatch Contributor Insights of the table, or the GSI when index_name is given. [API: DescribeContributorInsights]
+pub async fn describe_contributor_insights(
+    config: &SdkConfig,
+    table_name: &str,
+    index_name: Option<&str>,
+) -> Result<Option<ContributorInsightsStatus>, SdkError<DescribeContributorInsightsError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.describe_contributor_insights()
+        .table_name(table_name)
+        .set_index_name(index_name.map(String::from))
+        .send()
+        .await
+        .map(|res| res.contributor_insights_status)
+}
+
+/// Enables or disables CloudWatch Contributor Insights of the table, or the GSI when index_name is given. [API: UpdateContributorInsights]
+/// Returns the status after the update, e.g. ENABLING or DISABLING.
+pub async fn update_contributor_insights(
+    config: &SdkConfig,
+    table_name: &str,
+    index_name: Option<&str>,
+    enabled: bool,
+) -> Result<Option<ContributorInsightsStatus>, SdkError<UpdateContributorInsightsError>> {
+    let input = update_contributor_insights_input(table_name, index_name, enabled);
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.update_contributor_insights()
+        .set_table_name(input.table_name)
+        .set_index_name(input.index_name)
+        .set_contributor_insights_action(input.contributor_insights_action)
+        .send()
+        .await
+        .map(|res| res.contributor_insights_status)
+}
+
+/// Generates UpdateContributorInsightsInput sent by [`update_contributor_insights`].
+pub fn update_contributor_insights_input(
+    table_name: &str,
+    index_name: Option<&str>,
+    enabled: bool,
+) -> UpdateContributorInsightsInput {
+    let action = if enabled {
+        ContributorInsightsAction::Enable
+    } else {
+        ContributorInsightsAction::Disable
+    };
+    UpdateContributorInsightsInput::builder()
+        .table_name(table_name)
+        .set_index_name(index_name.map(String::from))
+        .contributor_insights_action(action)
+        .build()
+        .expect("UpdateContributorInsightsInput should be valid")
+}
+
+/// Returns TTL (Time to Live) settings of the table. [API: DescribeTimeToLive]
+pub async fn describe_time_to_live(
+    config: &SdkConfig,
+    table_name: &str,
+) -> Result<TimeToLiveDescription, SdkError<DescribeTimeToLiveError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.describe_time_to_live()
+        .table_name(table_name)
+        .send()
+        .await
+        .map(|res| {
+            res.time_to_live_description
+                .expect("TTL Description returned from API should be valid.")
+        })
+}
+
+/// Enables or disables TTL (Time to Live) of the table on the given attribute. [API: UpdateTimeToLive]
+/// Note that DynamoDB requires the current TTL attribute name even when disabling TTL.
+pub async fn update_time_to_live(
+    config: &SdkConfig,
+    table_name: &str,
+    attribute_name: &str,
+    enabled: bool,
+) -> Result<TimeToLiveSpecification, SdkError<UpdateTimeToLiveError>> {
+    let input = update_time_to_live_input(table_name, attribute_name, enabled);
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.update_time_to_live()
+        .set_table_name(input.table_name)
+        .set_time_to_live_specification(input.time_to_live_specification)
+        .send()
+        .await
+        .map(|res| {
+            res.time_to_live_specification
+                .expect("TTL Specification returned from API should be valid.")
+        })
+}
+
+/// Generates UpdateTimeToLiveInput sent by [`update_time_to_live`].
+pub fn update_time_to_live_input(
+    table_name: &str,
+    attribute_name: &str,
+    enabled: bool,
+) -> UpdateTimeToLiveInput {
+    let spec = TimeToLiveSpecification::builder()
+        .attribute_name(attribute_name)
+        .enabled(enabled)
+        .build()
+        .unwrap();
+    UpdateTimeToLiveInput::builder()
+        .table_name(table_name)
+        .time_to_live_specification(spec)
+        .build()
+        .expect("UpdateTimeToLiveInput should be valid")
+}
+
+/// Adds tags to the resource, e.g. a table. Existing tags with the same keys are overwritten. [API: TagResource]
+pub async fn tag_resource(
+    config: &SdkConfig,
+    resource_arn: &str,
+    tags: Vec<Tag>,
+) -> Result<(), SdkError<TagResourceError>> {
+    let input = tag_resource_input(resource_arn, tags);
+    let ddb = DynamoDbSdkClient::new(config);
+    ddb.tag_resource()
+        .set_resource_arn(input.resource_arn)
+        .set_tags(input.tags)
+        .send()
+        .await
+        .map(|_| ())
+}
+
+/// Generates TagResourceInput sent by [`tag_resource`].
+pub fn tag_resource_input(resource_arn: &str, tags: Vec<Tag>) -> TagResourceInput {
+    TagResourceInput::builder()
+        .resource_arn(resource_arn)
+        .set_tags(Some(tags))
+        .build()
+        .expect("TagResourceInput should be valid")
+}
+
+/// Returns all tags of the resource, e.g. a table. [API: ListTagsOfResource]
+/// ListTagsOfResource API is called repeatedly until all tags are retrieved.
+pub async fn list_tags_of_resource(
+    config: &SdkConfig,
+    resource_arn: &str,
+) -> Result<Vec<Tag>, SdkError<ListTagsOfResourceError>> {
+    let ddb = DynamoDbSdkClient::new(config);
+    let mut tags = Vec::<Tag>::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let res = ddb
+            .list_tags_of_resource()
+            .resource_arn(resource_arn)
+            .set_next_token(next_token)
+            .send()
+            .await?;
+        tags.append(&mut res.tags.unwrap_or_default());
+        match res.next_token {
+            None => return Ok(tags),
+            token => next_token = token,
+        }
+    }
+}
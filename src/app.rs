@@ -16,25 +16,35 @@
 
 use ::serde::{Deserialize, Serialize};
 use aws_config::{
-    meta::region::RegionProviderChain, retry::RetryConfig, BehaviorVersion, Region, SdkConfig,
+    environment::EnvironmentVariableRegionProvider,
+    meta::region::RegionProviderChain,
+    profile::{ProfileFileCredentialsProvider, ProfileFileRegionProvider},
+    retry::RetryConfig,
+    sts::AssumeRoleProvider,
+    timeout::TimeoutConfig,
+    BehaviorVersion, ConfigLoader, Region, SdkConfig,
 };
+use aws_sdk_dynamodb::config::SharedCredentialsProvider;
 use aws_sdk_dynamodb::types::{AttributeDefinition, TableDescription};
 use aws_smithy_runtime_api::client::result::SdkError;
 use aws_smithy_types::error::metadata::ProvideErrorMetadata;
+use dialoguer::{theme::ColorfulTheme, Confirm, Select};
 use log::{debug, error, info};
 use serde_yaml::Error as SerdeYAMLError;
 use std::convert::{TryFrom, TryInto};
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet},
     env, error,
     fmt::{self, Formatter},
     fs,
-    io::Error as IOError,
+    io::{self, Error as IOError, IsTerminal},
     path,
 };
 use tempfile::NamedTempFile;
 use thiserror::Error;
+use tokio::sync::OnceCell;
 
 use super::control;
 use super::ddb::{key, table};
@@ -47,7 +57,74 @@ const CONFIG_DIR: &str = ".dynein";
 const CONFIG_PATH_ENV_VAR_NAME: &str = "DYNEIN_CONFIG_DIR";
 const CONFIG_FILE_NAME: &str = "config.yml";
 const CACHE_FILE_NAME: &str = "cache.yml";
-const LOCAL_REGION: &str = "local";
+pub const LOCAL_REGION: &str = "local";
+
+/// Friendly aliases of regions users often type, i.e. abbreviations and city names, which are resolved by `resolve_region_alias`.
+const REGION_ALIASES: &[(&str, &str)] = &[
+    ("use1", "us-east-1"),
+    ("virginia", "us-east-1"),
+    ("use2", "us-east-2"),
+    ("ohio", "us-east-2"),
+    ("usw1", "us-west-1"),
+    ("california", "us-west-1"),
+    ("usw2", "us-west-2"),
+    ("oregon", "us-west-2"),
+    ("cac1", "ca-central-1"),
+    ("canada", "ca-central-1"),
+    ("sae1", "sa-east-1"),
+    ("saopaulo", "sa-east-1"),
+    ("euw1", "eu-west-1"),
+    ("ireland", "eu-west-1"),
+    ("euw2", "eu-west-2"),
+    ("london", "eu-west-2"),
+    ("euw3", "eu-west-3"),
+    ("paris", "eu-west-3"),
+    ("euc1", "eu-central-1"),
+    ("frankfurt", "eu-central-1"),
+    ("eun1", "eu-north-1"),
+    ("stockholm", "eu-north-1"),
+    ("aps1", "ap-south-1"),
+    ("mumbai", "ap-south-1"),
+    ("apse1", "ap-southeast-1"),
+    ("singapore", "ap-southeast-1"),
+    ("apse2", "ap-southeast-2"),
+    ("sydney", "ap-southeast-2"),
+    ("apne1", "ap-northeast-1"),
+    ("tokyo", "ap-northeast-1"),
+    ("apne2", "ap-northeast-2"),
+    ("seoul", "ap-northeast-2"),
+    ("apne3", "ap-northeast-3"),
+    ("osaka", "ap-northeast-3"),
+];
+
+/// Exit code used when a command is cancelled by --deadline. Same as the one of `timeout` command in GNU coreutils.
+pub const DEADLINE_EXCEEDED_EXIT_CODE: i32 = 124;
+
+/// Default maximum number of API calls in flight when a command fans out to many regions or tables, e.g. `dy list --all-regions`.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Role session name used for --role-arn unless --session-name is given.
+pub const DEFAULT_ROLE_SESSION_NAME: &str = "dynein";
+
+/// Default seconds after which cached table schemas are regarded as stale, i.e. 1 day. It can be overridden by cache_ttl in the config file.
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 86400;
+
+/// Exit code used when a command fails because requests are throttled. Same as EX_TEMPFAIL in sysexits.h.
+pub const THROTTLED_EXIT_CODE: i32 = 75;
+
+/// Printed along with throttling errors, to tell users how to avoid them.
+pub const THROTTLED_HINT: &str = "Too many requests are being sent. Send fewer requests in parallel with a lower --concurrency (e.g. --concurrency 2), \
+or retry more with --max-retries (e.g. --max-retries 10) or 'retry' setting in the dynein config file (~/.dynein/config.yml), then try again.";
+
+/// Exit code used by `dy desc --require-active` when the table or one of its GSIs is not ACTIVE.
+pub const TABLE_NOT_ACTIVE_EXIT_CODE: i32 = 3;
+
+/// Error codes returned by DynamoDB when requests are throttled.
+const THROTTLING_ERROR_CODES: [&str; 3] = [
+    "ThrottlingException",
+    "ProvisionedThroughputExceededException",
+    "RequestLimitExceeded",
+];
 
 pub enum DyneinFileType {
     ConfigFile,
@@ -62,6 +139,28 @@ pub struct TableSchema {
     pub sk: Option<key::Key>,
     pub indexes: Option<Vec<IndexSchema>>,
     pub mode: table::Mode,
+    /// When the schema was written to the cache, in RFC3339 format. It's missing in caches written by older versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_at: Option<String>,
+}
+
+impl TableSchema {
+    /// Returns true if the schema was cached more than ttl seconds ago, or when it was cached is unknown.
+    pub fn is_stale(&self, ttl: u64) -> bool {
+        match self
+            .cached_at
+            .as_deref()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        {
+            Some(cached_at) => {
+                chrono::Utc::now()
+                    .signed_duration_since(cached_at)
+                    .num_seconds()
+                    > ttl as i64
+            }
+            None => true,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -106,7 +205,9 @@ pub struct Config {
     pub using_port: Option<u32>,
     #[serde(default)]
     pub query: QueryConfig,
-    // pub cache_expiration_time: Option<i64>, // in second. default 300 (= 5 minutes)
+    /// Seconds after which cached table schemas are regarded as stale by `dy desc --cache-only`. Default is DEFAULT_CACHE_TTL_SECS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl: Option<u64>,
     pub retry: Option<RetrySettingGlobal>,
 }
 
@@ -191,11 +292,13 @@ pub struct QueryConfig {
 /// Cache contains retrieved info of tables, and how fresh they are (cache_created_at).
 /// Currently Cache struct doesn't manage freshness of each table.
 /// i.e. Entire cache will be removed after cache_expiration_time in Config has passed.
+/// Tables are kept in BTreeMap so that the cache is serialized deterministically (sorted by keys),
+/// which allows to share the cache file via `dy cache export/import` and to diff it in git.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Cache {
     /// cached table schema information.
     /// table schemas are stored in keys to identify the target table "<Region>/<TableName>" -- e.g. "ap-northeast-1/Employee"
-    pub tables: Option<HashMap<String, TableSchema>>,
+    pub tables: Option<BTreeMap<String, TableSchema>>,
     // pub cache_updated_at: String,
     // pub cache_created_at: String,
 }
@@ -206,6 +309,14 @@ pub struct Retry {
     pub batch_write_item: Option<RetryConfig>,
 }
 
+/// IAM role assumed by STS AssumeRole for API calls, given by --role-arn, --external-id and --session-name.
+#[derive(Debug, Clone)]
+pub struct AssumeRole {
+    pub role_arn: String,
+    pub external_id: Option<String>,
+    pub session_name: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Context {
     pub config: Option<Config>,
@@ -213,9 +324,21 @@ pub struct Context {
     pub overwritten_region: Option<Region>, // --region option
     pub overwritten_table_name: Option<String>, // --table option
     pub overwritten_port: Option<u32>,      // --port option
+    pub overwritten_endpoint_url: Option<String>, // --endpoint-url option
+    pub profile: Option<String>, // AWS named profile, given by --profile or used by --all-profiles
+    pub assume_role: Option<AssumeRole>, // --role-arn option
+    // Credentials of the role assumed for --role-arn, shared by clones of the context so that AssumeRole API is called once per command.
+    pub assumed_role_credentials: Arc<OnceCell<SharedCredentialsProvider>>,
     pub output: Option<String>,
     pub should_strict_for_query: Option<bool>,
     pub retry: Option<Retry>,
+    pub deadline: Option<Instant>, // --deadline option
+    pub max_retries: Option<u32>,  // --max-retries option
+    pub concurrency: usize,        // --concurrency option of list/desc
+    pub assume_yes: bool,          // --yes option
+    pub interactive: bool,         // --interactive option
+    pub no_cache: bool,            // --no-cache option
+    pub dry_run: bool,             // --dry-run option
 }
 
 /*
@@ -224,10 +347,12 @@ pub struct Context {
  Overwritten information is retrieved with `effective_*` functions as 1st priority.
 */
 impl Context {
+    /// With no_cache, the cache file is neither loaded nor created, e.g. when the dynein directory is not writable.
     pub fn new(
         region: Option<String>,
         port: Option<u32>,
         table: Option<String>,
+        no_cache: bool,
     ) -> Result<Context, DyneinConfigError> {
         let config = load_or_touch_config_file(true)?;
         let retry = match &config.retry {
@@ -238,13 +363,28 @@ impl Context {
         };
         Ok(Context {
             config: Some(config),
-            cache: Some(load_or_touch_cache_file(true)?),
+            cache: if no_cache {
+                None
+            } else {
+                Some(load_or_touch_cache_file(true)?)
+            },
             overwritten_region: region_from_str(region),
             overwritten_table_name: table,
             overwritten_port: port,
+            overwritten_endpoint_url: None,
+            profile: None,
+            assume_role: None,
+            assumed_role_credentials: Arc::default(),
             output: None,
             should_strict_for_query: None,
             retry,
+            deadline: None,
+            max_retries: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            assume_yes: false,
+            interactive: false,
+            no_cache,
+            dry_run: false,
         })
     }
 
@@ -274,19 +414,89 @@ impl Context {
         region_name: &str,
         retry_config: Option<RetryConfig>,
     ) -> SdkConfig {
-        let sdk_region = Region::new(region_name.to_owned());
-
-        let provider = RegionProviderChain::first_try(sdk_region);
-        let mut config = aws_config::defaults(BehaviorVersion::v2024_03_28()).region(provider);
-        if self.is_local().await {
+        let is_local = self.is_local().await;
+        let mut config = self.source_config_loader(region_name);
+        if let Some(endpoint_url) = &self.overwritten_endpoint_url {
+            config = config.endpoint_url(endpoint_url);
+        } else if is_local {
             config = config.endpoint_url(format!("http://localhost:{}", self.effective_port()));
         }
 
+        // With --role-arn, credentials of the source config (i.e. --profile or the default chain) are exchanged for temporary ones of the role.
+        // DynamoDB Local doesn't check credentials, so the role is not assumed for it.
+        // The provider (and its credentials cache) is built once and reused by all clients of the command, e.g. ones for each table of --all-tables.
+        if let Some(assume_role) = &self.assume_role {
+            if !is_local {
+                let provider = self
+                    .assumed_role_credentials
+                    .get_or_init(|| async {
+                        let source = self.source_config_loader(region_name).load().await;
+                        let mut provider = AssumeRoleProvider::builder(&assume_role.role_arn)
+                            .session_name(
+                                assume_role
+                                    .session_name
+                                    .as_deref()
+                                    .unwrap_or(DEFAULT_ROLE_SESSION_NAME),
+                            )
+                            .configure(&source);
+                        if let Some(external_id) = &assume_role.external_id {
+                            provider = provider.external_id(external_id);
+                        }
+                        SharedCredentialsProvider::new(provider.build().await)
+                    })
+                    .await;
+                config = config.credentials_provider(provider.clone());
+            }
+        }
+
+        // Retry setting of the config file applies to all API calls unless a specific one is given (e.g. for BatchWriteItem).
+        // The SDK retries only retriable errors such as throttling, with exponential backoff and jitter.
+        let retry_config = retry_config.or_else(|| self.retry.as_ref().map(|r| r.default.clone()));
+        let retry_config = match self.max_retries {
+            Some(max_retries) => Some(
+                retry_config
+                    .unwrap_or_else(RetryConfig::standard)
+                    .with_max_attempts(max_retries.saturating_add(1)),
+            ),
+            None => retry_config,
+        };
         if let Some(retry_config) = retry_config {
             config = config.retry_config(retry_config);
         }
 
-        config.load().await
+        let config = config.load().await;
+
+        // The operation timeout covers all retry attempts of an API call, so that the SDK stops retrying past the deadline.
+        // It's added to the loaded timeout config, hence other timeouts (e.g. the default connect timeout) are kept.
+        match self.remaining_time_until_deadline() {
+            Some(remaining) => {
+                let timeout_config = config
+                    .timeout_config()
+                    .map_or_else(TimeoutConfig::builder, TimeoutConfig::to_builder)
+                    .operation_timeout(remaining)
+                    .build();
+                config.to_builder().timeout_config(timeout_config).build()
+            }
+            None => config,
+        }
+    }
+
+    /// Loader of the config with the region and credentials of --profile, which is also used to call STS for --role-arn.
+    fn source_config_loader(&self, region_name: &str) -> ConfigLoader {
+        let sdk_region = Region::new(region_name.to_owned());
+
+        let provider = RegionProviderChain::first_try(sdk_region);
+        let mut config = aws_config::defaults(BehaviorVersion::v2024_03_28()).region(provider);
+
+        // Credentials of the named profile are used even if environment variables such as AWS_ACCESS_KEY_ID are set, as AWS CLI does with --profile.
+        if let Some(profile) = &self.profile {
+            config = config.profile_name(profile).credentials_provider(
+                ProfileFileCredentialsProvider::builder()
+                    .profile_name(profile)
+                    .build(),
+            );
+        }
+        config
     }
 
     pub async fn effective_region(&self) -> Region {
@@ -297,7 +507,7 @@ impl Context {
 
         // next, if there's an `using_region` field in the config file, use it.
         if let Some(using_region_name_in_config) =
-            &self.config.to_owned().and_then(|x| x.using_region)
+            self.config.as_ref().and_then(|x| x.using_region.as_ref())
         {
             return region_from_str(Some(using_region_name_in_config.to_owned())) // Option<Region>
                 .expect("Region name in the config file is invalid.");
@@ -306,7 +516,17 @@ impl Context {
         // otherwise, come down to "default region" of your environment.
         // e.g. region set via AWS CLI (check: $ aws configure get region), or environment variable `AWS_DEFAULT_REGION`.
         //      ref: https://docs.aws.amazon.com/cli/latest/userguide/cli-configure-envvars.html
-        let region_provider = RegionProviderChain::default_provider();
+        // When a named profile is given, the region of the profile is used instead of the one of AWS_PROFILE.
+        let region_provider = match &self.profile {
+            Some(profile) => {
+                RegionProviderChain::first_try(EnvironmentVariableRegionProvider::new()).or_else(
+                    ProfileFileRegionProvider::builder()
+                        .profile_name(profile)
+                        .build(),
+                )
+            }
+            None => RegionProviderChain::default_provider(),
+        };
         region_provider
             .region()
             .await
@@ -319,9 +539,9 @@ impl Context {
             return ow_table_name.to_owned();
         };
         // otherwise, retrieve an `using_table` from config file.
-        self.to_owned()
-            .config
-            .and_then(|x| x.using_table)
+        self.config
+            .as_ref()
+            .and_then(|x| x.using_table.to_owned())
             .unwrap_or_else(|| {
                 // if both --option nor config file are not available, raise error and exit the command.
                 error!("{}", Messages::NoEffectiveTable);
@@ -334,8 +554,8 @@ impl Context {
             return ow_port.to_owned();
         };
 
-        if let Some(using_port_in_config) = &self.config.to_owned().and_then(|x| x.using_port) {
-            return using_port_in_config.to_owned();
+        if let Some(using_port_in_config) = self.config.as_ref().and_then(|x| x.using_port) {
+            return using_port_in_config;
         };
 
         8000
@@ -351,26 +571,38 @@ impl Context {
 
     pub async fn cached_using_table_schema(&self) -> Option<TableSchema> {
         // return None if table name is not specified in both config and option.
-        if self.overwritten_table_name.is_none() {
-            match self.config.to_owned() {
-                Some(c) => c.using_table?,
-                None => return None,
-            };
+        if self.overwritten_table_name.is_none()
+            && self
+                .config
+                .as_ref()
+                .and_then(|c| c.using_table.as_ref())
+                .is_none()
+        {
+            return None;
         }
 
-        let cached_tables: HashMap<String, TableSchema> =
-            match self.cache.to_owned().and_then(|c| c.tables) {
-                Some(cts) => cts,
-                None => return None, // return None for this "cached_using_table_schema" function
-            };
-        let found_table_schema: Option<&TableSchema> =
-            cached_tables.get(&self.effective_cache_key().await);
-        // NOTE: HashMap's `get` returns a reference to the value / (&self, k: &Q) -> Option<&V>
-        found_table_schema.map(|schema| schema.to_owned())
+        self.cached_table_schema(&self.effective_table_name())
+            .await
+            .cloned()
+    }
+
+    /// Returns the cached schema of the given table in the effective region, borrowing it from the cache in the context.
+    /// Unlike cached_using_table_schema, the context doesn't need to be cloned with `with_table` to look up other tables.
+    pub async fn cached_table_schema(&self, table_name: &str) -> Option<&TableSchema> {
+        let cache_key = format!("{}/{}", self.effective_region().await.as_ref(), table_name);
+        self.cache.as_ref()?.tables.as_ref()?.get(&cache_key)
+    }
+
+    /// Returns seconds after which cached table schemas are regarded as stale.
+    pub fn cache_ttl(&self) -> u64 {
+        self.config
+            .as_ref()
+            .and_then(|c| c.cache_ttl)
+            .unwrap_or(DEFAULT_CACHE_TTL_SECS)
     }
 
     pub fn with_region(mut self, ec2_region: &str) -> Self {
-        self.overwritten_region = Some(Region::new(ec2_region.to_owned()));
+        self.overwritten_region = Some(Region::new(resolve_region_alias(ec2_region)));
         self
     }
 
@@ -379,6 +611,55 @@ impl Context {
         self
     }
 
+    pub fn with_endpoint_url(mut self, endpoint_url: Option<String>) -> Self {
+        self.overwritten_endpoint_url = endpoint_url;
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_assume_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
+    }
+
+    pub fn with_interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn with_assume_role(mut self, assume_role: Option<AssumeRole>) -> Self {
+        self.assume_role = assume_role;
+        self.assumed_role_credentials = Arc::default();
+        self
+    }
+
+    /// Credentials of the assumed role are not shared with the original context, as the role is assumed with credentials of the profile.
+    pub fn with_profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_owned());
+        self.assumed_role_credentials = Arc::default();
+        self
+    }
+
+    /// Returns remaining time of the command if --deadline is given. Returns zero duration once the deadline has passed.
+    pub fn remaining_time_until_deadline(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Returns true if --deadline is given and it has already passed.
+    pub fn is_deadline_exceeded(&self) -> bool {
+        self.remaining_time_until_deadline() == Some(Duration::ZERO)
+    }
+
     pub fn should_strict_for_query(&self) -> bool {
         self.should_strict_for_query
             .unwrap_or_else(|| self.config.as_ref().map_or(false, |c| c.query.strict_mode))
@@ -390,6 +671,20 @@ impl Context {
     }
 }
 
+#[derive(Error, Debug)]
+#[error("the command has been cancelled as it exceeded the deadline of {0} seconds")]
+pub struct DeadlineExceededError(pub u64);
+
+#[derive(Error, Debug)]
+pub enum DyneinConfirmationError {
+    #[error("Confirmation is required but stdin is not a terminal. Pass --yes to proceed without confirmation.")]
+    NotInteractive,
+    #[error("--interactive is given but stdin is not a terminal.")]
+    InteractiveRequired,
+    #[error("failed to read confirmation: {0}")]
+    Dialoguer(#[from] dialoguer::Error),
+}
+
 #[derive(Error, Debug)]
 pub enum DyneinConfigContentError {
     #[error("retry config error ")]
@@ -442,6 +737,18 @@ impl From<SerdeYAMLError> for DyneinConfigError {
     }
 }
 
+#[derive(Error, Debug)]
+pub enum DyneinCacheTransferError {
+    #[error(transparent)]
+    Config(#[from] DyneinConfigError),
+    #[error(transparent)]
+    IO(#[from] IOError),
+    #[error("invalid cache file: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Yaml(#[from] SerdeYAMLError),
+}
+
 /* =================================================
 Public functions
 ================================================= */
@@ -450,11 +757,42 @@ Public functions
 pub fn region_from_str(s: Option<String>) -> Option<Region> {
     match s.as_deref() {
         Some(LOCAL_REGION) => Some(Region::from_static(LOCAL_REGION)),
-        Some(x) => Some(Region::new(x.to_owned())), // convert Result<T, E> into Option<T>
+        Some(x) => Some(Region::new(resolve_region_alias(x))),
         None => None,
     }
 }
 
+/// Resolves a friendly alias of a region (e.g. "use1" or "tokyo", case-insensitive) into the canonical region name.
+/// Names which are not aliases, including canonical region names and "local", are returned as they are.
+pub fn resolve_region_alias(name: &str) -> String {
+    REGION_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map(|(_, region)| region.to_string())
+        .unwrap_or_else(|| name.to_owned())
+}
+
+/// Validates a region name given by users, e.g. "us-east-1" or "us-gov-west-1", so that typos are reported before calling any API.
+/// Note that SDK's Region accepts any string, and an unknown region would fail only at an API call with a confusing DNS error.
+pub fn validate_region_name(name: &str) -> Result<(), String> {
+    let parts: Vec<&str> = name.split('-').collect();
+    let valid = parts.len() >= 3
+        && parts[0].len() == 2
+        && parts[..parts.len() - 1]
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_lowercase()))
+        && parts[parts.len() - 1].chars().all(|c| c.is_ascii_digit())
+        && !parts[parts.len() - 1].is_empty();
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Unknown region: '{}'. Region should be given in the form of e.g. 'us-east-1', 'eu-west-1' or 'ap-northeast-1', or an alias such as 'tokyo'.",
+            name
+        ))
+    }
+}
+
 /// Loads dynein config file (YAML format) and return config struct as a result.
 /// Creates the file with default if the file couldn't be found.
 pub fn load_or_touch_config_file(first_try: bool) -> Result<Config, DyneinConfigError> {
@@ -534,7 +872,7 @@ pub async fn use_table(
         Some(tbl) => {
             debug!("describing the table: {}", tbl);
             let tbl = tbl.clone();
-            let desc: TableDescription = control::describe_table_api(cx, tbl.clone()).await;
+            let desc: TableDescription = describe_table_or_exit(cx, tbl.clone()).await;
             save_using_target(cx, desc).await?;
             println!("Now you're using the table '{}' ({}).", tbl, cx.effective_region().await.as_ref());
         },
@@ -544,11 +882,11 @@ pub async fn use_table(
     Ok(())
 }
 
-/// Inserts specified table description into cache file.
+/// Inserts specified table description into cache file. With --no-cache, the schema is returned without writing the file.
 pub async fn insert_to_table_cache(
     cx: &Context,
     desc: &TableDescription,
-) -> Result<(), DyneinConfigError> {
+) -> Result<TableSchema, DyneinConfigError> {
     let table_name = desc
         .table_name
         .clone()
@@ -560,32 +898,30 @@ pub async fn insert_to_table_cache(
         table_name
     );
 
-    // retrieve current cache from Context and update target table desc.
+    let schema = TableSchema {
+        region: String::from(region.as_ref()),
+        name: table_name.to_owned(),
+        pk: key::typed_key("HASH", desc).expect("pk should exist"),
+        sk: key::typed_key("RANGE", desc),
+        indexes: index_schemas(desc),
+        mode: table::extract_mode(&desc.billing_mode_summary),
+        cached_at: Some(chrono::Utc::now().to_rfc3339()),
+    };
+    if cx.no_cache {
+        debug!("--no-cache is given, hence the table schema is not written to the cache file.");
+        return Ok(schema);
+    }
+
+    // load current cache from the file (rather than Context, which may be outdated when multiple tables are cached) and update target table desc.
     // key to save the table desc is "<RegionName>/<TableName>" -- e.g. "us-west-2/app_data"
-    let mut cache: Cache = cx.cache.clone().expect("cx should have cache");
+    let mut cache: Cache = load_or_touch_cache_file(true)?;
     let cache_key = format!("{}/{}", region.as_ref(), table_name);
 
-    let mut table_schema_hashmap: HashMap<String, TableSchema> = match cache.tables {
-        Some(ts) => ts,
-        None => HashMap::<String, TableSchema>::new(),
-    };
-    debug!(
-        "table schema cache before insert: {:#?}",
-        table_schema_hashmap
-    );
+    let mut table_schema_map: BTreeMap<String, TableSchema> = cache.tables.unwrap_or_default();
+    debug!("table schema cache before insert: {:#?}", table_schema_map);
 
-    table_schema_hashmap.insert(
-        cache_key,
-        TableSchema {
-            region: String::from(region.as_ref()),
-            name: table_name,
-            pk: key::typed_key("HASH", desc).expect("pk should exist"),
-            sk: key::typed_key("RANGE", desc),
-            indexes: index_schemas(desc),
-            mode: table::extract_mode(&desc.billing_mode_summary),
-        },
-    );
-    cache.tables = Some(table_schema_hashmap);
+    table_schema_map.insert(cache_key, schema.clone());
+    cache.tables = Some(table_schema_map);
 
     // write to cache file
     let cache_yaml_string = serde_yaml::to_string(&cache)?;
@@ -595,6 +931,46 @@ pub async fn insert_to_table_cache(
     );
     write_dynein_file(DyneinFileType::CacheFile, cache_yaml_string)?;
 
+    Ok(schema)
+}
+
+/// Exports cached table schemas as pretty JSON, into the given file or stdout.
+/// Keys of tables are sorted, thus the result is stable and can be committed in git.
+pub fn export_cache(output_file: Option<String>) -> Result<(), DyneinCacheTransferError> {
+    let cache = load_or_touch_cache_file(true)?;
+    let json = serde_json::to_string_pretty(&cache)? + "\n";
+    match output_file {
+        None => print!("{}", json),
+        Some(path) => {
+            fs::write(&path, json)?;
+            println!(
+                "Exported {} table schema(s) to '{}'.",
+                cache.tables.map_or(0, |t| t.len()),
+                path
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Imports table schemas exported by `dy cache export` and merges them into the local cache file.
+/// Entries in the given file take precedence over existing entries that have the same "<Region>/<TableName>" key.
+pub fn import_cache(input_file: String) -> Result<(), DyneinCacheTransferError> {
+    let imported: Cache = serde_json::from_str(&fs::read_to_string(&input_file)?)?;
+    let imported_tables = imported.tables.unwrap_or_default();
+    let count = imported_tables.len();
+
+    let mut cache = load_or_touch_cache_file(true)?;
+    cache
+        .tables
+        .get_or_insert_with(BTreeMap::new)
+        .extend(imported_tables);
+    write_dynein_file(DyneinFileType::CacheFile, serde_yaml::to_string(&cache)?)?;
+
+    println!(
+        "Imported {} table schema(s) from '{}' into the cache.",
+        count, input_file
+    );
     Ok(())
 }
 
@@ -609,11 +985,17 @@ pub fn remove_dynein_files() -> Result<(), DyneinConfigError> {
 // Otherwise, load table schema info from config file.
 // fn table_schema(region: &Region, config: &config::Config, table_overwritten: Option<String>) -> TableSchema {
 pub async fn table_schema(cx: &Context) -> TableSchema {
-    match cx.overwritten_table_name.to_owned() {
+    // With --no-cache, the schema of the table in use is retrieved by DescribeTable API instead of the cache.
+    let table_to_describe = if cx.no_cache {
+        Some(cx.effective_table_name())
+    } else {
+        cx.overwritten_table_name.to_owned()
+    };
+    match table_to_describe {
         // It's possible that users pass --table without calling `dy use` for any table. Thus collect all data from DescribeTable results.
         Some(table_name) => {
             // TODO: reduce # of DescribeTable API calls. table_schema function is called every time you do something.
-            let desc: TableDescription = control::describe_table_api(
+            let desc: TableDescription = describe_table_or_exit(
                 cx, table_name, /* should be equal to 'cx.effective_table_name()' */
             )
             .await;
@@ -625,6 +1007,7 @@ pub async fn table_schema(cx: &Context) -> TableSchema {
                 sk: key::typed_key("RANGE", &desc),
                 indexes: index_schemas(&desc),
                 mode: table::extract_mode(&desc.billing_mode_summary),
+                cached_at: None,
             }
         }
         None => {
@@ -678,31 +1061,152 @@ pub fn index_schemas(desc: &TableDescription) -> Option<Vec<IndexSchema>> {
     if indexes.is_empty() {
         None
     } else {
+        // DescribeTable doesn't guarantee the order of indexes. Sort them to keep the cache file stable.
+        indexes.sort_by(|a, b| a.name.cmp(&b.name));
         Some(indexes)
     }
 }
 
+/// Enumerates profile names defined in AWS config (~/.aws/config) and credentials (~/.aws/credentials) files.
+/// As AWS CLI does, file locations can be overwritten by AWS_CONFIG_FILE and AWS_SHARED_CREDENTIALS_FILE environment variables.
+pub fn list_aws_profiles() -> Vec<String> {
+    let home = home::home_dir();
+    let mut profiles = BTreeSet::<String>::new();
+
+    for (env_var_name, file_name, is_config_file) in [
+        ("AWS_CONFIG_FILE", "config", true),
+        ("AWS_SHARED_CREDENTIALS_FILE", "credentials", false),
+    ] {
+        let file_path = match (env::var(env_var_name), &home) {
+            (Ok(p), _) => path::PathBuf::from(p),
+            (Err(_), Some(h)) => h.join(".aws").join(file_name),
+            (Err(_), None) => continue,
+        };
+        match fs::read_to_string(&file_path) {
+            Ok(content) => profiles.extend(parse_profile_names(&content, is_config_file)),
+            Err(e) => debug!("Skipped reading '{}': {}", file_path.display(), e),
+        }
+    }
+
+    profiles.into_iter().collect()
+}
+
+/// Whether to colorize human readable outputs, e.g. table statuses of `dy desc --output table`.
+/// Disabled when NO_COLOR is set (https://no-color.org/) or stdout is not a terminal, e.g. piped to other commands.
+pub fn color_enabled() -> bool {
+    env::var_os("NO_COLOR").map_or(true, |v| v.is_empty()) && io::stdout().is_terminal()
+}
+
+/// Asks users to confirm an operation, e.g. a destructive one. Returns true without asking when --yes is given.
+/// When stdin is not a terminal (e.g. in a pipe or CI), it refuses to proceed rather than waiting for input.
+pub fn confirm(cx: &Context, msg: &str) -> Result<bool, DyneinConfirmationError> {
+    if cx.assume_yes {
+        return Ok(true);
+    }
+    if !io::stdin().is_terminal() {
+        return Err(DyneinConfirmationError::NotInteractive);
+    }
+    Ok(Confirm::new().with_prompt(msg).interact()?)
+}
+
+/// Asks users to select one of the items, e.g. a backup to restore. Same as confirm, it refuses to proceed when stdin is not a terminal.
+/// Returns the index of the selected item.
+pub fn select<T: ToString>(prompt: &str, items: &[T]) -> Result<usize, DyneinConfirmationError> {
+    if !io::stdin().is_terminal() {
+        return Err(DyneinConfirmationError::NotInteractive);
+    }
+    Ok(Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .default(0)
+        .items(items)
+        .interact()?)
+}
+
+/// Fails when --interactive is given but stdin is not a terminal. It's checked before executing any command,
+/// so that no request is sent when confirmations would be refused later.
+pub fn ensure_interactive(cx: &Context) -> Result<(), DyneinConfirmationError> {
+    if cx.interactive && !io::stdin().is_terminal() {
+        return Err(DyneinConfirmationError::InteractiveRequired);
+    }
+    Ok(())
+}
+
+/// Prints the input of an API request instead of sending it, used by --dry-run option.
+/// The input struct of the SDK (e.g. CreateTableInput) is printed as it is, so that the output is faithful to the actual request.
+pub fn print_dry_run<T: fmt::Debug>(operation: &str, input: &T) {
+    println!(
+        "[dry-run] {} request has not been sent:\n{:#?}",
+        operation, input
+    );
+}
+
 pub fn bye(code: i32, msg: &str) -> ! {
     println!("{}", msg);
     std::process::exit(code);
 }
 
-pub fn bye_with_sdk_error<E, R>(code: i32, error: SdkError<E, R>) -> !
+pub fn bye_with_sdk_error<E, R>(cx: &Context, code: i32, error: SdkError<E, R>) -> !
 where
     E: fmt::Debug + ProvideErrorMetadata,
     R: fmt::Debug,
 {
+    // The operation timeout is derived from --deadline, but other timeouts (e.g. connect timeout) may expire before the deadline.
+    if matches!(error, SdkError::TimeoutError(_)) && cx.is_deadline_exceeded() {
+        error!("the command has been cancelled as it exceeded the deadline");
+        std::process::exit(DEADLINE_EXCEEDED_EXIT_CODE);
+    }
     match error.as_service_error() {
+        Some(service_error) if is_throttling_error_code(service_error.code()) => {
+            error!("request has been throttled: {:?}", service_error.meta());
+            error!("{}", THROTTLED_HINT);
+            std::process::exit(THROTTLED_EXIT_CODE);
+        }
         Some(service_error) => error!("service error occurred: {:?}", service_error.meta()),
         None => error!("an error occurred: {:?}", error),
     };
     std::process::exit(code);
 }
 
+/// Returns true if the given error code means that the request was throttled by DynamoDB.
+pub fn is_throttling_error_code(code: Option<&str>) -> bool {
+    code.map_or(false, |c| THROTTLING_ERROR_CODES.contains(&c))
+}
+
 /* =================================================
 Private functions
 ================================================= */
 
+/// Describes the table for functions which don't return errors to main.rs yet (e.g. table_schema used by data plane commands),
+/// hence it exits the process on errors.
+async fn describe_table_or_exit(cx: &Context, table_name: String) -> TableDescription {
+    match control::try_describe_table_api(cx, table_name).await {
+        Ok(desc) => desc,
+        Err(e) => {
+            debug!("DescribeTable API call got an error -- {:#?}", e);
+            bye_with_sdk_error(cx, 1, e);
+        }
+    }
+}
+
+/// Extracts profile names from section headers of an AWS config/credentials file.
+/// In the config file profiles are written as "[profile name]" (except "[default]"), and other sections such as "[sso-session name]" are ignored.
+/// In the credentials file every section is a profile, i.e. "[name]".
+fn parse_profile_names(content: &str, is_config_file: bool) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix('[')?.strip_suffix(']'))
+        .map(str::trim)
+        .filter_map(|section| {
+            if !is_config_file || section == "default" {
+                Some(section)
+            } else {
+                section.strip_prefix("profile ").map(str::trim)
+            }
+        })
+        .map(String::from)
+        .collect()
+}
+
 fn retrieve_dynein_file_path(file_type: DyneinFileType) -> Result<String, DyneinConfigError> {
     let filename = match file_type {
         DyneinFileType::ConfigFile => CONFIG_FILE_NAME,
@@ -782,6 +1286,27 @@ mod tests {
     use std::convert::TryInto;
     use std::error::Error;
 
+    #[test]
+    fn test_table_schema_is_stale() {
+        let schema = |cached_at: Option<String>| TableSchema {
+            region: String::from("local"),
+            name: String::from("tbl"),
+            pk: key::Key {
+                name: String::from("pk"),
+                kind: key::KeyType::S,
+            },
+            sk: None,
+            indexes: None,
+            mode: table::Mode::OnDemand,
+            cached_at,
+        };
+        let now = chrono::Utc::now();
+        assert!(schema(None).is_stale(DEFAULT_CACHE_TTL_SECS));
+        assert!(schema(Some(String::from("invalid"))).is_stale(DEFAULT_CACHE_TTL_SECS));
+        assert!(!schema(Some(now.to_rfc3339())).is_stale(DEFAULT_CACHE_TTL_SECS));
+        assert!(schema(Some((now - chrono::Duration::seconds(120)).to_rfc3339())).is_stale(60));
+    }
+
     #[tokio::test]
     async fn test_context_functions() -> Result<(), Box<dyn Error>> {
         let cx1 = Context {
@@ -790,9 +1315,20 @@ mod tests {
             overwritten_region: None,
             overwritten_table_name: None,
             overwritten_port: None,
+            overwritten_endpoint_url: None,
+            profile: None,
+            assume_role: None,
+            assumed_role_credentials: Arc::default(),
             output: None,
             should_strict_for_query: None,
             retry: None,
+            deadline: None,
+            max_retries: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            assume_yes: false,
+            interactive: false,
+            no_cache: false,
+            dry_run: false,
         };
         assert_eq!(
             &cx1.effective_region().await,
@@ -815,9 +1351,20 @@ mod tests {
             overwritten_region: None,
             overwritten_table_name: None,
             overwritten_port: None,
+            overwritten_endpoint_url: None,
+            profile: None,
+            assume_role: None,
+            assumed_role_credentials: Arc::default(),
             output: None,
             should_strict_for_query: None,
             retry: Some(RetrySettingGlobal::default().try_into()?),
+            deadline: None,
+            max_retries: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            assume_yes: false,
+            interactive: false,
+            no_cache: false,
+            dry_run: false,
         };
         assert_eq!(
             cx2.effective_region().await,
@@ -856,9 +1403,145 @@ mod tests {
         );
         assert_eq!(cx5.effective_table_name(), String::from("argtbl"));
 
+        let schema = TableSchema {
+            region: String::from("ap-northeast-1"),
+            name: String::from("cfgtbl"),
+            pk: key::Key {
+                name: String::from("pk"),
+                kind: key::KeyType::S,
+            },
+            sk: None,
+            indexes: None,
+            mode: table::Mode::OnDemand,
+            cached_at: None,
+        };
+        let cx6 = Context {
+            cache: Some(Cache {
+                tables: Some(BTreeMap::from([(
+                    String::from("ap-northeast-1/cfgtbl"),
+                    schema,
+                )])),
+            }),
+            ..cx2.clone()
+        };
+        assert_eq!(
+            cx6.cached_table_schema("cfgtbl")
+                .await
+                .map(|s| s.name.as_str()),
+            Some("cfgtbl")
+        );
+        assert!(cx6.cached_table_schema("argtbl").await.is_none());
+        assert_eq!(
+            cx6.cached_using_table_schema().await.map(|s| s.name),
+            Some(String::from("cfgtbl"))
+        );
+        assert!(cx6
+            .with_table("argtbl")
+            .cached_using_table_schema()
+            .await
+            .is_none());
+
         Ok(())
     }
 
+    #[test]
+    fn test_parse_profile_names() {
+        let config = "
+[default]
+region = us-east-1
+
+[profile dev]
+region = ap-northeast-1
+[ profile  prod ]
+[sso-session my-sso]
+sso_region = us-east-1
+";
+        assert_eq!(
+            parse_profile_names(config, true),
+            vec!["default", "dev", "prod"]
+        );
+
+        let credentials = "
+[default]
+aws_access_key_id = AKIA
+[staging]
+aws_access_key_id = AKIA
+";
+        assert_eq!(
+            parse_profile_names(credentials, false),
+            vec!["default", "staging"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_retries() -> Result<(), Box<dyn Error>> {
+        let cx = Context {
+            config: None,
+            cache: None,
+            overwritten_region: Some(Region::from_static("us-east-1")),
+            overwritten_table_name: None,
+            overwritten_port: None,
+            overwritten_endpoint_url: None,
+            profile: None,
+            assume_role: None,
+            assumed_role_credentials: Arc::default(),
+            output: None,
+            should_strict_for_query: None,
+            retry: Some(RetrySettingGlobal::default().try_into()?),
+            deadline: None,
+            max_retries: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            assume_yes: false,
+            interactive: false,
+            no_cache: false,
+            dry_run: false,
+        };
+        // max_attempts of the retry setting in the config file is used by default.
+        let config = cx.effective_sdk_config().await;
+        assert_eq!(config.retry_config().unwrap().max_attempts(), 10);
+
+        // --max-retries overrides it. The first attempt is not counted as a retry.
+        let config = cx.with_max_retries(Some(2)).effective_sdk_config().await;
+        assert_eq!(config.retry_config().unwrap().max_attempts(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_region_alias() {
+        assert_eq!(resolve_region_alias("use1"), "us-east-1");
+        assert_eq!(resolve_region_alias("Tokyo"), "ap-northeast-1");
+        assert_eq!(resolve_region_alias("us-west-2"), "us-west-2");
+        assert_eq!(resolve_region_alias("local"), "local");
+        assert_eq!(resolve_region_alias("unknown"), "unknown");
+        assert_eq!(
+            region_from_str(Some(String::from("apne1"))),
+            Some(Region::from_static("ap-northeast-1"))
+        );
+    }
+
+    #[test]
+    fn test_validate_region_name() {
+        assert!(validate_region_name("us-east-1").is_ok());
+        assert!(validate_region_name("ap-northeast-1").is_ok());
+        assert!(validate_region_name("us-gov-west-1").is_ok());
+        assert!(validate_region_name("us-east").is_err());
+        assert!(validate_region_name("useast1").is_err());
+        assert!(validate_region_name("US-EAST-1").is_err());
+        assert!(validate_region_name("us-east-x").is_err());
+        assert!(validate_region_name("us--1").is_err());
+    }
+
+    #[test]
+    fn test_is_throttling_error_code() {
+        assert!(is_throttling_error_code(Some("ThrottlingException")));
+        assert!(is_throttling_error_code(Some(
+            "ProvisionedThroughputExceededException"
+        )));
+        assert!(is_throttling_error_code(Some("RequestLimitExceeded")));
+        assert!(!is_throttling_error_code(Some("ResourceNotFoundException")));
+        assert!(!is_throttling_error_code(None));
+    }
+
     #[test]
     fn test_retry_setting_success() {
         let config1 = RetrySetting::default();
@@ -93,6 +93,14 @@ impl From<dialoguer::Error> for DyneinBatchError {
         }
     }
 }
+impl From<app::DyneinConfirmationError> for DyneinBatchError {
+    fn from(e: app::DyneinConfirmationError) -> Self {
+        match e {
+            app::DyneinConfirmationError::Dialoguer(e) => e.into(),
+            e @ app::DyneinConfirmationError::NotInteractive => Self::InvalidInput(e.to_string()),
+        }
+    }
+}
 
 /* =================================================
 Public functions
@@ -350,6 +358,61 @@ pub async fn batch_write_item(
     Ok(())
 }
 
+/// This function is intended to be called from main.rs, as a destination of `put --items` command.
+/// It receives a JSON array of items, either in standard JSON or DynamoDB JSON, validates primary key(s) of each item,
+/// and writes them with BatchWriteItem in chunks of 25 items, retrying UnprocessedItems.
+pub async fn put_items(cx: &app::Context, items: String) -> Result<(), DyneinBatchError> {
+    let items_jsonval: JsonValue = serde_json::from_str(&items)?;
+    let items_jsonval = items_jsonval.as_array().ok_or_else(|| {
+        DyneinBatchError::InvalidInput(String::from("--items should be a JSON array of items"))
+    })?;
+    let ts: app::TableSchema = app::table_schema(cx).await;
+
+    let mut write_requests = Vec::<WriteRequest>::new();
+    for item_jsonval in items_jsonval {
+        if !item_jsonval.is_object() {
+            return Err(DyneinBatchError::InvalidInput(format!(
+                "each item in --items should be a JSON object: {}",
+                item_jsonval
+            )));
+        }
+        let attrs = if is_ddbjson_item(item_jsonval) {
+            ddbjson_attributes_to_attrvals(item_jsonval)
+        } else {
+            item_jsonval
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(attr_name, body)| {
+                    (
+                        attr_name.to_string(),
+                        data::dispatch_jsonvalue_to_attrval(body, false),
+                    )
+                })
+                .collect()
+        };
+        validate_item_keys(&attrs, &ts)?;
+        write_requests.push(
+            WriteRequest::builder()
+                .put_request(PutRequest::builder().set_item(Some(attrs)).build().unwrap())
+                .build(),
+        );
+    }
+
+    for chunk in write_requests.chunks(25) {
+        // As BatchWriteItem request can have up to 25 items.
+        let request_items = HashMap::from([(ts.name.clone(), chunk.to_vec())]);
+        batch_write_until_processed(cx, request_items).await?;
+    }
+
+    println!(
+        "Successfully put {} items to the table '{}'.",
+        write_requests.len(),
+        &ts.name
+    );
+    Ok(())
+}
+
 /// This function takes cx (just for table name) and Vec<JsonValue>, where this JsonValue consists of multiple items as a standard JSON format,
 ///   then returns a HashMap from table name to Vec<WriteRequest>.
 ///   The returned HashMap can be used for a value of "RequestItems" parameter in BatchWriteItem API. https://docs.aws.amazon.com/amazondynamodb/latest/APIReference/API_BatchWriteItem.html
@@ -560,6 +623,24 @@ fn ddbjson_val_to_attrval(ddb_jsonval: &JsonValue) -> Option<AttributeValue> {
     }
 }
 
+/// Returns true when every attribute of the item is written in DynamoDB JSON, i.e. an object with a single data type descriptor
+/// such as `{"S": "foo"}` or `{"N": "42"}`. Otherwise the item is considered to be standard JSON.
+fn is_ddbjson_item(item_jsonval: &JsonValue) -> bool {
+    const DATA_TYPE_DESCRIPTORS: [&str; 10] =
+        ["S", "N", "B", "BOOL", "SS", "NS", "BS", "L", "M", "NULL"];
+    item_jsonval.as_object().map_or(false, |attrs| {
+        !attrs.is_empty()
+            && attrs.values().all(|body| {
+                body.as_object().map_or(false, |typed| {
+                    typed.len() == 1
+                        && typed
+                            .keys()
+                            .all(|descriptor| DATA_TYPE_DESCRIPTORS.contains(&descriptor.as_str()))
+                })
+            })
+    })
+}
+
 //  Decodes a base64 encoded binary value to Bytes.
 fn json_binary_val_to_bytes(v: &JsonValue) -> Bytes {
     Bytes::from(
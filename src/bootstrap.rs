@@ -18,7 +18,7 @@ use std::{
     collections::HashMap,
     error, fmt,
     io::{Cursor, Error as IOError, Read},
-    thread, time,
+    time,
 };
 
 use aws_sdk_dynamodb::{
@@ -35,6 +35,7 @@ use super::app;
 use super::batch;
 use super::control;
 use super::data;
+use super::ddb::table;
 
 /* =================================================
 struct / enum / const
@@ -288,10 +289,15 @@ https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/AppendixSampleT
 }
 
 async fn prepare_table(cx: &app::Context, table_name: &str, keys: &[&str]) {
+    let given_keys: Vec<String> = keys.iter().map(|k| (*k).to_string()).collect();
+    let (key_schema, attribute_definitions) =
+        table::generate_essential_key_definitions(&given_keys)
+            .expect("keys of sample tables should be valid");
     match control::create_table_api(
         cx,
         table_name.to_string(),
-        keys.iter().map(|k| (*k).to_string()).collect(),
+        key_schema,
+        attribute_definitions,
     )
     .await
     {
@@ -311,7 +317,7 @@ async fn prepare_table(cx: &app::Context, table_name: &str, keys: &[&str]) {
             ),
             _ => {
                 debug!("CreateTable API call got an error -- {:#?}", e);
-                app::bye_with_sdk_error(1, e);
+                app::bye_with_sdk_error(cx, 1, e);
             }
         },
     }
@@ -323,12 +329,18 @@ async fn wait_table_creation(cx: &app::Context, mut processing_tables: Vec<&str>
         let create_table_results = join_all(
             processing_tables
                 .iter()
-                .map(|t| control::describe_table_api(cx, (*t).to_string())),
+                .map(|t| control::try_describe_table_api(cx, (*t).to_string())),
         )
         .await;
         let statuses: Vec<String> = create_table_results
-            .iter()
-            .map(|desc| desc.table_status.to_owned().unwrap().to_string())
+            .into_iter()
+            .map(|result| match result {
+                Ok(desc) => desc.table_status.unwrap().to_string(),
+                Err(e) => {
+                    debug!("DescribeTable API call got an error -- {:#?}", e);
+                    app::bye_with_sdk_error(cx, 1, e);
+                }
+            })
             .collect();
         debug!("Current table statues: {:?}", statuses);
         processing_tables = processing_tables
@@ -343,6 +355,6 @@ async fn wait_table_creation(cx: &app::Context, mut processing_tables: Vec<&str>
             break;
         }
         println!("Waiting for tables to be ACTIVE status...");
-        thread::sleep(time::Duration::from_millis(5000));
+        tokio::time::sleep(time::Duration::from_millis(5000)).await;
     }
 }
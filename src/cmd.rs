@@ -18,6 +18,9 @@ use ::serde::{Deserialize, Serialize};
 use clap::{CommandFactory, FromArgMatches, Parser};
 use std::error::Error;
 use std::ffi::OsString;
+use std::io;
+
+use super::app;
 
 /* =================================================
 struct / enum / const
@@ -37,18 +40,89 @@ pub struct Dynein {
 
     /// The region to use (e.g. --region us-east-1). When using DynamodB Local, use `--region local`.
     /// You can use --region option in both top-level and subcommand-level.
-    #[clap(short, long, global = true, verbatim_doc_comment)]
+    /// Friendly aliases such as `use1` and `tokyo` are resolved into region names as well.
+    #[clap(short, long, global = true, value_parser = parse_region, verbatim_doc_comment)]
     pub region: Option<String>,
 
     /// Specify the port number. This option has an effect only when `--region local` is used.
     #[clap(short, long, global = true, verbatim_doc_comment)]
     pub port: Option<u32>,
 
+    /// Endpoint URL of DynamoDB, e.g. http://localhost:4566 for LocalStack. It takes precedence over the endpoint of the region (and --port).
+    #[clap(long, global = true, value_parser = parse_endpoint_url, verbatim_doc_comment)]
+    pub endpoint_url: Option<String>,
+
+    /// AWS named profile to use for credentials and the default region (e.g. --profile myprofile).
+    /// Same as AWS CLI, it takes precedence over credentials and profile given by environment variables such as AWS_ACCESS_KEY_ID and AWS_PROFILE.
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub profile: Option<String>,
+
+    /// ARN of an IAM role to assume with STS AssumeRole (e.g. --role-arn arn:aws:iam::123456789012:role/DyneinRole).
+    /// Credentials of --profile (or the default credentials) are used to assume the role, and API calls are made with temporary credentials of the role.
+    #[clap(long, global = true, value_parser = parse_role_arn, verbatim_doc_comment)]
+    pub role_arn: Option<String>,
+
+    /// External ID passed to AssumeRole, which may be required to assume a role of another account. Used with --role-arn.
+    #[clap(long, global = true, requires = "role_arn", verbatim_doc_comment)]
+    pub external_id: Option<String>,
+
+    /// Role session name passed to AssumeRole, which is recorded in CloudTrail (default: dynein). Used with --role-arn.
+    #[clap(
+        long,
+        global = true,
+        value_name = "NAME",
+        requires = "role_arn",
+        verbatim_doc_comment
+    )]
+    pub session_name: Option<String>,
+
     /// Target table of the operation. You can use --table option in both top-level and subcommand-level.
     /// You can store table schema locally by executing `$ dy use`, after that you need not to specify --table on every command.
     #[clap(short, long, global = true, verbatim_doc_comment)]
     pub table: Option<String>,
 
+    /// Overall deadline of the command in seconds, which caps the total wall-clock time including all API calls and their retries.
+    /// Once exceeded, in-flight operations are cancelled and dynein exits with code 124.
+    /// Unlike timeouts of each API call, the deadline is shared among all steps of a command (e.g. waiting for tables to be created).
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub deadline: Option<u64>,
+
+    /// Maximum number of retries of each API call on retriable errors (e.g. throttling and internal server errors), with exponential backoff and jitter.
+    /// It overrides max_attempts of the retry setting in the config file. Non-retriable errors (e.g. validation errors) fail immediately.
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub max_retries: Option<u32>,
+
+    /// Skip interactive confirmations before destructive operations, e.g. deleting tables, indexes or backups. Also available as --assume-yes.
+    /// Without this option, dynein refuses to proceed when stdin is not a terminal, instead of waiting for input.
+    #[clap(
+        short = 'y',
+        long,
+        alias = "assume-yes",
+        global = true,
+        verbatim_doc_comment
+    )]
+    pub yes: bool,
+
+    /// Ask for confirmations before destructive operations, which is the default without --yes. It cannot be used with --yes.
+    /// Unlike the default, dynein exits before sending any request when stdin is not a terminal, e.g. to make sure that the command is run by hand.
+    #[clap(long, conflicts_with = "yes", global = true, verbatim_doc_comment)]
+    pub interactive: bool,
+
+    /// Neither read nor write the local cache of table schemas (~/.dynein/cache.yml), e.g. when the file is not writable in CI.
+    /// Table schemas are retrieved by DescribeTable API every time instead.
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub no_cache: bool,
+
+    /// Print the request of mutating operations (e.g. creating/deleting tables and indexes, backup and restore) instead of sending it.
+    /// The request is printed as the input of the API, which is handy to review changes before applying them.
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub dry_run: bool,
+
+    /// Format of log messages written to stderr. `json` writes each message as a JSON object with timestamp, level, module and message fields, e.g. for log collectors.
+    /// The log level is controlled by RUST_LOG environment variable in both formats.
+    #[clap(long, global = true, value_parser = ["text", "json"], verbatim_doc_comment)]
+    pub log_format: Option<String>,
+
     #[clap(long, verbatim_doc_comment)]
     pub shell: bool,
 
@@ -58,11 +132,47 @@ pub struct Dynein {
     pub third_party_attribution: bool,
 }
 
+fn parse_endpoint_url(s: &str) -> Result<String, String> {
+    if s.starts_with("http://") || s.starts_with("https://") {
+        Ok(s.to_owned())
+    } else {
+        Err(String::from(
+            "endpoint URL should start with 'http://' or 'https://'",
+        ))
+    }
+}
+
+fn parse_role_arn(s: &str) -> Result<String, String> {
+    if s.starts_with("arn:") && s.contains(":role/") {
+        Ok(s.to_owned())
+    } else {
+        Err(String::from(
+            "role ARN should be in the form of 'arn:aws:iam::<account-id>:role/<role-name>'",
+        ))
+    }
+}
+
+/// Validates --region before building the context, so that a typo fails fast instead of at the first API call.
+/// "local" and aliases (e.g. "tokyo") are accepted, and the given string is kept as it is to be resolved later.
+fn parse_region(s: &str) -> Result<String, String> {
+    if s != app::LOCAL_REGION {
+        app::validate_region_name(&app::resolve_region_alias(s))?;
+    }
+    Ok(s.to_owned())
+}
+
 // NOTE: need to be placed in the same module as Dynein struct
 pub fn initialize_from_args() -> Dynein {
     Dynein::parse()
 }
 
+/// Prints a completion script of the given shell (e.g. "bash") for `dy` command to stdout, used by `$ dy completion`.
+pub fn print_completion(shell: &str) -> Result<(), Box<dyn Error>> {
+    let shell: clap_complete::Shell = shell.parse()?;
+    clap_complete::generate(shell, &mut Dynein::command(), "dy", &mut io::stdout());
+    Ok(())
+}
+
 pub fn parse_args<I, S>(input: I) -> Result<Sub, Box<dyn Error>>
 where
     I: IntoIterator<Item = S>,
@@ -93,22 +203,138 @@ pub enum Sub {
         /// List DynamoDB tables in all available regions
         #[clap(long, verbatim_doc_comment)]
         all_regions: bool,
+
+        /// Comma separated region names to iterate with --all-regions, e.g. us-east-1,eu-west-1.
+        /// The regions are used as they are, without calling EC2 DescribeRegions API (i.e. ec2:DescribeRegions permission is not required).
+        #[clap(
+            long,
+            value_name = "REGIONS",
+            value_delimiter = ',',
+            requires("all_regions"),
+            verbatim_doc_comment
+        )]
+        regions: Vec<String>,
+
+        /// List DynamoDB tables with every profile configured in AWS config files (~/.aws/config and ~/.aws/credentials)
+        #[clap(long, conflicts_with("all_regions"), verbatim_doc_comment)]
+        all_profiles: bool,
+
+        /// Exclude tables whose names start with the prefix, e.g. tables managed by other tools. Multiple prefixes can be specified by repeating the option.
+        /// e.g. --exclude-prefix amplify- --exclude-prefix sam-
+        #[clap(long = "exclude-prefix", value_name = "PREFIX", verbatim_doc_comment)]
+        exclude_prefixes: Vec<String>,
+
+        /// Show only tables whose names start with the prefix. It's applied before tables are described for --sort.
+        #[clap(long, value_name = "PREFIX", verbatim_doc_comment)]
+        prefix: Option<String>,
+
+        /// Sort tables by name, size or items. Sorting by size or items calls DescribeTable API for each table,
+        /// and the largest table comes first.
+        #[clap(long, value_parser = ["name", "size", "items"], conflicts_with("all_profiles"), verbatim_doc_comment)]
+        sort: Option<String>,
+
+        /// Show size and item count of each table in aligned columns, e.g. as a quick inventory. It calls DescribeTable API for each table.
+        /// Note that DynamoDB updates them approximately every six hours.
+        #[clap(long, conflicts_with("all_profiles"), verbatim_doc_comment)]
+        show_size: bool,
+
+        /// Render each table with a Go template style format string instead of the default output.
+        /// Available fields are 'name' and 'region' (and 'size_bytes' and 'item_count' with --show-size). e.g. --format '{{.region}}/{{.name}}'
+        #[clap(long, conflicts_with("all_profiles"), verbatim_doc_comment)]
+        format: Option<String>,
+
+        /// Switch output format. `json` prints an array of objects with 'name', 'region' and 'is_current' fields, e.g. to pipe into jq.
+        /// With --all-regions, tables in all regions are printed as one array. With --show-size, 'size_bytes' and 'item_count' fields are added.
+        #[clap(short, long, value_parser = ["text", "json"], conflicts_with_all(["format", "all_profiles"]), verbatim_doc_comment)]
+        output: Option<String>,
+
+        /// Maximum number of regions (with --all-regions), profiles (with --all-profiles) or tables (with --sort size/items or --show-size) processed in parallel. Lower it to avoid throttling.
+        #[clap(long, default_value_t = app::DEFAULT_CONCURRENCY, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), verbatim_doc_comment)]
+        concurrency: usize,
     },
 
     // NOTE: this command is defined both in top-level and sub-subcommand of table family.
     /// Show detailed information of a table. [API: DescribeTable]
     #[clap(aliases = &["show", "describe", "info"], verbatim_doc_comment)]
     Desc {
-        /// Target table name. Optionally you may specify the target table by --table (-t) option.
-        target_table_to_desc: Option<String>,
+        /// Target table name(s). Optionally you may specify the target table by --table (-t) option.
+        /// When multiple tables are given, they are printed as one YAML multi-document (or JSON array with --output json).
+        /// A table ARN (e.g. arn:aws:dynamodb:us-east-1:123456789012:table/app_users) is accepted as well, and the table is described in its region.
+        target_table_to_desc: Vec<String>,
 
         /// Show details of all tables in the region
         #[clap(long, verbatim_doc_comment)]
         all_tables: bool,
 
-        /// Switch output format.
-        #[clap(short, long, value_parser = ["yaml" /*, "raw" */ ], verbatim_doc_comment)]
+        /// With --all-tables, wait for all tables to be described and print them as one document (YAML multi-document or JSON array) sorted by name,
+        /// instead of printing each table as soon as it's described.
+        #[clap(long, requires("all_tables"), verbatim_doc_comment)]
+        aggregate: bool,
+
+        /// Show details of the table with every profile configured in AWS config files (~/.aws/config and ~/.aws/credentials)
+        #[clap(long, conflicts_with("all_tables"), verbatim_doc_comment)]
+        all_profiles: bool,
+
+        /// Switch output format. `table` shows key facts of the table as aligned columns.
+        /// `raw` prints the complete TableDescription returned by DescribeTable API as JSON, including fields omitted in other formats.
+        #[clap(short, long, value_parser = ["yaml", "json", "table", "raw"], verbatim_doc_comment)]
         output: Option<String>,
+
+        /// Render the table description with a Go template style format string instead of --output.
+        /// Fields of the YAML output are available, and nested fields can be referred with dots. e.g. --format '{{.name}} {{.status}} {{.schema.pk}}'
+        #[clap(long, conflicts_with("output"), verbatim_doc_comment)]
+        format: Option<String>,
+
+        /// Show only primary keys of the table and its indexes in a compact form, skipping all other metadata.
+        #[clap(long, verbatim_doc_comment)]
+        only_keys: bool,
+
+        /// Print only the given top-level fields of the YAML/JSON output, separated by commas. e.g. --fields name,mode,size_bytes
+        #[clap(
+            long,
+            value_name = "FIELDS",
+            value_delimiter = ',',
+            conflicts_with_all(["format", "only_keys", "cache_only", "compare", "all_profiles"]),
+            verbatim_doc_comment
+        )]
+        fields: Option<Vec<String>>,
+
+        /// Read table schemas from the local cache (written by previous `dy desc`) without calling any API.
+        /// The cache holds only keys of tables and indexes, hence the output is the same as --only-keys.
+        #[clap(long, conflicts_with_all(["all_tables", "all_profiles", "format"]), verbatim_doc_comment)]
+        cache_only: bool,
+
+        /// With --cache-only, re-fetch table schemas by DescribeTable API and update the local cache before printing them.
+        /// Cached schemas older than cache_ttl in the config file (default: 1 day) are printed with a warning unless refreshed.
+        #[clap(long, requires("cache_only"), verbatim_doc_comment)]
+        refresh_cache: bool,
+
+        /// Compare schemas of the two given tables, i.e. keys, indexes, billing mode and stream settings, and print their differences.
+        /// Exits with a non-zero code when the schemas differ, e.g. to validate a migration in CI.
+        #[clap(long, conflicts_with_all(["all_tables", "all_profiles", "output", "format", "only_keys", "cache_only"]), verbatim_doc_comment)]
+        compare: bool,
+
+        /// Clear the screen and describe the table every given seconds (default: 3) until the table and its GSIs become ACTIVE,
+        /// e.g. to follow a table being created or a GSI being backfilled. Press Ctrl-C to stop watching.
+        #[clap(
+            long,
+            value_name = "SECONDS",
+            num_args = 0..=1,
+            default_missing_value = "3",
+            value_parser = clap::builder::RangedU64ValueParser::<u64>::new().range(1..),
+            conflicts_with_all(["all_tables", "all_profiles", "cache_only", "compare"]),
+            verbatim_doc_comment
+        )]
+        watch: Option<u64>,
+
+        /// Exit with code 3 when the table or any of its GSIs is not ACTIVE, e.g. to gate a CI step on the readiness of the table.
+        /// With --watch, the table is described until it becomes ACTIVE, and the code is 3 only if watching is stopped by Ctrl-C.
+        #[clap(long, conflicts_with_all(["all_tables", "all_profiles", "cache_only", "compare"]), verbatim_doc_comment)]
+        require_active: bool,
+
+        /// Maximum number of tables described in parallel with --all-tables or multiple table names, or profiles with --all-profiles. Lower it to avoid throttling.
+        #[clap(long, default_value_t = app::DEFAULT_CONCURRENCY, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), verbatim_doc_comment)]
+        concurrency: usize,
     },
 
     /* =================================================
@@ -224,8 +450,9 @@ pub enum Sub {
     /// Create a new item, or replace an existing item. [API: PutItem]
     #[clap(aliases = &["p"], verbatim_doc_comment)]
     Put {
-        /// Partition Key of the target item.
-        pval: String,
+        /// Partition Key of the target item. Not required when --items is given.
+        #[clap(required_unless_present = "items")]
+        pval: Option<String>,
         /// Sort Key of the target item (if any).
         sval: Option<String>,
 
@@ -233,6 +460,11 @@ pub enum Sub {
         /// e.g. --item '{"name": "John", "age": 18, "like": ["Apple", "Banana"]}'
         #[clap(short, long, verbatim_doc_comment)]
         item: Option<String>,
+
+        /// Put multiple items at once with BatchWriteItem, given as a JSON array of items. Each item must contain the primary key(s).
+        /// Items can be written in standard JSON or DynamoDB JSON. e.g. --items '[{"pk": "a", "n": 1}, {"pk": {"S": "b"}, "n": {"N": "2"}}]'
+        #[clap(long, conflicts_with_all(["pval", "sval", "item"]), verbatim_doc_comment)]
+        items: Option<String>,
     },
 
     /// Delete an existing item. [API: DeleteItem]
@@ -321,6 +553,13 @@ pub enum Sub {
         grandchild: ConfigSub,
     },
 
+    /// <sub> Share cached table schemas (cache.yml) between machines
+    #[clap(verbatim_doc_comment)]
+    Cache {
+        #[clap(subcommand, verbatim_doc_comment)]
+        grandchild: CacheSub,
+    },
+
     /// Create sample tables and load test data for bootstrapping
     #[clap(verbatim_doc_comment)]
     Bootstrap {
@@ -339,8 +578,13 @@ pub enum Sub {
     #[clap(verbatim_doc_comment)]
     Export {
         /// Output target filename where dynein exports data into.
-        #[clap(short, long, verbatim_doc_comment)]
-        output_file: String,
+        #[clap(
+            short,
+            long,
+            required_unless_present = "s3_bucket",
+            verbatim_doc_comment
+        )]
+        output_file: Option<String>,
 
         /// Data format for export items.{n}
         ///   json = JSON format with newline/indent.{n}
@@ -358,6 +602,19 @@ pub enum Sub {
         /// [csv] Export only Primary Key(s). Effective only when --format is 'csv'.
         #[clap(long, conflicts_with("attributes"), verbatim_doc_comment)]
         keys_only: bool,
+
+        /// [s3] Export the table into the S3 bucket in DynamoDB JSON format, instead of a local file. [API: ExportTableToPointInTime]
+        /// PITR (point-in-time recovery) must be enabled on the table, e.g. by `dy pitr enable`. Items are not read via Scan API.
+        #[clap(long, conflicts_with_all(["output_file", "format", "attributes", "keys_only"]), verbatim_doc_comment)]
+        s3_bucket: Option<String>,
+
+        /// [s3] Key prefix of exported objects in the S3 bucket.
+        #[clap(long, requires = "s3_bucket", verbatim_doc_comment)]
+        s3_prefix: Option<String>,
+
+        /// [s3] Point in time to export the table as of, in RFC3339 format, e.g. `2024-01-01T00:00:00Z`. By default, the current time is used.
+        #[clap(long, requires = "s3_bucket", verbatim_doc_comment)]
+        time: Option<String>,
     },
 
     /// Import items into a DynamoDB table from CSV/JSON file.
@@ -367,20 +624,40 @@ pub enum Sub {
     #[clap(verbatim_doc_comment)]
     Import {
         /// Filename contains DynamoDB items data. Specify appropriate format with --format option.
-        #[clap(short, long, verbatim_doc_comment)]
-        input_file: String,
+        #[clap(
+            short,
+            long,
+            required_unless_present = "s3_bucket",
+            verbatim_doc_comment
+        )]
+        input_file: Option<String>,
 
         /// Data format for import items.{n}
         ///   json = JSON format with newline/indent.{n}
         ///   jsonl = JSON Lines (http://jsonlines.org). i.e. one item per line.{n}
         ///   json-compact = JSON format, all items are packed in oneline.{n}
-        ///   csv = comma-separated values with header. Header columns are considered to be DynamoDB attributes.
-        #[clap(short, long, value_parser = ["csv", "json", "jsonl", "json-compact"], verbatim_doc_comment)]
+        ///   csv = comma-separated values with header. Header columns are considered to be DynamoDB attributes.{n}
+        ///   dynamodb_json = [s3] DynamoDB JSON format, same as exports to S3. The default with --s3-bucket.{n}
+        ///   ion = [s3] Amazon Ion format. Only csv, dynamodb_json and ion are acceptable with --s3-bucket.
+        #[clap(short, long, value_parser = ["csv", "json", "jsonl", "json-compact", "dynamodb_json", "ion"], verbatim_doc_comment)]
         format: Option<String>,
 
         /// Enable type inference for set types. This option is provided for backward compatibility.
         #[clap(long)]
         enable_set_inference: bool,
+
+        /// [s3] Create a new table (--table) from data in the S3 bucket, instead of importing a local file into an existing table. [API: ImportTable]
+        /// The new table is created in OnDemand mode with the keys given by --keys.
+        #[clap(long, requires = "keys", conflicts_with_all(["input_file", "enable_set_inference"]), verbatim_doc_comment)]
+        s3_bucket: Option<String>,
+
+        /// [s3] Key prefix of objects to import in the S3 bucket.
+        #[clap(long, requires = "s3_bucket", verbatim_doc_comment)]
+        s3_prefix: Option<String>,
+
+        /// [s3] Primary key(s) of the new table, in the same format as `dy admin create table`, e.g. `--keys myPk,S mySk,N`.
+        #[clap(short, long, num_args = 1..=2, requires = "s3_bucket", verbatim_doc_comment)]
+        keys: Vec<String>,
     },
 
     /// Take backup of a DynamoDB table using on-demand backup
@@ -395,6 +672,27 @@ pub enum Sub {
         /// List backups for all tables in the region
         #[clap(long, verbatim_doc_comment)]
         all_tables: bool,
+
+        /// Show at most the given number of the newest backups, newest first. Acceptable only with --list.
+        #[clap(long, requires = "list", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), verbatim_doc_comment)]
+        max_items: Option<usize>,
+
+        /// Delete existing AVAILABLE backups of the table (or all tables with --all-tables)
+        #[clap(long, conflicts_with = "list", verbatim_doc_comment)]
+        delete: bool,
+
+        /// Delete only backups created before the given time in RFC3339 format, e.g. `2024-01-01T00:00:00Z`. Acceptable only with --delete.
+        #[clap(long, requires = "delete", verbatim_doc_comment)]
+        before: Option<String>,
+
+        /// Name of the new backup. If not specified, default naming rule "<table-name>--dynein-<epoch>" would be used.
+        /// It should be 3-255 characters long, consisting of alphanumerics, '_', '-' and '.'.
+        #[clap(long, conflicts_with_all(["list", "delete"]), verbatim_doc_comment)]
+        name: Option<String>,
+
+        /// Wait until the new backup becomes AVAILABLE (up to 5 minutes), e.g. to restore or copy it right after.
+        #[clap(long, conflicts_with_all(["list", "delete"]), verbatim_doc_comment)]
+        wait: bool,
     },
 
     /// Restore a DynamoDB table from backup data
@@ -409,9 +707,189 @@ pub enum Sub {
         /// Name of the newly restored table. If not specified, default naming rule "<source-table-name>-restore-<timestamp>" would be used.
         #[clap(long, verbatim_doc_comment)]
         restore_name: Option<String>,
+
+        /// DynamoDB capacity mode of the restored table. Available values: [provisioned, ondemand]. By default, the mode of the source table is used.
+        /// When you restore into Provisioned mode, WCU and RCU must be given as well.
+        #[clap(short, long, value_parser = ["provisioned", "ondemand"], verbatim_doc_comment)]
+        mode: Option<String>,
+
+        /// WCU (write capacity units) for the restored table and its GSIs. Required with --mode provisioned, and acceptable only with it.
+        #[clap(long, verbatim_doc_comment)]
+        wcu: Option<i64>,
+
+        /// RCU (read capacity units) for the restored table and its GSIs. Required with --mode provisioned, and acceptable only with it.
+        #[clap(long, verbatim_doc_comment)]
+        rcu: Option<i64>,
+
+        /// Wait until the restored table becomes ACTIVE (up to 5 minutes) before showing the table description.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
+
+        /// Copy tags of the source table to the restored table, as backups don't carry tags. The source table must still exist.
+        /// Tags are added once the restored table becomes ACTIVE, hence it waits as --wait does.
+        #[clap(long, verbatim_doc_comment)]
+        copy_tags_to_restore: bool,
+    },
+
+    /// Create an empty table with the same schema as the source table. Data is not copied. [API: DescribeTable, CreateTable]
+    ///
+    /// Key schema, indexes (GSIs and LSIs), capacity mode (and capacity units) and stream settings are copied.
+    #[clap(verbatim_doc_comment)]
+    CloneSchema {
+        /// Name of the source table.
+        source_table: String,
+
+        /// Name of the new table to create.
+        new_table: String,
+
+        /// Wait until the new table becomes ACTIVE (up to 5 minutes) before showing the table description.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
+    },
+
+    /// Copy a table including its items into a new table, by taking an on-demand backup and restoring it. [API: CreateBackup, RestoreTableFromBackup]
+    ///
+    /// The backup taken for the copy is kept, which you can delete by `$ dy backup --delete`.
+    /// Copying a table into another region is not supported, as backups can be restored only in the same region.
+    #[clap(verbatim_doc_comment)]
+    CopyTable {
+        /// Name of the source table.
+        source_table: String,
+
+        /// Name of the new table to create.
+        new_table: String,
+
+        /// Region of the new table. Only the same region as the source table is supported for now.
+        #[clap(long, verbatim_doc_comment)]
+        dest_region: Option<String>,
+
+        /// Wait until the new table becomes ACTIVE (up to 5 minutes) before showing the table description.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
+    },
+
+    /// Create a replica of the table in another region, which makes the table a global table. [API: UpdateTable]
+    ///
+    /// For more details: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/GlobalTables.html
+    /// The region of the replica is given as an argument, as --region specifies the region of the table to replicate.
+    #[clap(verbatim_doc_comment)]
+    CreateReplica {
+        /// Region to create the replica in, e.g. us-west-2.
+        replica_region: String,
+    },
+
+    /// Delete a replica of the table in the given region, including its items. [API: UpdateTable]
+    ///
+    /// For more details: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/GlobalTables.html
+    /// The table and replicas in other regions are kept.
+    #[clap(verbatim_doc_comment)]
+    DeleteReplica {
+        /// Region of the replica to delete, e.g. us-west-2.
+        replica_region: String,
+    },
+
+    /// <sub> Manage PITR (point-in-time recovery) of a table
+    #[clap(verbatim_doc_comment)]
+    Pitr {
+        #[clap(subcommand, verbatim_doc_comment)]
+        grandchild: PitrSub,
+    },
+
+    /// <sub> Enable or disable DynamoDB Streams of a table
+    #[clap(verbatim_doc_comment)]
+    Stream {
+        #[clap(subcommand, verbatim_doc_comment)]
+        grandchild: StreamSub,
+    },
+
+    /// <sub> Add or list tags of a table
+    #[clap(verbatim_doc_comment)]
+    Tag {
+        #[clap(subcommand, verbatim_doc_comment)]
+        grandchild: TagSub,
+    },
+
+    /// Enable or disable TTL (Time to Live) of a table. [API: UpdateTimeToLive]
+    ///
+    /// For more details: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html
+    #[clap(verbatim_doc_comment)]
+    Ttl {
+        /// Name of the attribute that stores expiration time of items in epoch seconds (Number type).
+        #[clap(short, long, required_unless_present = "disable", verbatim_doc_comment)]
+        attribute: Option<String>,
+
+        /// Disable TTL of the table. If --attribute is not specified, the current TTL attribute is used.
+        #[clap(long, verbatim_doc_comment)]
+        disable: bool,
+    },
+
+    /// <sub> Enable or disable CloudWatch Contributor Insights of a table or a GSI
+    #[clap(verbatim_doc_comment)]
+    Insights {
+        #[clap(subcommand, verbatim_doc_comment)]
+        grandchild: InsightsSub,
+    },
+
+    /* =================================================
+    Utility commands
+    ================================================= */
+    /// Generate a completion script of dy command for the given shell
+    ///
+    /// The script is printed to stdout. e.g. for bash: `$ dy completion bash > ~/.local/share/bash-completion/completions/dy`
+    #[clap(verbatim_doc_comment)]
+    Completion {
+        /// Shell to generate the completion script for.
+        #[clap(value_parser = ["bash", "zsh", "fish", "powershell", "elvish"], verbatim_doc_comment)]
+        shell: String,
     },
 }
 
+impl Sub {
+    /// Returns true for commands that are known to be safe with --dry-run, i.e. read-only commands and the ones that print
+    /// their mutating requests instead of sending them. Other commands (including new ones) are rejected with --dry-run,
+    /// otherwise they would change resources or local files despite the option.
+    pub fn supports_dry_run(&self) -> bool {
+        matches!(
+            self,
+            Sub::Admin {
+                grandchild: AdminSub::List { .. }
+                    | AdminSub::Desc { .. }
+                    | AdminSub::Create { .. }
+                    | AdminSub::Update { .. }
+                    | AdminSub::Delete { .. }
+            } | Sub::List { .. }
+                | Sub::Desc { .. }
+                | Sub::Scan { .. }
+                | Sub::Get { .. }
+                | Sub::Query { .. }
+                | Sub::Config {
+                    grandchild: ConfigSub::Dump
+                }
+                | Sub::Bootstrap { list: true, .. }
+                | Sub::Export {
+                    s3_bucket: Some(_),
+                    ..
+                }
+                | Sub::Import {
+                    s3_bucket: Some(_),
+                    ..
+                }
+                | Sub::Backup { .. }
+                | Sub::Restore { .. }
+                | Sub::CopyTable { .. }
+                | Sub::CloneSchema { .. }
+                | Sub::CreateReplica { .. }
+                | Sub::DeleteReplica { .. }
+                | Sub::Pitr { .. }
+                | Sub::Stream { .. }
+                | Sub::Ttl { .. }
+                | Sub::Insights { .. }
+                | Sub::Tag { .. }
+                | Sub::Completion { .. }
+        )
+    }
+}
+
 #[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
 pub enum AdminSub {
     /// List tables in the region. [API: ListTables]
@@ -420,21 +898,137 @@ pub enum AdminSub {
         /// List DynamoDB tables in all available regions
         #[clap(long, verbatim_doc_comment)]
         all_regions: bool,
+
+        /// Comma separated region names to iterate with --all-regions, e.g. us-east-1,eu-west-1.
+        /// The regions are used as they are, without calling EC2 DescribeRegions API (i.e. ec2:DescribeRegions permission is not required).
+        #[clap(
+            long,
+            value_name = "REGIONS",
+            value_delimiter = ',',
+            requires("all_regions"),
+            verbatim_doc_comment
+        )]
+        regions: Vec<String>,
+
+        /// List DynamoDB tables with every profile configured in AWS config files (~/.aws/config and ~/.aws/credentials)
+        #[clap(long, conflicts_with("all_regions"), verbatim_doc_comment)]
+        all_profiles: bool,
+
+        /// Exclude tables whose names start with the prefix, e.g. tables managed by other tools. Multiple prefixes can be specified by repeating the option.
+        /// e.g. --exclude-prefix amplify- --exclude-prefix sam-
+        #[clap(long = "exclude-prefix", value_name = "PREFIX", verbatim_doc_comment)]
+        exclude_prefixes: Vec<String>,
+
+        /// Show only tables whose names start with the prefix. It's applied before tables are described for --sort.
+        #[clap(long, value_name = "PREFIX", verbatim_doc_comment)]
+        prefix: Option<String>,
+
+        /// Sort tables by name, size or items. Sorting by size or items calls DescribeTable API for each table,
+        /// and the largest table comes first.
+        #[clap(long, value_parser = ["name", "size", "items"], conflicts_with("all_profiles"), verbatim_doc_comment)]
+        sort: Option<String>,
+
+        /// Show size and item count of each table in aligned columns, e.g. as a quick inventory. It calls DescribeTable API for each table.
+        /// Note that DynamoDB updates them approximately every six hours.
+        #[clap(long, conflicts_with("all_profiles"), verbatim_doc_comment)]
+        show_size: bool,
+
+        /// Render each table with a Go template style format string instead of the default output.
+        /// Available fields are 'name' and 'region' (and 'size_bytes' and 'item_count' with --show-size). e.g. --format '{{.region}}/{{.name}}'
+        #[clap(long, conflicts_with("all_profiles"), verbatim_doc_comment)]
+        format: Option<String>,
+
+        /// Switch output format. `json` prints an array of objects with 'name', 'region' and 'is_current' fields, e.g. to pipe into jq.
+        /// With --all-regions, tables in all regions are printed as one array. With --show-size, 'size_bytes' and 'item_count' fields are added.
+        #[clap(short, long, value_parser = ["text", "json"], conflicts_with_all(["format", "all_profiles"]), verbatim_doc_comment)]
+        output: Option<String>,
+
+        /// Maximum number of regions (with --all-regions), profiles (with --all-profiles) or tables (with --sort size/items or --show-size) processed in parallel. Lower it to avoid throttling.
+        #[clap(long, default_value_t = app::DEFAULT_CONCURRENCY, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), verbatim_doc_comment)]
+        concurrency: usize,
     },
 
     /// Show detailed information of a table. [API: DescribeTable]
     #[clap(aliases = &["show", "describe", "info"], verbatim_doc_comment)]
     Desc {
-        /// Target table name. Optionally you may specify the target table by --table (-t) option.
-        target_table_to_desc: Option<String>,
+        /// Target table name(s). Optionally you may specify the target table by --table (-t) option.
+        /// When multiple tables are given, they are printed as one YAML multi-document (or JSON array with --output json).
+        /// A table ARN (e.g. arn:aws:dynamodb:us-east-1:123456789012:table/app_users) is accepted as well, and the table is described in its region.
+        target_table_to_desc: Vec<String>,
 
         /// Show details of all tables in the region
         #[clap(long, verbatim_doc_comment)]
         all_tables: bool,
 
-        /// Switch output format.
-        #[clap(short, long, value_parser = ["yaml" /*, "raw" */ ], verbatim_doc_comment)]
+        /// With --all-tables, wait for all tables to be described and print them as one document (YAML multi-document or JSON array) sorted by name,
+        /// instead of printing each table as soon as it's described.
+        #[clap(long, requires("all_tables"), verbatim_doc_comment)]
+        aggregate: bool,
+
+        /// Show details of the table with every profile configured in AWS config files (~/.aws/config and ~/.aws/credentials)
+        #[clap(long, conflicts_with("all_tables"), verbatim_doc_comment)]
+        all_profiles: bool,
+
+        /// Switch output format. `table` shows key facts of the table as aligned columns.
+        /// `raw` prints the complete TableDescription returned by DescribeTable API as JSON, including fields omitted in other formats.
+        #[clap(short, long, value_parser = ["yaml", "json", "table", "raw"], verbatim_doc_comment)]
         output: Option<String>,
+
+        /// Render the table description with a Go template style format string instead of --output.
+        /// Fields of the YAML output are available, and nested fields can be referred with dots. e.g. --format '{{.name}} {{.status}} {{.schema.pk}}'
+        #[clap(long, conflicts_with("output"), verbatim_doc_comment)]
+        format: Option<String>,
+
+        /// Show only primary keys of the table and its indexes in a compact form, skipping all other metadata.
+        #[clap(long, verbatim_doc_comment)]
+        only_keys: bool,
+
+        /// Print only the given top-level fields of the YAML/JSON output, separated by commas. e.g. --fields name,mode,size_bytes
+        #[clap(
+            long,
+            value_name = "FIELDS",
+            value_delimiter = ',',
+            conflicts_with_all(["format", "only_keys", "cache_only", "compare", "all_profiles"]),
+            verbatim_doc_comment
+        )]
+        fields: Option<Vec<String>>,
+
+        /// Read table schemas from the local cache (written by previous `dy desc`) without calling any API.
+        /// The cache holds only keys of tables and indexes, hence the output is the same as --only-keys.
+        #[clap(long, conflicts_with_all(["all_tables", "all_profiles", "format"]), verbatim_doc_comment)]
+        cache_only: bool,
+
+        /// With --cache-only, re-fetch table schemas by DescribeTable API and update the local cache before printing them.
+        /// Cached schemas older than cache_ttl in the config file (default: 1 day) are printed with a warning unless refreshed.
+        #[clap(long, requires("cache_only"), verbatim_doc_comment)]
+        refresh_cache: bool,
+
+        /// Compare schemas of the two given tables, i.e. keys, indexes, billing mode and stream settings, and print their differences.
+        /// Exits with a non-zero code when the schemas differ, e.g. to validate a migration in CI.
+        #[clap(long, conflicts_with_all(["all_tables", "all_profiles", "output", "format", "only_keys", "cache_only"]), verbatim_doc_comment)]
+        compare: bool,
+
+        /// Clear the screen and describe the table every given seconds (default: 3) until the table and its GSIs become ACTIVE,
+        /// e.g. to follow a table being created or a GSI being backfilled. Press Ctrl-C to stop watching.
+        #[clap(
+            long,
+            value_name = "SECONDS",
+            num_args = 0..=1,
+            default_missing_value = "3",
+            value_parser = clap::builder::RangedU64ValueParser::<u64>::new().range(1..),
+            conflicts_with_all(["all_tables", "all_profiles", "cache_only", "compare"]),
+            verbatim_doc_comment
+        )]
+        watch: Option<u64>,
+
+        /// Exit with code 3 when the table or any of its GSIs is not ACTIVE, e.g. to gate a CI step on the readiness of the table.
+        /// With --watch, the table is described until it becomes ACTIVE, and the code is 3 only if watching is stopped by Ctrl-C.
+        #[clap(long, conflicts_with_all(["all_tables", "all_profiles", "cache_only", "compare"]), verbatim_doc_comment)]
+        require_active: bool,
+
+        /// Maximum number of tables described in parallel with --all-tables or multiple table names, or profiles with --all-profiles. Lower it to avoid throttling.
+        #[clap(long, default_value_t = app::DEFAULT_CONCURRENCY, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), verbatim_doc_comment)]
+        concurrency: usize,
     },
 
     /// Create new DynamoDB table or GSI. [API: CreateTable, UpdateTable]
@@ -451,7 +1045,7 @@ pub enum AdminSub {
         target_type: UpdateSub,
     },
 
-    /// Delete a DynamoDB table or GSI. [API: DeleteTable]
+    /// Delete a DynamoDB table or GSI. [API: DeleteTable, UpdateTable]
     #[clap(verbatim_doc_comment)]
     Delete {
         #[clap(subcommand, verbatim_doc_comment)]
@@ -496,20 +1090,120 @@ pub enum CreateSub {
 
         /// (requried) Primary key(s) of the table. Key name followed by comma and data type (S/N/B).
         /// e.g. for Partition key only table: `--keys myPk,S`, and for Partition and Sort key table `--keys myPk,S mySk,N`
+        /// Key roles can be given explicitly as the third field, e.g. `--keys myPk,S,HASH mySk,N,RANGE`
         #[clap(short, long, required = true, num_args = 1..=2, verbatim_doc_comment)]
         keys: Vec<String>,
+
+        /// DynamoDB capacity mode of the new table. Available values: [provisioned, ondemand]. By default, OnDemand mode is used.
+        /// When you create a Provisioned mode table, you can pass WCU and RCU as well (NOTE: default capacity unit for Provisioned mode is 5).
+        #[clap(short, long, value_parser = ["provisioned", "ondemand"], verbatim_doc_comment)]
+        mode: Option<String>,
+
+        /// WCU (write capacity units) for the table. Acceptable only on Provisioned mode.
+        #[clap(long, verbatim_doc_comment)]
+        wcu: Option<i64>,
+
+        /// RCU (read capacity units) for the table. Acceptable only on Provisioned mode.
+        #[clap(long, verbatim_doc_comment)]
+        rcu: Option<i64>,
+
+        /// Create a GSI (global secondary index) along with the table, in the form of NAME:PK[:SK]. You can specify this option multiple times,
+        /// e.g. `--gsi rank_index:rank,N --gsi user_date_index:user,S:date,S`
+        #[clap(long = "gsi", value_name = "SPEC", verbatim_doc_comment)]
+        gsis: Vec<String>,
+
+        /// Attributes projected into the GSIs given by --gsi. Available values: [all, keys_only, include:<comma separated attributes>].
+        /// e.g. `--projection include:a,b`. By default, all attributes are projected.
+        #[clap(long, requires = "gsis", verbatim_doc_comment)]
+        projection: Option<String>,
+
+        /// WCU (write capacity units) for the GSIs given by --gsi. Acceptable only on Provisioned mode. By default, GSIs inherit capacity units of the table.
+        #[clap(long, requires = "gsis", verbatim_doc_comment)]
+        gsi_wcu: Option<i64>,
+
+        /// RCU (read capacity units) for the GSIs given by --gsi. Acceptable only on Provisioned mode. By default, GSIs inherit capacity units of the table.
+        #[clap(long, requires = "gsis", verbatim_doc_comment)]
+        gsi_rcu: Option<i64>,
+
+        /// Encrypt the table with a KMS key. By default, AWS managed key (aws/dynamodb) is used unless --kms-key-id is specified.
+        /// Without this option, the table is encrypted with an AWS owned key.
+        #[clap(long, verbatim_doc_comment)]
+        sse: bool,
+
+        /// ID, ARN, or alias of the KMS key to encrypt the table. Acceptable only with --sse.
+        #[clap(long, requires = "sse", verbatim_doc_comment)]
+        kms_key_id: Option<String>,
+
+        /// Tag of the table in the form of "key=value". You can specify this option multiple times, e.g. `--tag env=prod --tag team=db`.
+        #[clap(long = "tag", value_name = "KEY=VALUE", verbatim_doc_comment)]
+        tags: Vec<String>,
+
+        /// Wait until the table becomes ACTIVE (up to 5 minutes) before showing the table description.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
+
+        /// Skip checking whether a table with the same name already exists before calling CreateTable API.
+        #[clap(long, verbatim_doc_comment)]
+        force: bool,
+
+        /// Succeed without creating the table when a table with the same name already exists, e.g. in provisioning scripts run repeatedly.
+        /// The existing table is described, with a warning if its schema differs from the given one.
+        #[clap(long, verbatim_doc_comment)]
+        if_not_exists: bool,
     },
 
     /// Create new GSI (global secondary index) for a table with given primary key(s). [API: UpdateTable]
     #[clap(verbatim_doc_comment)]
     Index {
-        /// index name to create
-        index_name: String,
+        /// index name to create. Required unless --index is given.
+        #[clap(required_unless_present = "indexes")]
+        index_name: Option<String>,
 
         /// (requried) Primary key(s) of the index. Key name followed by comma and data type (S/N/B).
         /// e.g. for Partition key only table: `--keys myPk,S`, and for Partition and Sort key table `--keys myPk,S mySk,N`
-        #[clap(short, long, required = true, num_args = 1..=2, verbatim_doc_comment)]
+        /// Key roles can be given explicitly as the third field, e.g. `--keys myPk,S,HASH mySk,N,RANGE`
+        #[clap(short, long, required_unless_present = "indexes", num_args = 1..=2, verbatim_doc_comment)]
         keys: Vec<String>,
+
+        /// WCU (write capacity units) for the index. Acceptable only when the table is Provisioned mode.
+        /// By default, the index inherits capacity units of the table.
+        #[clap(long, verbatim_doc_comment)]
+        wcu: Option<i64>,
+
+        /// RCU (read capacity units) for the index. Acceptable only when the table is Provisioned mode.
+        /// By default, the index inherits capacity units of the table.
+        #[clap(long, verbatim_doc_comment)]
+        rcu: Option<i64>,
+
+        /// Attributes projected into the index. Available values: [all, keys_only, include]. By default, all attributes are projected.
+        /// With `include`, specify non-key attributes to project by --non-key-attributes option.
+        #[clap(long, value_parser = ["all", "keys_only", "include"], verbatim_doc_comment)]
+        projection: Option<String>,
+
+        /// Comma separated non-key attributes projected into the index, e.g. `--non-key-attributes a,b,c`. Acceptable only with --projection include.
+        #[clap(
+            long,
+            value_name = "ATTRIBUTES",
+            value_delimiter = ',',
+            verbatim_doc_comment
+        )]
+        non_key_attributes: Vec<String>,
+
+        /// Create multiple indexes instead of the index name argument, by repeating the option. Each index is given as NAME:KEYS[:PROJECTION],
+        /// where KEYS are key definitions of --keys joined by '+', and PROJECTION is all (default), keys_only or include=<comma separated attributes>.
+        /// e.g. --index rank_index:rank,N --index user_date_index:user,S+date,S:keys_only
+        /// DynamoDB creates only one GSI per UpdateTable call, hence indexes are created one by one, waiting for the previous one to become ACTIVE.
+        #[clap(
+            long = "index",
+            value_name = "SPEC",
+            conflicts_with_all(["index_name", "keys", "projection", "non_key_attributes"]),
+            verbatim_doc_comment
+        )]
+        indexes: Vec<String>,
+
+        /// Wait until the index finishes backfilling and becomes ACTIVE (up to 5 minutes) before showing the table description.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
     },
 }
 
@@ -533,11 +1227,19 @@ pub enum UpdateSub {
         /// RCU (read capacity units) for the table. Acceptable only on Provisioned mode.
         #[clap(long, verbatim_doc_comment)]
         rcu: Option<i64>,
-        // TODO: support following parameters
-        // - sse_enabled: bool, (default false) ... UpdateTable API
-        // - stream_enabled: bool, (default false) ... UpdateTable API
-        // - ttl_enabled: bool, UpdateTimeToLive API
-        // - pitr_enabled: bool, UpdateContinuousBackups API (PITR)
+
+        /// Table class of the table. Available values: [standard, standard_ia]. STANDARD_IA (Standard-Infrequent Access) lowers storage cost of rarely accessed data.
+        #[clap(long, value_parser = ["standard", "standard_ia"], verbatim_doc_comment)]
+        table_class: Option<String>,
+
+        /// ID, ARN, or alias of the KMS key to encrypt the table with, e.g. to rotate to another customer managed key.
+        #[clap(long, value_name = "KEY", verbatim_doc_comment)]
+        sse_kms_key: Option<String>,
+
+        /// Switch encryption of the table back to an AWS owned key. You'll be asked to confirm as it's a security-relevant change.
+        #[clap(long, conflicts_with = "sse_kms_key", verbatim_doc_comment)]
+        sse_disable: bool,
+        // NOTE: streams, TTL, and PITR are configured by `dy stream`, `dy ttl`, and `dy pitr` respectively.
     },
 }
 
@@ -546,16 +1248,21 @@ pub enum DeleteSub {
     /// Delete a DynamoDB table.
     #[clap(verbatim_doc_comment)]
     Table {
-        /// table name to delete
-        table_name_to_delete: String,
+        /// table name(s) to delete. Multiple tables are deleted in parallel after a single confirmation.
+        #[clap(required = true, verbatim_doc_comment)]
+        table_name_to_delete: Vec<String>,
 
-        /// Skip interactive confirmation before deleting a table.
-        #[clap(short, long, verbatim_doc_comment)]
-        yes: bool,
+        /// Wait until the table is deleted completely (up to 5 minutes), e.g. to recreate a table with the same name right after.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
+    },
+
+    /// Delete a GSI (global secondary index) of a table. [API: UpdateTable]
+    #[clap(verbatim_doc_comment)]
+    Index {
+        /// index name to delete
+        index_name_to_delete: String,
     },
-    // #[clap(verbatim_doc_comment)]
-    // Index {
-    // }
 }
 
 #[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
@@ -570,6 +1277,108 @@ pub enum ConfigSub {
     Clear,
 }
 
+#[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
+pub enum PitrSub {
+    /// Enable PITR (point-in-time recovery) of the table. [API: UpdateContinuousBackups]
+    ///
+    /// For more details: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/PointInTimeRecovery.html
+    #[clap(verbatim_doc_comment)]
+    Enable,
+
+    /// Disable PITR (point-in-time recovery) of the table. [API: UpdateContinuousBackups]
+    #[clap(verbatim_doc_comment)]
+    Disable,
+
+    /// Restore the table as of the given time into a new table. [API: RestoreTableToPointInTime]
+    #[clap(verbatim_doc_comment)]
+    Restore {
+        /// Time to restore in RFC3339 format, e.g. `2024-01-01T00:00:00Z`. It must be within the recoverable window shown by `dy desc`.
+        /// If not specified, the latest restorable time is used.
+        #[clap(long, verbatim_doc_comment)]
+        time: Option<String>,
+
+        /// Name of the newly restored table. If not specified, default naming rule "<source-table-name>-restore-<timestamp>" would be used.
+        #[clap(long, verbatim_doc_comment)]
+        restore_name: Option<String>,
+
+        /// Wait until the restored table becomes ACTIVE (up to 5 minutes) before showing the table description.
+        #[clap(long, verbatim_doc_comment)]
+        wait: bool,
+    },
+}
+
+#[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
+pub enum InsightsSub {
+    /// Enable CloudWatch Contributor Insights of the table. [API: UpdateContributorInsights]
+    ///
+    /// For more details: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/contributorinsights_HowItWorks.html
+    #[clap(verbatim_doc_comment)]
+    Enable {
+        /// Name of a GSI to enable Contributor Insights for, instead of the table.
+        #[clap(short, long, verbatim_doc_comment)]
+        index: Option<String>,
+    },
+
+    /// Disable CloudWatch Contributor Insights of the table. [API: UpdateContributorInsights]
+    #[clap(verbatim_doc_comment)]
+    Disable {
+        /// Name of a GSI to disable Contributor Insights for, instead of the table.
+        #[clap(short, long, verbatim_doc_comment)]
+        index: Option<String>,
+    },
+}
+
+#[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
+pub enum StreamSub {
+    /// Enable DynamoDB Streams of the table. [API: UpdateTable]
+    ///
+    /// For more details: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Streams.html
+    #[clap(verbatim_doc_comment)]
+    Enable {
+        /// Information written to the stream when an item is modified.
+        #[clap(long, default_value = "NEW_AND_OLD_IMAGES", value_parser = ["NEW_IMAGE", "OLD_IMAGE", "NEW_AND_OLD_IMAGES", "KEYS_ONLY"], verbatim_doc_comment)]
+        view: String,
+    },
+
+    /// Disable DynamoDB Streams of the table. [API: UpdateTable]
+    #[clap(verbatim_doc_comment)]
+    Disable,
+}
+
+#[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
+pub enum TagSub {
+    /// Add tags to the table. Existing tags with the same keys are overwritten. [API: TagResource]
+    #[clap(verbatim_doc_comment)]
+    Add {
+        /// Tags in the form of "key=value", e.g. `dy tag add env=prod team=db`.
+        #[clap(required = true, value_name = "KEY=VALUE", verbatim_doc_comment)]
+        tags: Vec<String>,
+    },
+
+    /// List tags of the table. [API: ListTagsOfResource]
+    #[clap(aliases = &["ls"], verbatim_doc_comment)]
+    List,
+}
+
+#[derive(Parser, Debug, Serialize, Deserialize, PartialEq)]
+pub enum CacheSub {
+    /// Export cached table schemas as JSON. Tables are sorted by keys so that the output can be committed and diffed in git.
+    #[clap(verbatim_doc_comment)]
+    Export {
+        /// Output filename. If not specified, the result is printed to stdout.
+        #[clap(short, long, verbatim_doc_comment)]
+        output_file: Option<String>,
+    },
+
+    /// Import table schemas exported by `dy cache export` and merge them into the local cache. Existing schemas of the same tables are overwritten.
+    #[clap(verbatim_doc_comment)]
+    Import {
+        /// Input filename that was generated by `dy cache export`.
+        #[clap(short, long, verbatim_doc_comment)]
+        input_file: String,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::{parse_args, Sub};
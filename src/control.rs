@@ -15,424 +15,2938 @@
  */
 
 // This module interact with DynamoDB Control Plane APIs
+use ::serde::Serialize;
 use aws_sdk_dynamodb::{
+    error::SdkError,
+    operation::{
+        create_table::{CreateTableError, CreateTableInput},
+        delete_table::{DeleteTableError, DeleteTableInput},
+        describe_table::DescribeTableError,
+        list_backups::ListBackupsError,
+        list_tables::ListTablesError,
+        restore_table_from_backup::RestoreTableFromBackupError,
+        tag_resource::TagResourceInput,
+        update_table::{builders::UpdateTableFluentBuilder, UpdateTableError},
+    },
+    primitives::DateTime,
     types::{
-        BackupStatus, BackupSummary, BillingMode, CreateGlobalSecondaryIndexAction,
-        GlobalSecondaryIndexUpdate, Projection, ProjectionType, ProvisionedThroughput,
-        TableDescription,
+        AttributeDefinition, BackupStatus, BackupSummary, BillingMode, BillingModeSummary,
+        ContinuousBackupsDescription, ContributorInsightsStatus, CreateGlobalSecondaryIndexAction,
+        CreateReplicationGroupMemberAction, DeleteGlobalSecondaryIndexAction,
+        DeleteReplicationGroupMemberAction, GlobalSecondaryIndex, GlobalSecondaryIndexDescription,
+        GlobalSecondaryIndexUpdate, IndexStatus, InputFormat, KeySchemaElement,
+        PointInTimeRecoveryDescription, PointInTimeRecoveryStatus, Projection, ProjectionType,
+        ProvisionedThroughput, ReplicationGroupUpdate, S3BucketSource, SseDescription,
+        SseSpecification, SseType, StreamSpecification, StreamViewType, TableClass,
+        TableCreationParameters, TableDescription, TableStatus, Tag, TimeToLiveDescription,
     },
     Client as DynamoDbSdkClient,
 };
-use aws_sdk_ec2::Client as Ec2SdkClient;
-use futures::future::join_all;
+use aws_sdk_ec2::{operation::describe_regions::DescribeRegionsError, Client as Ec2SdkClient};
+use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+use aws_smithy_types::error::{display::DisplayErrorContext, metadata::ProvideErrorMetadata};
+use futures::{future::join_all, stream, StreamExt};
 use log::{debug, error};
 use std::borrow::Cow::{Borrowed, Owned};
 use std::{
     io::{self, Error as IOError, Write},
     time,
 };
+use thiserror::Error;
 
-use dialoguer::{theme::ColorfulTheme, Confirm, Select};
 use tabwriter::TabWriter;
 
+use super::api;
 use super::app;
 use super::ddb::table;
+use super::template::{self, TemplateError};
 
 /* =================================================
-Public functions
+struct / enum / const
 ================================================= */
 
-pub async fn list_tables_all_regions(cx: &app::Context) {
-    // get all regions from us-east-1 regardless specified region
-    let config = cx
-        .clone()
-        .with_region("us-east-1")
-        .effective_sdk_config()
-        .await;
-    let ec2 = Ec2SdkClient::new(&config);
-    match ec2.describe_regions().send().await {
-        Err(e) => {
-            app::bye_with_sdk_error(1, e);
-        }
-        Ok(res) => {
-            join_all(
-                res.regions
-                    .expect("regions should exist") // Vec<Region>
-                    .iter()
-                    .map(|r| list_tables(cx, Some(r.region_name.as_ref().unwrap()))),
+/// Errors returned by control functions, instead of exiting the process deep inside them.
+/// main.rs prints the error and exits with a proper code, e.g. THROTTLED_EXIT_CODE for throttling errors
+/// and TABLE_NOT_ACTIVE_EXIT_CODE for TableNotActive. Other errors exit with 1.
+#[derive(Error, Debug)]
+pub enum DyneinControlError {
+    #[error("{0}")]
+    InvalidInput(String),
+    #[error("{0}")]
+    PartialFailure(String),
+    #[error("{0}")]
+    SchemaMismatch(String),
+    /// The table (or one of its GSIs) is not ACTIVE, returned by `$ dy desc --require-active`.
+    #[error("{0}")]
+    TableNotActive(String),
+    /// Timed out waiting for a resource to reach the expected state, e.g. `$ dy admin delete table --wait`.
+    #[error("{0}")]
+    WaitTimeout(String),
+    #[error("Failed to render --format template: {0}")]
+    Template(#[from] TemplateError),
+    #[error(transparent)]
+    IO(#[from] IOError),
+    #[error(transparent)]
+    Confirmation(#[from] app::DyneinConfirmationError),
+    #[error("Failed to update the cache file: {0}")]
+    Cache(#[from] app::DyneinConfigError),
+    #[error("ListTables API call failed: {}", DisplayErrorContext(.0))]
+    ListTables(SdkError<ListTablesError, HttpResponse>),
+    #[error("DescribeRegions API call failed: {}", DisplayErrorContext(.0))]
+    DescribeRegions(SdkError<DescribeRegionsError, HttpResponse>),
+    #[error("CreateTable API call failed: {}", DisplayErrorContext(.0))]
+    CreateTable(SdkError<CreateTableError, HttpResponse>),
+    #[error("DeleteTable API call failed: {}", DisplayErrorContext(.0))]
+    DeleteTable(SdkError<DeleteTableError, HttpResponse>),
+    #[error("ListBackups API call failed: {}", DisplayErrorContext(.0))]
+    ListBackups(SdkError<ListBackupsError, HttpResponse>),
+    #[error("DescribeTable API call failed: {}", DisplayErrorContext(.0))]
+    DescribeTable(SdkError<DescribeTableError, HttpResponse>),
+    #[error("RestoreTableFromBackup API call failed: {}", DisplayErrorContext(.0))]
+    RestoreTableFromBackup(SdkError<RestoreTableFromBackupError, HttpResponse>),
+    /// DescribeTable response lacks a field required to show the table, e.g. AttributeDefinitions.
+    #[error(transparent)]
+    IncompleteDescription(#[from] table::IncompleteDescriptionError),
+    /// Other API calls than the above, e.g. UpdateTable or TagResource.
+    #[error(transparent)]
+    Api(#[from] ApiError),
+}
+
+impl DyneinControlError {
+    /// Returns true if the error is a timeout of an API call, which means the deadline has passed when --deadline is given.
+    pub fn is_timeout(&self) -> bool {
+        use DyneinControlError::*;
+        match self {
+            ListTables(e) => matches!(e, SdkError::TimeoutError(_)),
+            DescribeRegions(e) => matches!(e, SdkError::TimeoutError(_)),
+            CreateTable(e) => matches!(e, SdkError::TimeoutError(_)),
+            DeleteTable(e) => matches!(e, SdkError::TimeoutError(_)),
+            ListBackups(e) => matches!(e, SdkError::TimeoutError(_)),
+            DescribeTable(e) => matches!(e, SdkError::TimeoutError(_)),
+            RestoreTableFromBackup(e) => matches!(e, SdkError::TimeoutError(_)),
+            Api(e) => e.timed_out,
+            _ => false,
+        }
+    }
+}
+
+/// An error of an API call which doesn't have a dedicated variant in DyneinControlError.
+/// The SDK error is not kept as it is generic over operations, but its error code is kept
+/// so that main.rs can exit with a proper code, e.g. THROTTLED_EXIT_CODE for throttling errors.
+#[derive(Error, Debug)]
+#[error("{operation} API call failed: {message}")]
+pub struct ApiError {
+    pub operation: &'static str,
+    pub code: Option<String>, // error code of the service, e.g. "ResourceNotFoundException"
+    pub timed_out: bool,      // whether the SDK gave up the request by a timeout
+    message: String,
+}
+
+impl ApiError {
+    pub fn new<E, R>(operation: &'static str, e: SdkError<E, R>) -> ApiError
+    where
+        E: std::error::Error + ProvideErrorMetadata + Send + Sync + 'static,
+        R: std::fmt::Debug + Send + Sync + 'static,
+    {
+        ApiError {
+            operation,
+            code: e.code().map(String::from),
+            timed_out: matches!(e, SdkError::TimeoutError(_)),
+            message: DisplayErrorContext(&e).to_string(),
+        }
+    }
+}
+
+/// Returns a closure to convert an SDK error into DyneinControlError::Api, to be used with map_err, e.g. `.map_err(api_error("UpdateTable"))?`.
+fn api_error<E, R>(operation: &'static str) -> impl FnOnce(SdkError<E, R>) -> DyneinControlError
+where
+    E: std::error::Error + ProvideErrorMetadata + Send + Sync + 'static,
+    R: std::fmt::Debug + Send + Sync + 'static,
+{
+    move |e| {
+        debug!("{} API call got an error -- {:#?}", operation, e);
+        DyneinControlError::Api(ApiError::new(operation, e))
+    }
+}
+
+/// A table shown by `dy list`, which is serialized as an element of the array with --output json.
+#[derive(Serialize, Debug)]
+struct ListedTable {
+    name: String,
+    region: String,
+    is_current: bool, // whether the table is the one currently used by `dy use`
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    size: Option<ListedTableSize>, // only with --show-size
+}
+
+/// Size and item count of a listed table, retrieved by DescribeTable API for --sort size/items and --show-size.
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq)]
+struct ListedTableSize {
+    size_bytes: i64,
+    item_count: i64,
+}
+
+/// Filter of table names given by --prefix and --exclude-prefix options of `dy list`.
+#[derive(Debug, Default)]
+pub struct TableNameFilter {
+    pub prefix: Option<String>,
+    pub exclude_prefixes: Vec<String>,
+}
+
+impl TableNameFilter {
+    /// Keeps tables whose names start with the prefix (if given) and don't start with any of the excluded prefixes.
+    fn apply(&self, table_names: Vec<String>) -> Vec<String> {
+        table_names
+            .into_iter()
+            .filter(|name| {
+                self.prefix
+                    .as_deref()
+                    .map_or(true, |prefix| name.starts_with(prefix))
+                    && !self
+                        .exclude_prefixes
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix.as_str()))
+            })
+            .collect()
+    }
+}
+
+/// Options of `dy list` given per command, i.e. how tables are sorted and printed.
+#[derive(Debug, Default)]
+pub struct ListTablesOptions {
+    pub format: Option<String>, // --format option (template)
+    pub sort: Option<String>,   // --sort option, i.e. name, size or items
+    pub show_size: bool,        // --show-size option
+}
+
+/// Options of `dy desc` given per command, i.e. how tables are printed and whether they must be ACTIVE.
+#[derive(Debug, Default)]
+pub struct DescribeTableOptions {
+    pub format: Option<String>,      // --format option (template)
+    pub only_keys: bool,             // --only-keys option
+    pub fields: Option<Vec<String>>, // --fields option
+    pub require_active: bool,        // --require-active option
+}
+
+/// A GSI to create by `dy admin create index`, given by the index name argument (with --keys etc.) or --index option.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexSpec {
+    pub name: String,
+    pub keys: Vec<String>,
+    pub projection: Option<String>, // all, keys_only or include. None means all.
+    pub non_key_attributes: Vec<String>,
+}
+
+impl IndexSpec {
+    /// Parses --index option in the form of NAME:KEYS[:PROJECTION], e.g. `user_date_index:user,S+date,S:keys_only`.
+    /// Key definitions are joined by '+', and attributes of include projection are given as `include=a,b`.
+    pub fn parse(spec: &str) -> Result<IndexSpec, String> {
+        let invalid = || {
+            format!(
+                "Invalid format for --index option: '{}'. Valid format is '--index myIndex:myPk,S+mySk,N:keys_only', where the projection is optional.",
+                spec
             )
-            .await;
+        };
+        let mut parts = spec.splitn(3, ':');
+        let name = parts.next().filter(|s| !s.is_empty()).ok_or_else(invalid)?;
+        let keys = parts.next().filter(|s| !s.is_empty()).ok_or_else(invalid)?;
+        let (projection, non_key_attributes) = match parts.next() {
+            None => (None, vec![]),
+            Some(p @ ("all" | "keys_only")) => (Some(p.to_owned()), vec![]),
+            Some(p) => match p.strip_prefix("include=") {
+                Some(attributes) if !attributes.is_empty() => (
+                    Some(String::from("include")),
+                    attributes.split(',').map(String::from).collect(),
+                ),
+                _ => {
+                    return Err(format!(
+                        "Invalid projection '{}' in --index option: '{}'. Available values: [all, keys_only, include=<comma separated attributes>].",
+                        p, spec
+                    ))
+                }
+            },
+        };
+        Ok(IndexSpec {
+            name: name.to_owned(),
+            keys: keys.split('+').map(String::from).collect(),
+            projection,
+            non_key_attributes,
+        })
+    }
 
-            if cx.is_local().await {
-                list_tables(cx, None).await;
+    /// Parses --gsi option of `dy admin create table` in the form of NAME:PK[:SK], e.g. `user_date_index:user,S:date,S`.
+    /// The projection is given by --projection option shared among GSIs, i.e. all, keys_only or include:<comma separated attributes>.
+    pub fn parse_gsi(spec: &str, projection: Option<&str>) -> Result<IndexSpec, String> {
+        let invalid = || {
+            format!(
+                "Invalid format for --gsi option: '{}'. Valid format is '--gsi myIndex:myPk,S:mySk,N', where the sort key is optional.",
+                spec
+            )
+        };
+        let mut parts = spec.splitn(3, ':');
+        let name = parts.next().filter(|s| !s.is_empty()).ok_or_else(invalid)?;
+        let pk = parts.next().filter(|s| !s.is_empty()).ok_or_else(invalid)?;
+        let mut keys = vec![pk.to_owned()];
+        if let Some(sk) = parts.next() {
+            if sk.is_empty() || sk.contains(':') {
+                return Err(invalid());
             }
+            keys.push(sk.to_owned());
         }
-    };
+        let (projection, non_key_attributes) = match projection {
+            None => (None, vec![]),
+            Some(p @ ("all" | "keys_only")) => (Some(p.to_owned()), vec![]),
+            Some(p) => match p.strip_prefix("include:") {
+                Some(attributes) if !attributes.is_empty() => (
+                    Some(String::from("include")),
+                    attributes.split(',').map(String::from).collect(),
+                ),
+                _ => {
+                    return Err(format!(
+                        "Invalid --projection '{}'. Available values: [all, keys_only, include:<comma separated attributes>].",
+                        p
+                    ))
+                }
+            },
+        };
+        Ok(IndexSpec {
+            name: name.to_owned(),
+            keys,
+            projection,
+            non_key_attributes,
+        })
+    }
+
+    /// Builds the action of UpdateTable API to create the index, along with attribute definitions of its keys.
+    /// Capacity units are not set here, as they depend on the mode of the table.
+    fn create_action(
+        self,
+    ) -> Result<(CreateGlobalSecondaryIndexAction, Vec<AttributeDefinition>), String> {
+        if self.keys.is_empty() || self.keys.len() >= 3 {
+            return Err(String::from(
+                "You should pass one or two key definitions with --keys option",
+            ));
+        };
+
+        // Map given string into "ProjectionType". Note that in cmd.rs clap already limits acceptable values. ALL is the default.
+        let projection_type: ProjectionType = match self.projection.as_deref() {
+            None | Some("all") => ProjectionType::All,
+            Some("keys_only") => ProjectionType::KeysOnly,
+            Some("include") => ProjectionType::Include,
+            Some(_) => panic!(
+                "You shouldn't see this message as --projection can takes only 'all', 'keys_only', or 'include'."
+            ),
+        };
+        match (&projection_type, self.non_key_attributes.is_empty()) {
+            (ProjectionType::Include, true) => {
+                return Err(String::from(
+                    "--non-key-attributes option is required with --projection include.",
+                ))
+            }
+            (ProjectionType::All | ProjectionType::KeysOnly, false) => {
+                return Err(String::from(
+                    "--non-key-attributes option is acceptable only with --projection include.",
+                ))
+            }
+            _ => (),
+        };
+
+        let (key_schema, attribute_definitions) =
+            table::generate_essential_key_definitions(&self.keys)?;
+        let action = CreateGlobalSecondaryIndexAction::builder()
+            .index_name(self.name)
+            .set_key_schema(Some(key_schema))
+            .projection(
+                Projection::builder()
+                    .projection_type(projection_type)
+                    .set_non_key_attributes(
+                        (!self.non_key_attributes.is_empty()).then_some(self.non_key_attributes),
+                    )
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        Ok((action, attribute_definitions))
+    }
+
+    /// Builds the GSI created along with the table by CreateTable API, along with attribute definitions of its keys.
+    fn create_table_gsi(
+        self,
+        provisioned_throughput: Option<ProvisionedThroughput>,
+    ) -> Result<(GlobalSecondaryIndex, Vec<AttributeDefinition>), String> {
+        let (action, attribute_definitions) = self.create_action()?;
+        let gsi = GlobalSecondaryIndex::builder()
+            .index_name(action.index_name)
+            .set_key_schema(Some(action.key_schema))
+            .set_projection(action.projection)
+            .set_provisioned_throughput(provisioned_throughput)
+            .build()
+            .expect("GSI should have index name and key schema");
+        Ok((gsi, attribute_definitions))
+    }
 }
 
-pub async fn list_tables(cx: &app::Context, override_region: Option<&str>) {
-    let table_names = list_tables_api(cx, override_region).await;
-    let region = cx.effective_region().await.to_string();
+/// Settings of a table created by [`create_table_api_with_options`] other than its name and keys.
+/// Capacity units must be given for Provisioned mode, and must not for OnDemand mode.
+#[derive(Debug, Clone)]
+pub struct CreateTableOptions {
+    pub mode: table::Mode,
+    pub provisioned_throughput: Option<ProvisionedThroughput>,
+    pub sse_specification: Option<SseSpecification>,
+    pub stream_specification: Option<StreamSpecification>,
+    pub tags: Option<Vec<Tag>>,
+    pub global_secondary_indexes: Option<Vec<GlobalSecondaryIndex>>,
+}
 
-    println!("DynamoDB tables in region: {}", region);
-    if table_names.is_empty() {
-        return println!("  No table in this region.");
+/// OnDemand mode, encrypted with an AWS owned key, without stream, tags nor GSIs, i.e. the defaults of DynamoDB.
+impl Default for CreateTableOptions {
+    fn default() -> Self {
+        CreateTableOptions {
+            mode: table::Mode::OnDemand,
+            provisioned_throughput: None,
+            sse_specification: None,
+            stream_specification: None,
+            tags: None,
+            global_secondary_indexes: None,
+        }
     }
+}
 
-    if let Some(table_in_config) = cx.cached_using_table_schema().await {
-        for table_name in table_names {
-            if region == table_in_config.region && table_name == table_in_config.name {
-                println!("* {}", table_name);
-            } else {
-                println!("  {}", table_name);
+impl CreateTableOptions {
+    fn validate(&self) -> Result<(), DyneinControlError> {
+        match (&self.mode, &self.provisioned_throughput) {
+            (table::Mode::Provisioned, None) => {
+                return Err(DyneinControlError::InvalidInput(String::from(
+                    "Capacity units are required to create a table in Provisioned mode.",
+                )))
+            }
+            (table::Mode::OnDemand, Some(_)) => {
+                return Err(DyneinControlError::InvalidInput(String::from(
+                    "Capacity units cannot be given to create a table in OnDemand mode.",
+                )))
             }
+            _ => (),
         }
-    } else {
-        debug!("No table information (currently using table) is found on config file");
-        for table_name in table_names {
-            println!("  {}", table_name)
+        // GSIs follow the mode of the table, i.e. each GSI of a Provisioned table needs its own capacity units.
+        for gsi in self.global_secondary_indexes.as_deref().unwrap_or_default() {
+            match (&self.mode, &gsi.provisioned_throughput) {
+                (table::Mode::Provisioned, None) => {
+                    return Err(DyneinControlError::InvalidInput(format!(
+                        "Capacity units are required for the GSI '{}' of a table in Provisioned mode.",
+                        gsi.index_name
+                    )))
+                }
+                (table::Mode::OnDemand, Some(_)) => {
+                    return Err(DyneinControlError::InvalidInput(format!(
+                        "Capacity units cannot be given to the GSI '{}' of a table in OnDemand mode.",
+                        gsi.index_name
+                    )))
+                }
+                _ => (),
+            }
         }
+        Ok(())
     }
-}
 
-/// Executed when you call `$ dy desc --all-tables`.
-/// Note that `describe_table` function calls are executed in parallel (async + join_all).
-pub async fn describe_all_tables(cx: &app::Context) {
-    let table_names = list_tables_api(cx, None).await;
-    join_all(table_names.into_iter().map(|t| describe_table(cx, Some(t)))).await;
+    /// Generates CreateTableInput sent by create_table_api_with_options, which is printed as it is by --dry-run.
+    fn create_table_input(
+        self,
+        name: &str,
+        key_schema: Vec<KeySchemaElement>,
+        attribute_definitions: Vec<AttributeDefinition>,
+    ) -> CreateTableInput {
+        let mut input = api::create_table_input(
+            name,
+            key_schema,
+            attribute_definitions,
+            self.provisioned_throughput,
+            self.sse_specification,
+            self.tags,
+        );
+        input.stream_specification = self.stream_specification;
+        input.global_secondary_indexes = self.global_secondary_indexes;
+        input
+    }
 }
 
-/// Executed when you call `$ dy desc (table)`. Retrieve TableDescription via describe_table_api function,
-/// then print them in convenient way using table::print_table_description function (default/yaml).
-pub async fn describe_table(cx: &app::Context, target_table_to_desc: Option<String>) {
-    debug!("context: {:#?}", &cx);
-    debug!("positional arg table name: {:?}", &target_table_to_desc);
-    let new_context = if let Some(t) = target_table_to_desc {
-        Owned(cx.clone().with_table(&t))
-    } else {
-        Borrowed(cx)
-    };
+/// Maximum number of DeleteBackup API calls in flight for `$ dy backup --delete`.
+const DELETE_BACKUPS_CONCURRENCY: usize = 8;
 
-    let desc: TableDescription =
-        describe_table_api(new_context.as_ref(), new_context.effective_table_name()).await;
-    debug!(
-        "Retrieved table to describe is: '{}' table in '{}' region.",
-        new_context.effective_table_name(),
-        new_context.effective_region().await.as_ref()
-    );
+/// Interval and timeout of polling DescribeTable API for `--wait` option.
+const WAIT_POLLING_INTERVAL: time::Duration = time::Duration::from_secs(3);
+const WAIT_TIMEOUT: time::Duration = time::Duration::from_secs(300);
 
-    // save described table info into cache for future use.
-    // Note that when this functiono is called from describe_all_tables, not all tables would be cached as calls are parallel.
-    match app::insert_to_table_cache(new_context.as_ref(), &desc).await {
-        Ok(_) => debug!("Described table schema was written to the cache file."),
-        Err(e) => println!(
-            "Failed to write table schema to the cache with follwoing error: {:?}",
-            e
-        ),
-    };
+/* =================================================
+Public functions
+================================================= */
 
-    match new_context.output.as_deref() {
-        None | Some("yaml") => {
-            table::print_table_description(new_context.effective_region().await.as_ref(), &desc)
+/// Executed when you call `$ dy list --all-regions`. When regions are given by --regions option,
+/// only the regions are iterated without calling EC2 DescribeRegions API.
+/// Regions are processed with bounded concurrency given by --concurrency option, in order to avoid throttling.
+/// With --output json, tables of all regions are printed as one JSON array.
+pub async fn list_tables_all_regions(
+    cx: &app::Context,
+    regions: &[String],
+    filter: &TableNameFilter,
+    options: &ListTablesOptions,
+) -> Result<(), DyneinControlError> {
+    // None means the region of the context, i.e. DynamoDB Local when `--region local` is given.
+    let mut target_regions: Vec<Option<String>> = vec![];
+    if !regions.is_empty() {
+        for region in regions {
+            let region = app::resolve_region_alias(region);
+            app::validate_region_name(&region).map_err(DyneinControlError::InvalidInput)?;
+            target_regions.push(Some(region));
+        }
+    } else {
+        // get all regions from us-east-1 regardless specified region. --endpoint-url is for DynamoDB, so it's not used for EC2.
+        let config = cx
+            .clone()
+            .with_region("us-east-1")
+            .with_endpoint_url(None)
+            .effective_sdk_config()
+            .await;
+        let ec2 = Ec2SdkClient::new(&config);
+        let res = ec2
+            .describe_regions()
+            .send()
+            .await
+            .map_err(DyneinControlError::DescribeRegions)?;
+        for r in res.regions.expect("regions should exist") {
+            target_regions.push(r.region_name);
         }
-        // Some("raw") => println!("{:#?}", desc),
-        Some(_) => {
-            println!("ERROR: unsupported output type.");
-            std::process::exit(1);
+        // Regions returned by DescribeRegions are not ordered, hence sort them to print in a stable order.
+        target_regions.sort();
+        if cx.is_local().await {
+            target_regions.push(None);
         }
     }
-}
 
-/// Originally intended to be called by describe_table function, which is called from `$ dy desc`,
-/// however it turned out that DescribeTable API result is useful in various logic, separated API into this standalone function.
-pub async fn describe_table_api(cx: &app::Context, table_name: String) -> TableDescription {
-    let region = cx.effective_region().await;
-    let config = cx.effective_sdk_config_with_region(region.as_ref()).await;
-    let ddb = DynamoDbSdkClient::new(&config);
+    // Tables are listed in parallel but printed after all regions complete, grouped by region in the order of target_regions,
+    // so that outputs of regions don't interleave.
+    let results: Vec<Result<Vec<ListedTable>, DyneinControlError>> =
+        stream::iter(target_regions.iter())
+            .map(|r| listed_tables(cx, r.as_deref(), filter, options))
+            .buffered(cx.concurrency)
+            .collect()
+            .await;
 
-    match ddb.describe_table().table_name(table_name).send().await {
-        Err(e) => {
-            debug!("DescribeTable API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
-        }
-        Ok(res) => {
-            let desc: TableDescription = res.table.expect("This message should not be shown.");
-            debug!("Received DescribeTable Result: {:?}\n", desc);
-            desc
+    // A region that fails by itself (e.g. an opt-in region which is not enabled) is skipped with a warning, so that it doesn't prevent others from being printed.
+    // Fatal errors such as missing credentials abort the command, and so does a failure of all regions.
+    let mut listed: Vec<(Option<&str>, Vec<ListedTable>)> = vec![];
+    let mut first_error: Option<DyneinControlError> = None;
+    for (region, result) in target_regions.iter().zip(results) {
+        match result {
+            Ok(tables) => listed.push((region.as_deref(), tables)),
+            Err(e) => match region_skip_reason(&e) {
+                Some(reason) => {
+                    debug!("Failed to list tables in region {:?}: {:?}", region, e);
+                    eprintln!(
+                        "WARN: skipping {}: {}",
+                        region.as_deref().unwrap_or("local"),
+                        reason
+                    );
+                    if first_error.is_none() {
+                        first_error = Some(e);
+                    }
+                }
+                None => return Err(e),
+            },
         }
     }
+    if let (Some(e), true) = (first_error, listed.is_empty()) {
+        return Err(e);
+    }
+
+    if cx.output.as_deref() == Some("json") {
+        let tables: Vec<ListedTable> = listed.into_iter().flat_map(|(_, t)| t).collect();
+        println!("{}", serde_json::to_string_pretty(&tables).unwrap());
+        return Ok(());
+    }
+    for (region, tables) in listed {
+        print_listed_tables(cx, region, tables, options).await?;
+    }
+    Ok(())
 }
 
-/// This function is designed to be called from dynein command, mapped in main.rs.
-/// Note that it simply ignores --table option if specified. Newly created table name should be given by the 1st argument "name".
-pub async fn create_table(cx: &app::Context, name: String, given_keys: Vec<String>) {
-    if given_keys.is_empty() || given_keys.len() >= 3 {
-        error!("You should pass one or two key definitions with --keys option");
-        std::process::exit(1);
-    };
+/// Returns a short reason to skip the region in `dy list --all-regions` when listing tables in it failed,
+/// or None when the error is fatal for all regions, e.g. credentials cannot be loaded or the deadline is exceeded.
+fn region_skip_reason(e: &DyneinControlError) -> Option<String> {
+    match e {
+        DyneinControlError::ListTables(e) => sdk_error_skip_reason(e),
+        // DescribeTable API is called to sort tables by --sort.
+        DyneinControlError::DescribeTable(e) => sdk_error_skip_reason(e),
+        _ => None,
+    }
+}
 
-    match create_table_api(cx, name, given_keys).await {
-        Ok(desc) => table::print_table_description(cx.effective_region().await.as_ref(), &desc),
-        Err(e) => {
-            debug!("CreateTable API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
+fn sdk_error_skip_reason<E: ProvideErrorMetadata>(e: &SdkError<E, HttpResponse>) -> Option<String> {
+    if let Some(service_error) = e.as_service_error() {
+        return Some(match service_error.code() {
+            // DynamoDB rejects requests in regions which are not enabled for the account with UnrecognizedClientException.
+            Some("UnrecognizedClientException") => String::from("opt-in required"),
+            code => format!(
+                "{} ({})",
+                service_error.message().unwrap_or("service error"),
+                code.unwrap_or("unknown error code")
+            ),
+        });
+    }
+    match e {
+        SdkError::DispatchFailure(f) if f.is_io() || f.is_timeout() => {
+            Some(String::from("DynamoDB endpoint is unreachable"))
         }
+        _ => None,
     }
 }
 
-pub async fn create_table_api(
+pub async fn list_tables(
     cx: &app::Context,
-    name: String,
-    given_keys: Vec<String>,
-) -> Result<
-    TableDescription,
-    aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::create_table::CreateTableError>,
-> {
-    debug!(
-        "Trying to create a table '{}' with keys '{:?}'",
-        &name, &given_keys
-    );
-
-    let (key_schema, attribute_definitions) =
-        table::generate_essential_key_definitions(&given_keys);
+    override_region: Option<&str>,
+    filter: &TableNameFilter,
+    options: &ListTablesOptions,
+) -> Result<(), DyneinControlError> {
+    let tables = listed_tables(cx, override_region, filter, options).await?;
+    print_listed_tables(cx, override_region, tables, options).await
+}
 
-    let config = cx.effective_sdk_config().await;
-    let ddb = DynamoDbSdkClient::new(&config);
+/// Prints tables listed in a region, i.e. the region given by override_region or the one of the context.
+async fn print_listed_tables(
+    cx: &app::Context,
+    override_region: Option<&str>,
+    tables: Vec<ListedTable>,
+    options: &ListTablesOptions,
+) -> Result<(), DyneinControlError> {
+    if cx.output.as_deref() == Some("json") {
+        println!("{}", serde_json::to_string_pretty(&tables).unwrap());
+        return Ok(());
+    }
 
-    ddb.create_table()
-        .table_name(name)
-        .billing_mode(BillingMode::PayPerRequest)
-        .set_key_schema(Some(key_schema))
-        .set_attribute_definitions(Some(attribute_definitions))
-        .send()
-        .await
-        .map(|res| {
-            res.table_description
-                .expect("Table Description returned from API should be valid.")
-        })
-}
+    if let Some(template) = options.format.as_deref() {
+        for table in tables {
+            let mut fields = serde_json::json!({ "name": table.name, "region": table.region });
+            if let Some(size) = table.size {
+                fields["size_bytes"] = serde_json::json!(size.size_bytes);
+                fields["item_count"] = serde_json::json!(size.item_count);
+            }
+            println!("{}", template::render(template, &fields)?);
+        }
+        return Ok(());
+    }
 
-pub async fn create_index(cx: &app::Context, index_name: String, given_keys: Vec<String>) {
-    if given_keys.is_empty() || given_keys.len() >= 3 {
-        error!("You should pass one or two key definitions with --keys option");
-        std::process::exit(1);
+    let region = match override_region {
+        Some(r) => r.to_owned(),
+        None => cx.effective_region().await.to_string(),
     };
-    debug!(
-        "Trying to create an index '{}' with keys '{:?}', on table '{}' ",
-        &index_name,
-        &given_keys,
-        &cx.effective_table_name()
-    );
-
-    let (key_schema, attribute_definitions) =
-        table::generate_essential_key_definitions(&given_keys);
+    println!("DynamoDB tables in region: {}", region);
+    if tables.is_empty() {
+        println!("  No table in this region.");
+        return Ok(());
+    }
+    if options.show_size {
+        return print_listed_tables_with_size(&tables).map_err(DyneinControlError::IO);
+    }
+    for table in tables {
+        if table.is_current {
+            println!("* {}", table.name);
+        } else {
+            println!("  {}", table.name);
+        }
+    }
+    Ok(())
+}
 
-    let config = cx.effective_sdk_config().await;
-    let ddb = DynamoDbSdkClient::new(&config);
+/// Prints tables with their size and item count in aligned columns, for `dy list --show-size`.
+fn print_listed_tables_with_size(tables: &[ListedTable]) -> Result<(), IOError> {
+    let mut tw = TabWriter::new(io::stdout());
+    tw.write_all(b"  Table\tSize\tItems\n")?;
+    for table in tables {
+        let size = table.size.unwrap_or_default();
+        let marker = if table.is_current { "*" } else { " " };
+        tw.write_all(
+            format!(
+                "{} {}\t{}\t{}\n",
+                marker,
+                table.name,
+                table::humanize_size(size.size_bytes),
+                size.item_count
+            )
+            .as_bytes(),
+        )?;
+    }
+    tw.flush()
+}
 
-    let create_gsi_action = CreateGlobalSecondaryIndexAction::builder()
-        .index_name(index_name)
-        .set_key_schema(Some(key_schema))
-        .projection(
-            Projection::builder()
-                .projection_type(ProjectionType::All)
-                .build(),
-        )
-        .set_provisioned_throughput(None) // TODO: assign default rcu/wcu if base table is Provisioned mode. currently it works only for OnDemand talbe.
-        .build()
-        .unwrap();
+/// Executed when you call `$ dy list --all-profiles`. ListTables API is called with each profile in AWS config files,
+/// and results are aggregated into one table with a profile column. A failure of a profile doesn't abort others, and failures are returned at the end.
+pub async fn list_tables_all_profiles(
+    cx: &app::Context,
+    filter: &TableNameFilter,
+) -> Result<(), DyneinControlError> {
+    let profiles = aws_profiles()?;
 
-    let gsi_update = GlobalSecondaryIndexUpdate::builder()
-        .create(create_gsi_action)
-        .build();
+    // Tables are listed in the region of each profile unless --region is given, with bounded concurrency given by --concurrency option.
+    let results: Vec<_> = stream::iter(profiles.iter())
+        .map(|profile| async move {
+            let cx = cx.clone().with_profile(profile);
+            let region = cx.effective_region().await.to_string();
+            (region, try_list_tables_api(&cx, None).await)
+        })
+        .buffered(cx.concurrency)
+        .collect()
+        .await;
 
-    match ddb
-        .update_table()
-        .table_name(cx.effective_table_name())
-        .set_attribute_definitions(Some(attribute_definitions))
-        .global_secondary_index_updates(gsi_update)
-        .send()
-        .await
-    {
-        Err(e) => {
-            debug!("UpdateTable API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
-        }
-        Ok(res) => {
-            debug!("Returned result: {:#?}", res);
-            table::print_table_description(
-                cx.effective_region().await.as_ref(),
-                &res.table_description.unwrap(),
-            );
+    let mut failures: Vec<String> = vec![];
+    let mut tw = TabWriter::new(io::stdout());
+    tw.write_all(((["Profile", "Region", "Table"].join("\t")) + "\n").as_bytes())?;
+    for (profile, (region, result)) in profiles.iter().zip(results) {
+        match result {
+            Ok(table_names) => {
+                for table_name in filter.apply(table_names) {
+                    tw.write_all([profile, &region, &table_name, "\n"].join("\t").as_bytes())?;
+                }
+            }
+            Err(e) => failures.push(format!(
+                "Failed to list tables with the profile '{}': {}",
+                profile,
+                DisplayErrorContext(e)
+            )),
         }
     }
+    tw.flush()?;
+
+    if !failures.is_empty() {
+        return Err(DyneinControlError::PartialFailure(failures.join("\n")));
+    }
+    Ok(())
 }
 
-pub async fn update_table(
+/// Executed when you call `$ dy desc --all-profiles`. The same table is described with each profile in AWS config files.
+/// Each description is printed as `dy desc` does (i.e. --output, --format and --fields are respected), headed by a YAML comment line
+/// that shows the profile. A failure of a profile doesn't abort others.
+pub async fn describe_table_all_profiles(
     cx: &app::Context,
-    table_name_to_update: String,
-    mode_string: Option<String>,
-    wcu: Option<i64>,
-    rcu: Option<i64>,
-) {
-    // Retrieve TableDescription of the table to update, current (before update) status.
-    let desc: TableDescription = describe_table_api(cx, table_name_to_update.clone()).await;
+    target_table_to_desc: Option<String>,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let profiles = aws_profiles()?;
+    let table_name = target_table_to_desc.unwrap_or_else(|| cx.effective_table_name());
 
-    // Map given string into "Mode" enum. Note that in cmd.rs clap already limits acceptable values.
-    let switching_to_mode: Option<table::Mode> = match mode_string {
-        None => None,
-        Some(ms) => match ms.as_str() {
-            "provisioned" => Some(table::Mode::Provisioned),
-            "ondemand"    => Some(table::Mode::OnDemand),
-            _ => panic!("You shouldn't see this message as --mode can takes only 'provisioned' or 'ondemand'."),
-        },
-    };
+    // The table is described in the region of each profile unless --region is given, as describe_table does with --profile.
+    // Profiles are processed with bounded concurrency given by --concurrency option.
+    let results: Vec<_> = stream::iter(profiles.iter())
+        .map(|profile| {
+            let table_name = table_name.clone();
+            async move {
+                fetch_described_table(
+                    &cx.clone().with_profile(profile),
+                    table_name,
+                    !options.only_keys,
+                )
+                .await
+            }
+        })
+        .buffered(cx.concurrency)
+        .collect()
+        .await;
 
-    // Configure ProvisionedThroughput struct based on argumsnts (mode/wcu/rcu).
-    let provisioned_throughput: Option<ProvisionedThroughput> = match &switching_to_mode {
-        // when --mode is not given, no mode switch happens. Check the table's current mode.
-        None => {
-            match table::extract_mode(&desc.billing_mode_summary) {
-                // When currently OnDemand mode and you're not going to change the it, set None for CU.
-                table::Mode::OnDemand => {
-                    if wcu.is_some() || rcu.is_some() {
-                        println!("Ignoring --rcu/--wcu options as the table mode is OnDemand.");
-                    };
-                    None
+    // The header is a YAML comment, hence it goes to stderr for JSON so as not to break the output.
+    let header_to_stderr = matches!(cx.output.as_deref(), Some("json") | Some("raw"));
+    let mut failures: Vec<String> = vec![];
+    for (profile, result) in profiles.iter().zip(results) {
+        match result {
+            Ok(described) => {
+                if header_to_stderr {
+                    eprintln!("# profile: {}", profile);
+                } else {
+                    println!("# profile: {}", profile);
                 }
-                // When currently Provisioned mode and you're not going to change the it,
-                // pass given rcu/wcu, and use current values if missing. Provisioned table should have valid capacity units so unwrap() here.
-                table::Mode::Provisioned => Some(
-                    ProvisionedThroughput::builder()
-                        .read_capacity_units(rcu.unwrap_or_else(|| {
-                            desc.provisioned_throughput
-                                .as_ref()
-                                .unwrap()
-                                .read_capacity_units
-                                .unwrap()
-                        }))
-                        .write_capacity_units(wcu.unwrap_or_else(|| {
-                            desc.provisioned_throughput
-                                .as_ref()
-                                .unwrap()
-                                .write_capacity_units
-                                .unwrap()
-                        }))
-                        .build()
-                        .unwrap(),
-                ),
+                print_described_table(cx, &described, options)?;
             }
+            Err(e) => failures.push(format!(
+                "Failed to describe the table '{}' with the profile '{}': {}",
+                table_name, profile, e
+            )),
         }
-        // When the user trying to switch mode.
-        Some(target_mode) => match target_mode {
-            // when switching Provisioned->OnDemand mode, ProvisionedThroughput can be None.
-            table::Mode::OnDemand => {
-                if wcu.is_some() || rcu.is_some() {
-                    println!("Ignoring --rcu/--wcu options as --mode ondemand.");
-                };
-                None
-            }
-            // when switching OnDemand->Provisioned mode, set given wcu/rcu, fill with "5" as a default if not given.
-            table::Mode::Provisioned => Some(
-                ProvisionedThroughput::builder()
-                    .read_capacity_units(rcu.unwrap_or(5))
-                    .write_capacity_units(wcu.unwrap_or(5))
-                    .build()
-                    .unwrap(),
-            ),
-        },
-    };
+    }
+    if !failures.is_empty() {
+        return Err(DyneinControlError::PartialFailure(failures.join("\n")));
+    }
+    Ok(())
+}
 
-    // TODO: support updating CU of the table with GSI. If the table has GSIs, you must specify CU for them at the same time.
-    // error message: One or more parameter values were invalid: ProvisionedThroughput must be specified for index: xyz_index,abc_index2
-    //   if table has gsi
-    //     build GlobalSecondaryIndexUpdates { [... current values ...] }
+/// Returns profiles configured in AWS config files for --all-profiles, or an error if there is none.
+fn aws_profiles() -> Result<Vec<String>, DyneinControlError> {
+    let profiles = app::list_aws_profiles();
+    if profiles.is_empty() {
+        return Err(DyneinControlError::InvalidInput(String::from(
+            "No profile found in AWS config files.",
+        )));
+    }
+    Ok(profiles)
+}
 
-    match update_table_api(
-        cx,
-        table_name_to_update,
-        switching_to_mode,
-        provisioned_throughput,
-    )
-    .await
-    {
-        Ok(desc) => table::print_table_description(cx.effective_region().await.as_ref(), &desc),
-        Err(e) => {
-            debug!("UpdateTable API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
-        }
+/// Validates --fields of `dy desc` before describing any table. Fields are selected from the YAML/JSON output,
+/// hence they cannot be combined with `--output table` or `--output raw`.
+pub fn validate_desc_fields(
+    cx: &app::Context,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let fields = match &options.fields {
+        Some(fields) => fields,
+        None => return Ok(()),
+    };
+    if matches!(cx.output.as_deref(), Some("table") | Some("raw")) {
+        return Err(DyneinControlError::InvalidInput(String::from(
+            "--fields supports only yaml and json for --output.",
+        )));
     }
+    table::validate_fields(fields).map_err(DyneinControlError::InvalidInput)
 }
 
-/// UpdateTable API accepts following parameters (ref: https://docs.aws.amazon.com/amazondynamodb/latest/APIReference/API_UpdateTable.html):
-///   * [x] TableName (required)
+/// Executed when you call `$ dy desc --all-tables`.
+/// Note that `describe_table` function calls are executed in parallel, up to the number given by --concurrency option.
+/// A failure of a table doesn't abort others. Failures are collected and summarized at the end, e.g. "2 failed: X (throttled)".
+/// With --aggregate, all tables are printed as one document sorted by name once all of them are described.
+pub async fn describe_all_tables(
+    cx: &app::Context,
+    aggregate: bool,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let mut table_names = list_tables_api(cx, None).await?;
+    if aggregate {
+        // describe_tables awaits all tables and prints them as one document in the given order.
+        table_names.sort();
+        return describe_tables(cx, table_names, options).await;
+    }
+    let total = table_names.len();
+    let mut failures: Vec<(String, DyneinControlError)> = stream::iter(table_names.into_iter())
+        .map(|t| async move { (t.clone(), describe_one_table(cx, t, false, options).await) })
+        .buffer_unordered(cx.concurrency)
+        .filter_map(|(t, result)| async move { result.err().map(|e| (t, e)) })
+        .collect()
+        .await;
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    failures.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Err(describe_failures_summary(total, failures))
+}
+
+/// Summarizes failures of `dy desc` for multiple tables, e.g. "described 8/10 tables; 2 failed: X (throttled), Y (not found)".
+/// When all tables have been described but some of them are not ACTIVE with --require-active, TableNotActive is returned instead.
+fn describe_failures_summary(
+    total: usize,
+    failures: Vec<(String, DyneinControlError)>,
+) -> DyneinControlError {
+    if failures
+        .iter()
+        .all(|(_, e)| matches!(e, DyneinControlError::TableNotActive(_)))
+    {
+        return DyneinControlError::TableNotActive(
+            failures
+                .iter()
+                .map(|(_, e)| e.to_string())
+                .collect::<Vec<String>>()
+                .join("\n"),
+        );
+    }
+    for (table_name, e) in &failures {
+        debug!("Failed to describe the table '{}': {:?}", table_name, e);
+    }
+    DyneinControlError::PartialFailure(format!(
+        "described {}/{} tables; {} failed: {}",
+        total - failures.len(),
+        total,
+        failures.len(),
+        failures
+            .iter()
+            .map(|(table_name, e)| format!("{} ({})", table_name, describe_failure_reason(e)))
+            .collect::<Vec<String>>()
+            .join(", ")
+    ))
+}
+
+/// Returns a short reason of a failure to describe a table, e.g. "throttled" or "not found", used in the summary of `dy desc --all-tables`.
+fn describe_failure_reason(e: &DyneinControlError) -> String {
+    match e {
+        DyneinControlError::DescribeTable(e) => match e.as_service_error() {
+            Some(service_error) if app::is_throttling_error_code(service_error.code()) => {
+                String::from("throttled")
+            }
+            Some(DescribeTableError::ResourceNotFoundException(_)) => String::from("not found"),
+            Some(service_error) => service_error
+                .code()
+                .unwrap_or("unknown error code")
+                .to_owned(),
+            None => DisplayErrorContext(e).to_string(),
+        },
+        DyneinControlError::TableNotActive(_) => String::from("not active"),
+        DyneinControlError::IncompleteDescription(e) => format!("missing {}", e.field),
+        e => e.to_string(),
+    }
+}
+
+/// Executed when you call `$ dy desc --cache-only`. Table schemas are read from the local cache file
+/// that is written by `dy desc` (and other commands describing tables), without calling any API.
+/// With refresh (--refresh-cache), tables are described by DescribeTable API and the cache is updated before printing.
+/// Schemas older than the cache TTL are printed with a warning as they may be stale.
+pub async fn describe_table_from_cache(
+    cx: &app::Context,
+    table_names: Vec<String>,
+    refresh: bool,
+) -> Result<(), DyneinControlError> {
+    if cx.no_cache {
+        return Err(DyneinControlError::InvalidInput(String::from(
+            "--cache-only cannot be used with --no-cache.",
+        )));
+    }
+    if matches!(cx.output.as_deref(), Some("table") | Some("raw")) {
+        return Err(DyneinControlError::InvalidInput(String::from(
+            "--cache-only supports only yaml and json for --output.",
+        )));
+    }
+
+    let table_names: Vec<String> = if table_names.is_empty() {
+        vec![cx.effective_table_name()]
+    } else {
+        table_names
+    };
+
+    let mut schemas: Vec<app::TableSchema> = vec![];
+    for table_name in table_names {
+        if refresh {
+            let desc = describe_table_api(cx, table_name).await?;
+            schemas.push(app::insert_to_table_cache(cx, &desc).await?);
+            continue;
+        }
+
+        match cx.cached_table_schema(&table_name).await {
+            Some(schema) => {
+                if schema.is_stale(cx.cache_ttl()) {
+                    eprintln!(
+                        "WARN: The cached schema of '{}' table in {} region was cached at {}, more than {} seconds ago, and may be stale. Pass --refresh-cache to re-fetch it.",
+                        schema.name,
+                        schema.region,
+                        schema.cached_at.as_deref().unwrap_or("unknown time"),
+                        cx.cache_ttl()
+                    );
+                }
+                schemas.push(schema.to_owned())
+            }
+            None => {
+                return Err(DyneinControlError::InvalidInput(format!(
+                    "No cached schema found for '{}' table in {} region. Run `dy desc {}` without --cache-only first to cache it.",
+                    table_name,
+                    cx.effective_region().await.as_ref(),
+                    table_name,
+                )))
+            }
+        }
+    }
+
+    table::print_cached_table_schemas(&schemas, cx.output.as_deref());
+    Ok(())
+}
+
+/// Executed when you call `$ dy desc (table1) (table2) ...` or `$ dy desc --all-tables --aggregate`.
+/// Tables are described with bounded concurrency (--concurrency option) in the same way as describe_table,
+/// and printed as one document (YAML multi-document or JSON array) in the given order.
+/// A failure of a table (e.g. not found) doesn't abort others. Failures are summarized at the end as describe_all_tables does.
+pub async fn describe_tables(
+    cx: &app::Context,
+    table_names: Vec<String>,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let results: Vec<_> = stream::iter(table_names.iter())
+        .map(|table_name| fetch_described_table(cx, table_name.clone(), false))
+        .buffered(cx.concurrency)
+        .collect()
+        .await;
+
+    let total = table_names.len();
+    let mut described: Vec<DescribedTable> = vec![];
+    let mut failures: Vec<(String, DyneinControlError)> = vec![];
+    for (table_name, result) in table_names.into_iter().zip(results) {
+        match result {
+            Ok(table) => described.push(table),
+            Err(e) => failures.push((table_name, e)),
+        }
+    }
+    print_described_tables(cx, &described, options)?;
+
+    // Same as describe_table, readiness is checked after printing descriptions.
+    if options.require_active {
+        for table in &described {
+            if let Err(e) = ensure_table_active(&table.name, &table.desc) {
+                failures.push((table.name.clone(), e));
+            }
+        }
+    }
+    if failures.is_empty() {
+        return Ok(());
+    }
+    Err(describe_failures_summary(total, failures))
+}
+
+/// Prints tables described by describe_tables as one document, or one by one with --format and --output table.
+fn print_described_tables(
+    cx: &app::Context,
+    described: &[DescribedTable],
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    if let Some(template) = options.format.as_deref() {
+        for table in described {
+            println!(
+                "{}",
+                table::render_table_description(
+                    &table.region,
+                    &table.desc,
+                    &table.settings,
+                    template,
+                    options.only_keys
+                )?
+            );
+        }
+    } else if cx.output.as_deref() == Some("table") {
+        // Tables are separated by a blank line.
+        for (i, table) in described.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            let rows = table::table_description_rows(
+                &table.region,
+                &table.desc,
+                &table.settings,
+                options.only_keys,
+            );
+            print_description_rows(&rows)?;
+        }
+    } else {
+        let descs: Vec<(&str, &TableDescription, &table::SupplementalSettings)> = described
+            .iter()
+            .map(|table| (table.region.as_str(), &table.desc, &table.settings))
+            .collect();
+        table::print_table_descriptions(
+            &descs,
+            cx.output.as_deref(),
+            options.only_keys,
+            options.fields.as_deref(),
+        );
+    }
+    Ok(())
+}
+
+/// Executed when you call `$ dy desc --compare (table1) (table2)`. Describes both tables and prints differences of their schemas,
+/// i.e. keys, indexes, billing mode and stream settings. Returns an error when they differ, so that the command can gate CI.
+pub async fn compare_tables(
+    cx: &app::Context,
+    table_names: Vec<String>,
+) -> Result<(), DyneinControlError> {
+    let (table_a, table_b) = match table_names.as_slice() {
+        [table_a, table_b] => (table_a, table_b),
+        _ => {
+            return Err(DyneinControlError::InvalidInput(String::from(
+                "--compare requires exactly two table names.",
+            )))
+        }
+    };
+    let (desc_a, desc_b) = futures::join!(
+        describe_table_api(cx, table_a.to_owned()),
+        describe_table_api(cx, table_b.to_owned())
+    );
+    let (desc_a, desc_b) = (desc_a?, desc_b?);
+
+    let differences = table::compare_table_schemas(&desc_a, &desc_b);
+    if differences.is_empty() {
+        println!(
+            "Schemas of the tables '{}' and '{}' are identical.",
+            table_a, table_b
+        );
+        return Ok(());
+    }
+    println!("--- {}\n+++ {}", table_a, table_b);
+    for difference in &differences {
+        println!("{}", difference);
+    }
+    Err(DyneinControlError::SchemaMismatch(format!(
+        "Schemas of the tables '{}' and '{}' differ in {} field(s).",
+        table_a,
+        table_b,
+        differences.len()
+    )))
+}
+
+/// Executed when you call `$ dy desc (table)`. Retrieve TableDescription via describe_table_api function,
+/// then print them in convenient way using table::print_table_description function (default/yaml).
+pub async fn describe_table(
+    cx: &app::Context,
+    target_table_to_desc: Option<String>,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    debug!("context: {:#?}", &cx);
+    debug!("positional arg table name: {:?}", &target_table_to_desc);
+    debug!("options: {:?}", &options);
+    let table_name = target_table_to_desc.unwrap_or_else(|| cx.effective_table_name());
+    describe_one_table(cx, table_name, !options.only_keys, options).await
+}
+
+/// Describes and prints a table, shared by describe_table and describe_all_tables.
+/// The table name is passed explicitly rather than cloning the context with `with_table`, as this function is called for every table by describe_all_tables.
+async fn describe_one_table(
+    cx: &app::Context,
+    table_name: String,
+    with_settings: bool,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let described = fetch_described_table(cx, table_name, with_settings).await?;
+    print_described_table(cx, &described, options)?;
+
+    if options.require_active {
+        ensure_table_active(&described.name, &described.desc)?;
+    }
+    Ok(())
+}
+
+/// A table described by `dy desc`, with the region where the table is, which differs from the one of the context for a table ARN.
+struct DescribedTable {
+    name: String,
+    region: String,
+    desc: TableDescription,
+    settings: table::SupplementalSettings,
+}
+
+/// Describes a table given to `dy desc` by its name or ARN, and writes its schema into the cache. It's shared by describe_table, describe_tables and watch_table.
+/// Supplemental settings (TTL, PITR and Contributor Insights) take three more API calls per table, hence they are retrieved only with_settings,
+/// i.e. for a single table without --watch nor --only-keys.
+async fn fetch_described_table(
+    cx: &app::Context,
+    table_name: String,
+    with_settings: bool,
+) -> Result<DescribedTable, DyneinControlError> {
+    // A table ARN (e.g. copied from the console) is described in its region. The context is cloned only in this case.
+    let (cx, table_name) = if table_name.starts_with("arn:") {
+        let (region, name) =
+            table::parse_table_arn(&table_name).map_err(DyneinControlError::InvalidInput)?;
+        (Owned(cx.clone().with_region(&region)), name)
+    } else {
+        (Borrowed(cx), table_name)
+    };
+    let cx: &app::Context = &cx;
+    let region = cx.effective_region().await;
+
+    let desc: TableDescription = try_describe_table_api(cx, table_name.clone())
+        .await
+        .map_err(DyneinControlError::DescribeTable)?;
+    // Checked before writing the cache, so that an incomplete response fails only this table, e.g. in `dy desc --all-tables`.
+    table::check_table_description(&desc)?;
+    debug!(
+        "Retrieved table to describe is: '{}' table in '{}' region.",
+        table_name,
+        region.as_ref()
+    );
+    let settings: table::SupplementalSettings = if with_settings {
+        describe_supplemental_settings(cx, &table_name).await
+    } else {
+        table::SupplementalSettings::default()
+    };
+
+    // save described table info into cache for future use.
+    // Note that when this function is called from describe_all_tables, not all tables would be cached as calls are parallel.
+    // The warning goes to stderr so as not to break the output, e.g. JSON of --output json or --aggregate.
+    match app::insert_to_table_cache(cx, &desc).await {
+        Ok(_) => debug!("Described table schema was written to the cache file."),
+        Err(e) => eprintln!(
+            "WARN: Failed to write table schema to the cache with following error: {:?}",
+            e
+        ),
+    };
+
+    Ok(DescribedTable {
+        name: table_name,
+        region: region.as_ref().to_owned(),
+        desc,
+        settings,
+    })
+}
+
+/// Returns TableNotActive unless the table and all of its GSIs are ACTIVE, so that scripts can gate on readiness by the exit code.
+fn ensure_table_active(
+    table_name: &str,
+    desc: &TableDescription,
+) -> Result<(), DyneinControlError> {
+    let table_status = desc.table_status.as_ref().map_or("UNKNOWN", |s| s.as_str());
+    let indexes_in_progress = indexes_in_progress(desc);
+    if desc.table_status == Some(TableStatus::Active) && indexes_in_progress.is_empty() {
+        Ok(())
+    } else if indexes_in_progress.is_empty() {
+        Err(DyneinControlError::TableNotActive(format!(
+            "The table '{}' is not ACTIVE (status: {}).",
+            table_name, table_status
+        )))
+    } else {
+        Err(DyneinControlError::TableNotActive(format!(
+            "The table '{}' is not ACTIVE (status: {}, indexes in progress: {}).",
+            table_name,
+            table_status,
+            indexes_in_progress.join(", ")
+        )))
+    }
+}
+
+/// Executed when you call `$ dy desc --watch`. Clears the screen and describes the table on every interval
+/// until the table and all of its GSIs become ACTIVE, or until the user stops it with Ctrl-C.
+pub async fn watch_table(
+    cx: &app::Context,
+    target_table_to_desc: Option<String>,
+    interval: time::Duration,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let table_name = target_table_to_desc.unwrap_or_else(|| cx.effective_table_name());
+    tokio::select! {
+        result = watch_table_until_active(cx, &table_name, interval, options) => result,
+        _ = tokio::signal::ctrl_c() => {
+            if options.require_active {
+                return Err(DyneinControlError::TableNotActive(format!(
+                    "Stopped watching the table '{}' before it became ACTIVE.",
+                    table_name
+                )));
+            }
+            eprintln!("\nStopped watching the table '{}'.", table_name);
+            Ok(())
+        }
+    }
+}
+
+async fn watch_table_until_active(
+    cx: &app::Context,
+    table_name: &str,
+    interval: time::Duration,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let term = console::Term::stdout();
+    loop {
+        let described = fetch_described_table(cx, table_name.to_owned(), false).await?;
+        let desc = &described.desc;
+        // The screen is cleared only for a terminal, so that a redirected output keeps every description.
+        if term.is_term() {
+            term.clear_screen()?;
+        }
+        print_described_table(cx, &described, options)?;
+
+        let table_status = desc.table_status.as_ref().map_or("UNKNOWN", |s| s.as_str());
+        let indexes_in_progress = indexes_in_progress(desc);
+        if desc.table_status == Some(TableStatus::Active) && indexes_in_progress.is_empty() {
+            eprintln!("The table '{}' is ACTIVE.", table_name);
+            return Ok(());
+        }
+        if indexes_in_progress.is_empty() {
+            eprintln!(
+                "Watching the table '{}' every {} seconds... (status: {}, press Ctrl-C to stop)",
+                table_name,
+                interval.as_secs(),
+                table_status
+            );
+        } else {
+            eprintln!(
+                "Watching the table '{}' every {} seconds... (status: {}, indexes in progress: {}, press Ctrl-C to stop)",
+                table_name,
+                interval.as_secs(),
+                table_status,
+                indexes_in_progress.join(", ")
+            );
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Describes GSIs which are not ACTIVE yet with their statuses, e.g. "idx (CREATING, backfilling)".
+fn indexes_in_progress(desc: &TableDescription) -> Vec<String> {
+    desc.global_secondary_indexes
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|idx| idx.index_status != Some(IndexStatus::Active))
+        .map(|idx| {
+            let status = idx.index_status.as_ref().map_or("UNKNOWN", |s| s.as_str());
+            let name = idx.index_name.as_deref().unwrap_or_default();
+            if idx.backfilling == Some(true) {
+                format!("{} ({}, backfilling)", name, status)
+            } else {
+                format!("{} ({})", name, status)
+            }
+        })
+        .collect()
+}
+
+/// Prints the table retrieved by fetch_described_table in the format given by --format or --output,
+/// with supplemental settings such as TTL and PITR.
+fn print_described_table(
+    cx: &app::Context,
+    described: &DescribedTable,
+    options: &DescribeTableOptions,
+) -> Result<(), DyneinControlError> {
+    let DescribedTable {
+        region,
+        desc,
+        settings,
+        ..
+    } = described;
+    if let Some(template) = options.format.as_deref() {
+        let rendered =
+            table::render_table_description(region, desc, settings, template, options.only_keys)?;
+        println!("{}", rendered);
+        return Ok(());
+    }
+
+    match cx.output.as_deref() {
+        output @ (None | Some("yaml") | Some("json") | Some("raw")) => {
+            table::print_table_description_with_output(
+                region,
+                desc,
+                settings,
+                output,
+                options.only_keys,
+                options.fields.as_deref(),
+            )
+        }
+        Some("table") => {
+            let rows = table::table_description_rows(region, desc, settings, options.only_keys);
+            print_description_rows(&rows)?;
+        }
+        Some(o) => {
+            return Err(DyneinControlError::InvalidInput(format!(
+                "unsupported output type: '{}'.",
+                o
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Prints rows built by table::table_description_rows as aligned columns, used by `$ dy desc --output table`.
+fn print_description_rows(rows: &[(String, String)]) -> Result<(), IOError> {
+    let colored = app::color_enabled();
+    let mut tw = TabWriter::new(io::stdout());
+    for (k, v) in rows {
+        // Statuses are in the last column, hence escape sequences of colors don't break the alignment.
+        let v = if colored {
+            table::colorize_status_in_row(k, v)
+        } else {
+            v.to_owned()
+        };
+        tw.write_all([k.as_str(), v.as_str()].join("\t").as_bytes())?;
+        tw.write_all(b"\n")?;
+    }
+    tw.flush()
+}
+
+/// Originally intended to be called by describe_table function, which is called from `$ dy desc`,
+/// however it turned out that DescribeTable API result is useful in various logic, separated API into this standalone function.
+pub async fn describe_table_api(
+    cx: &app::Context,
+    table_name: String,
+) -> Result<TableDescription, DyneinControlError> {
+    try_describe_table_api(cx, table_name).await.map_err(|e| {
+        debug!("DescribeTable API call got an error -- {:#?}", e);
+        DyneinControlError::DescribeTable(e)
+    })
+}
+
+/// Same as describe_table_api, but returns the error of the SDK as it is, e.g. to tell ResourceNotFoundException from others.
+pub async fn try_describe_table_api(
+    cx: &app::Context,
+    table_name: String,
+) -> Result<TableDescription, SdkError<DescribeTableError, HttpResponse>> {
+    let region = cx.effective_region().await;
+    let config = cx.effective_sdk_config_with_region(region.as_ref()).await;
+
+    api::describe_table(&config, &table_name).await.map(|desc| {
+        debug!("Received DescribeTable Result: {:?}\n", desc);
+        desc
+    })
+}
+
+/// This function is designed to be called from dynein command, mapped in main.rs.
+/// Note that it simply ignores --table option if specified. Newly created table name should be given by the 1st argument "name".
+#[allow(clippy::too_many_arguments)]
+pub async fn create_table(
+    cx: &app::Context,
+    name: String,
+    given_keys: Vec<String>,
+    mode_string: Option<String>,
+    wcu: Option<i64>,
+    rcu: Option<i64>,
+    gsis: Vec<IndexSpec>,
+    gsi_wcu: Option<i64>,
+    gsi_rcu: Option<i64>,
+    sse: bool,
+    kms_key_id: Option<String>,
+    tags: Vec<String>,
+    wait: bool,
+    force: bool,
+    if_not_exists: bool,
+) -> Result<(), DyneinControlError> {
+    if given_keys.is_empty() || given_keys.len() >= 3 {
+        return Err(DyneinControlError::InvalidInput(String::from(
+            "You should pass one or two key definitions with --keys option",
+        )));
+    };
+
+    let (key_schema, mut attribute_definitions) =
+        table::generate_essential_key_definitions(&given_keys)
+            .map_err(DyneinControlError::InvalidInput)?;
+    print_key_name_warnings(&key_schema);
+    let tags: Vec<Tag> = table::parse_tags(&tags).map_err(DyneinControlError::InvalidInput)?;
+
+    // Map given string into "Mode" enum. Note that in cmd.rs clap already limits acceptable values. OnDemand is the default.
+    let mode: table::Mode = match mode_string.as_deref() {
+        None | Some("ondemand") => table::Mode::OnDemand,
+        Some("provisioned") => table::Mode::Provisioned,
+        Some(_) => panic!(
+            "You shouldn't see this message as --mode can takes only 'provisioned' or 'ondemand'."
+        ),
+    };
+
+    // fill with "5" as a default if not given, same as switching OnDemand->Provisioned mode by update_table.
+    let provisioned_throughput: Option<ProvisionedThroughput> =
+        resolve_billing(Some(&mode), wcu, rcu, false)?;
+
+    // GSIs given by --gsi are created by the same CreateTable call, hence all of them are validated here beforehand.
+    let mut global_secondary_indexes: Vec<GlobalSecondaryIndex> = vec![];
+    if !gsis.is_empty() {
+        if mode == table::Mode::OnDemand && (gsi_wcu.is_some() || gsi_rcu.is_some()) {
+            return Err(DyneinControlError::InvalidInput(String::from(
+                "--gsi-wcu/--gsi-rcu options are acceptable only with --mode provisioned.",
+            )));
+        }
+        for (option, units) in [("--gsi-wcu", gsi_wcu), ("--gsi-rcu", gsi_rcu)] {
+            if let Some(units) = units.filter(|units| *units <= 0) {
+                return Err(DyneinControlError::InvalidInput(format!(
+                    "{} should be greater than 0, but {} was given.",
+                    option, units
+                )));
+            }
+        }
+        let gsi_provisioned_throughput = index_provisioned_throughput(
+            &mode,
+            provisioned_throughput
+                .as_ref()
+                .map(|pt| pt.read_capacity_units),
+            provisioned_throughput
+                .as_ref()
+                .map(|pt| pt.write_capacity_units),
+            gsi_wcu,
+            gsi_rcu,
+        );
+        for spec in gsis {
+            if global_secondary_indexes
+                .iter()
+                .any(|gsi| gsi.index_name == spec.name)
+            {
+                return Err(DyneinControlError::InvalidInput(format!(
+                    "The GSI '{}' is given more than once by --gsi option.",
+                    spec.name
+                )));
+            }
+            let (gsi, gsi_attribute_definitions) = spec
+                .create_table_gsi(gsi_provisioned_throughput.clone())
+                .map_err(DyneinControlError::InvalidInput)?;
+            print_key_name_warnings(&gsi.key_schema);
+            merge_attribute_definitions(&mut attribute_definitions, gsi_attribute_definitions)
+                .map_err(DyneinControlError::InvalidInput)?;
+            global_secondary_indexes.push(gsi);
+        }
+    }
+
+    // Without --sse, SSESpecification is not sent so that the table is encrypted with an AWS owned key as usual.
+    // With --sse, the table is encrypted with the given KMS key, or AWS managed key (aws/dynamodb) if no key is given.
+    let sse_specification: Option<SseSpecification> = if sse {
+        Some(
+            SseSpecification::builder()
+                .enabled(true)
+                .sse_type(SseType::Kms)
+                .set_kms_master_key_id(kms_key_id)
+                .build(),
+        )
+    } else {
+        None
+    };
+
+    // With --if-not-exists, an existing table is compared with the one to be created.
+    let expected = expected_table_description(
+        &name,
+        &key_schema,
+        &attribute_definitions,
+        &global_secondary_indexes,
+        &mode,
+    );
+
+    // Checking the table beforehand gives a clearer message than ResourceInUseException of CreateTable API.
+    // Errors other than the existence (e.g. lack of permission for DescribeTable) are left to CreateTable API.
+    if !force {
+        match try_describe_table_api(cx, name.clone()).await {
+            Ok(existing) if if_not_exists => {
+                report_existing_table(cx, &existing, &expected).await;
+                return Ok(());
+            }
+            Ok(_) => {
+                return Err(DyneinControlError::InvalidInput(format!(
+                    "Table '{}' already exists in {} region.",
+                    name,
+                    cx.effective_region().await
+                )))
+            }
+            Err(e) => debug!(
+                "Table existence check before CreateTable got an error -- {:#?}",
+                e
+            ),
+        }
+    }
+
+    let options = CreateTableOptions {
+        mode,
+        provisioned_throughput,
+        sse_specification,
+        stream_specification: None,
+        tags: (!tags.is_empty()).then_some(tags),
+        global_secondary_indexes: (!global_secondary_indexes.is_empty())
+            .then_some(global_secondary_indexes),
+    };
+    if cx.dry_run {
+        options.validate()?;
+        let input = options.create_table_input(&name, key_schema, attribute_definitions);
+        app::print_dry_run("CreateTable", &input);
+        return Ok(());
+    }
+
+    let desc = match create_table_api_with_options(
+        cx,
+        name.clone(),
+        key_schema,
+        attribute_definitions,
+        options,
+    )
+    .await
+    {
+        // The table may be created by others after the check above, or the check is skipped by --force.
+        Err(DyneinControlError::CreateTable(e))
+            if if_not_exists
+                && matches!(
+                    e.as_service_error(),
+                    Some(CreateTableError::ResourceInUseException(_))
+                ) =>
+        {
+            let existing = describe_table_api(cx, name.clone()).await?;
+            report_existing_table(cx, &existing, &expected).await;
+            return Ok(());
+        }
+        result => result?,
+    };
+    let desc = if wait {
+        wait_until_table_active(cx, &name).await?
+    } else {
+        desc
+    };
+    table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+    Ok(())
+}
+
+/// Builds the description of a table to be created by `$ dy admin create table`, to be compared with an existing table by --if-not-exists.
+fn expected_table_description(
+    name: &str,
+    key_schema: &[KeySchemaElement],
+    attribute_definitions: &[AttributeDefinition],
+    global_secondary_indexes: &[GlobalSecondaryIndex],
+    mode: &table::Mode,
+) -> TableDescription {
+    // BillingModeSummary is missing for tables that have always been in Provisioned mode.
+    let billing_mode_summary = (mode == &table::Mode::OnDemand).then(|| {
+        BillingModeSummary::builder()
+            .billing_mode(BillingMode::PayPerRequest)
+            .build()
+    });
+    let gsis: Vec<GlobalSecondaryIndexDescription> = global_secondary_indexes
+        .iter()
+        .map(|gsi| {
+            GlobalSecondaryIndexDescription::builder()
+                .index_name(&gsi.index_name)
+                .set_key_schema(Some(gsi.key_schema.clone()))
+                .set_projection(gsi.projection.clone())
+                .build()
+        })
+        .collect();
+    TableDescription::builder()
+        .table_name(name)
+        .set_key_schema(Some(key_schema.to_vec()))
+        .set_attribute_definitions(Some(attribute_definitions.to_vec()))
+        .set_billing_mode_summary(billing_mode_summary)
+        .set_global_secondary_indexes((!gsis.is_empty()).then_some(gsis))
+        .build()
+}
+
+/// Merges attribute definitions of GSI keys into the ones of the table, as CreateTable API rejects duplicated attribute definitions.
+/// An attribute used by multiple keys must have the same type in all of them.
+fn merge_attribute_definitions(
+    attribute_definitions: &mut Vec<AttributeDefinition>,
+    additional: Vec<AttributeDefinition>,
+) -> Result<(), String> {
+    for definition in additional {
+        match attribute_definitions
+            .iter()
+            .find(|d| d.attribute_name == definition.attribute_name)
+        {
+            Some(existing) if existing.attribute_type == definition.attribute_type => (),
+            Some(existing) => {
+                return Err(format!(
+                    "The attribute '{}' is defined with different types: {} and {}.",
+                    definition.attribute_name,
+                    existing.attribute_type.as_str(),
+                    definition.attribute_type.as_str()
+                ))
+            }
+            None => attribute_definitions.push(definition),
+        }
+    }
+    Ok(())
+}
+
+/// Treats the existing table as success of --if-not-exists, and prints its description.
+/// A warning is printed with the differences when its schema (i.e. keys, billing mode, stream and indexes) differs from the expected one.
+async fn report_existing_table(
+    cx: &app::Context,
+    existing: &TableDescription,
+    expected: &TableDescription,
+) {
+    let region = cx.effective_region().await;
+    let name = expected.table_name.as_deref().unwrap_or_default();
+    let differences = table::compare_table_schemas(expected, existing);
+    if differences.is_empty() {
+        println!(
+            "Table '{}' already exists in {} region with the same schema.",
+            name, region
+        );
+    } else {
+        eprintln!(
+            "WARN: Table '{}' already exists in {} region with a different schema:",
+            name, region
+        );
+        for difference in &differences {
+            eprintln!("  {}", difference);
+        }
+    }
+    table::print_table_description(region.as_ref(), existing);
+}
+
+/// Creates a table with given keys in OnDemand mode, i.e. with the default CreateTableOptions.
+/// Key schema and attribute definitions can be generated by `ddb::table::generate_essential_key_definitions`.
+pub async fn create_table_api(
+    cx: &app::Context,
+    name: String,
+    key_schema: Vec<KeySchemaElement>,
+    attribute_definitions: Vec<AttributeDefinition>,
+) -> Result<
+    TableDescription,
+    aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::create_table::CreateTableError>,
+> {
+    debug!(
+        "Trying to create a table '{}' with keys '{:?}'",
+        &name, &key_schema
+    );
+
+    let config = cx.effective_sdk_config().await;
+    let input =
+        CreateTableOptions::default().create_table_input(&name, key_schema, attribute_definitions);
+    api::create_table_with_input(&config, input).await
+}
+
+/// Creates a table with given keys and options, e.g. billing mode, capacity units, SSE, stream and tags.
+/// Inconsistent options (e.g. Provisioned mode without capacity units) are rejected before calling CreateTable API.
+pub async fn create_table_api_with_options(
+    cx: &app::Context,
+    name: String,
+    key_schema: Vec<KeySchemaElement>,
+    attribute_definitions: Vec<AttributeDefinition>,
+    options: CreateTableOptions,
+) -> Result<TableDescription, DyneinControlError> {
+    options.validate()?;
+    debug!(
+        "Trying to create a table '{}' with keys '{:?}' and options '{:?}'",
+        &name, &key_schema, &options
+    );
+
+    let config = cx.effective_sdk_config().await;
+    let input = options.create_table_input(&name, key_schema, attribute_definitions);
+    api::create_table_with_input(&config, input)
+        .await
+        .map_err(|e| {
+            debug!("CreateTable API call got an error -- {:#?}", e);
+            DyneinControlError::CreateTable(e)
+        })
+}
+
+/// Creates GSIs on the table given by --table option, via UpdateTable API with GlobalSecondaryIndexUpdates.
+/// DynamoDB accepts only one GSI creation per UpdateTable call, hence multiple indexes are created one by one,
+/// waiting for the previous index to become ACTIVE before requesting the next one.
+pub async fn create_index(
+    cx: &app::Context,
+    specs: Vec<IndexSpec>,
+    wcu: Option<i64>,
+    rcu: Option<i64>,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    // Validate all indexes before sending any request, so that a typo in the last index doesn't leave the table half-updated.
+    let mut actions: Vec<(CreateGlobalSecondaryIndexAction, Vec<AttributeDefinition>)> = vec![];
+    for spec in specs {
+        let action = spec
+            .create_action()
+            .map_err(DyneinControlError::InvalidInput)?;
+        print_key_name_warnings(&action.0.key_schema);
+        actions.push(action);
+    }
+
+    let desc: TableDescription = describe_table_api(cx, cx.effective_table_name()).await?;
+    let base = desc.provisioned_throughput.as_ref();
+    let provisioned_throughput = index_provisioned_throughput(
+        &table::extract_mode(&desc.billing_mode_summary),
+        base.and_then(|pt| pt.read_capacity_units),
+        base.and_then(|pt| pt.write_capacity_units),
+        wcu,
+        rcu,
+    );
+    for (action, _) in actions.iter_mut() {
+        action.provisioned_throughput = provisioned_throughput.clone();
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+    let index_names: Vec<String> = actions
+        .iter()
+        .map(|(action, _)| action.index_name.clone())
+        .collect();
+    let total = actions.len();
+    for (i, (create_gsi_action, attribute_definitions)) in actions.into_iter().enumerate() {
+        let index_name = create_gsi_action.index_name.clone();
+        debug!(
+            "Trying to create an index '{}' with keys '{:?}', on table '{}' ",
+            &index_name,
+            &create_gsi_action.key_schema,
+            &cx.effective_table_name()
+        );
+        let gsi_update = GlobalSecondaryIndexUpdate::builder()
+            .create(create_gsi_action)
+            .build();
+        let request = ddb
+            .update_table()
+            .table_name(cx.effective_table_name())
+            .set_attribute_definitions(Some(attribute_definitions))
+            .global_secondary_index_updates(gsi_update);
+        if cx.dry_run {
+            let input = request.as_input().clone().build();
+            app::print_dry_run(
+                "UpdateTable",
+                &input.expect("UpdateTableInput should be valid"),
+            );
+            continue;
+        }
+
+        let res = request.send().await.map_err(|e| {
+            if let Some(
+                UpdateTableError::LimitExceededException(_)
+                | UpdateTableError::ResourceInUseException(_),
+            ) = e.as_service_error()
+            {
+                eprintln!("NOTE: DynamoDB creates only one GSI at a time on a table. If another index is being created or deleted, wait for it to finish (e.g. `dy desc --watch`) and retry.");
+            }
+            index_creation_error(api_error("UpdateTable")(e), &index_names[..i], &index_names[i..])
+        })?;
+        debug!("Returned result: {:#?}", res);
+        if total > 1 {
+            eprintln!(
+                "Creating the index '{}' has been started. ({}/{})",
+                index_name,
+                i + 1,
+                total
+            );
+        }
+        // GSI goes through a backfilling phase, so wait for the index to become ACTIVE as well as the table.
+        // The next index can be created only after that, hence indexes other than the last one are always waited.
+        // Backfilling a large table may take hours, so the wait for the next index is bounded only by --deadline.
+        let desc = if i + 1 < total {
+            wait_until_table_active_within(cx, &cx.effective_table_name(), None)
+                .await
+                .map_err(|e| index_creation_error(e, &index_names[..=i], &index_names[i + 1..]))?
+        } else if wait {
+            wait_until_table_active(cx, &cx.effective_table_name()).await?
+        } else {
+            res.table_description.unwrap()
+        };
+        if i + 1 == total {
+            table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+        }
+    }
+    Ok(())
+}
+
+/// Wraps an error in the middle of creating multiple indexes by create_index, with indexes whose creation has been started and the rest.
+/// The original error is returned as it is when no index has been started, i.e. nothing has changed on the table.
+fn index_creation_error(
+    e: DyneinControlError,
+    started: &[String],
+    pending: &[String],
+) -> DyneinControlError {
+    if started.is_empty() {
+        return e;
+    }
+    DyneinControlError::PartialFailure(format!(
+        "{}\nIndexes whose creation has been started: {}\nIndexes not created yet: {}",
+        e,
+        started.join(", "),
+        pending.join(", ")
+    ))
+}
+
+/// Capacity units of a new GSI. GSI on a Provisioned table requires its own ProvisionedThroughput, while OnDemand tables don't accept it.
+/// Given rcu/wcu are used, and capacity units of the base table (base_rcu/base_wcu) are inherited if missing.
+fn index_provisioned_throughput(
+    mode: &table::Mode,
+    base_rcu: Option<i64>,
+    base_wcu: Option<i64>,
+    wcu: Option<i64>,
+    rcu: Option<i64>,
+) -> Option<ProvisionedThroughput> {
+    match mode {
+        table::Mode::OnDemand => {
+            if wcu.is_some() || rcu.is_some() {
+                println!("Ignoring --rcu/--wcu options as the table mode is OnDemand.");
+            };
+            None
+        }
+        // The base table may not have capacity units during a mode switch, then fill with "5" as a default.
+        table::Mode::Provisioned => Some(
+            ProvisionedThroughput::builder()
+                .read_capacity_units(rcu.or(base_rcu).unwrap_or(5))
+                .write_capacity_units(wcu.or(base_wcu).unwrap_or(5))
+                .build()
+                .unwrap(),
+        ),
+    }
+}
+
+/// Polls DescribeTable API until the table and all of its GSIs become ACTIVE, then returns the latest TableDescription.
+/// It's used by `--wait` option of commands that create a table or an index. Returns WaitTimeout after WAIT_TIMEOUT.
+pub async fn wait_until_table_active(
+    cx: &app::Context,
+    table_name: &str,
+) -> Result<TableDescription, DyneinControlError> {
+    wait_until_table_active_within(cx, table_name, Some(WAIT_TIMEOUT)).await
+}
+
+/// Same as wait_until_table_active, but with the given timeout. Without timeout, it waits until the table becomes ACTIVE or --deadline passes.
+async fn wait_until_table_active_within(
+    cx: &app::Context,
+    table_name: &str,
+    timeout: Option<time::Duration>,
+) -> Result<TableDescription, DyneinControlError> {
+    let started_at = time::Instant::now();
+    loop {
+        let desc: TableDescription = describe_table_api(cx, table_name.to_owned()).await?;
+        let table_status = desc.table_status.as_ref().map_or("UNKNOWN", |s| s.as_str());
+        let pending_indexes: Vec<&str> = desc
+            .global_secondary_indexes
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|idx| idx.index_status != Some(IndexStatus::Active))
+            .map(|idx| idx.index_name.as_deref().unwrap_or_default())
+            .collect();
+
+        if desc.table_status == Some(TableStatus::Active) && pending_indexes.is_empty() {
+            return Ok(desc);
+        }
+        if let Some(timeout) = timeout.filter(|timeout| &started_at.elapsed() >= timeout) {
+            return Err(DyneinControlError::WaitTimeout(format!(
+                "Timed out after {} seconds waiting for the table '{}' to become ACTIVE (status: {}).",
+                timeout.as_secs(),
+                table_name,
+                table_status
+            )));
+        }
+        // Give up before --deadline cancels the whole command, so that the caller can report what has been done so far.
+        if cx
+            .remaining_time_until_deadline()
+            .map_or(false, |remaining| remaining <= WAIT_POLLING_INTERVAL)
+        {
+            return Err(DyneinControlError::WaitTimeout(format!(
+                "Gave up waiting for the table '{}' to become ACTIVE as the deadline is approaching (status: {}).",
+                table_name, table_status
+            )));
+        }
+
+        if pending_indexes.is_empty() {
+            eprintln!(
+                "Waiting for the table '{}' to become ACTIVE... (status: {})",
+                table_name, table_status
+            );
+        } else {
+            eprintln!(
+                "Waiting for the table '{}' to become ACTIVE... (status: {}, indexes in progress: {})",
+                table_name,
+                table_status,
+                pending_indexes.join(", ")
+            );
+        }
+        tokio::time::sleep(WAIT_POLLING_INTERVAL).await;
+    }
+}
+
+/// Polls DescribeTable API until it returns ResourceNotFoundException, i.e. the table has been deleted completely.
+/// It's used by `--wait` option of `dy admin delete table`. Returns WaitTimeout after WAIT_TIMEOUT.
+pub async fn wait_until_table_deleted(
+    cx: &app::Context,
+    table_name: &str,
+) -> Result<(), DyneinControlError> {
+    let started_at = time::Instant::now();
+    loop {
+        let table_status = match try_describe_table_api(cx, table_name.to_owned()).await {
+            Ok(desc) => desc
+                .table_status
+                .map_or_else(|| String::from("UNKNOWN"), |s| s.as_str().to_owned()),
+            // The table has gone, which is what we've been waiting for.
+            Err(e)
+                if matches!(
+                    e.as_service_error(),
+                    Some(DescribeTableError::ResourceNotFoundException(_))
+                ) =>
+            {
+                return Ok(())
+            }
+            Err(e) => {
+                debug!("DescribeTable API call got an error -- {:#?}", e);
+                return Err(DyneinControlError::DescribeTable(e));
+            }
+        };
+        if started_at.elapsed() >= WAIT_TIMEOUT {
+            return Err(DyneinControlError::WaitTimeout(format!(
+                "Timed out after {} seconds waiting for the table '{}' to be deleted (status: {}).",
+                WAIT_TIMEOUT.as_secs(),
+                table_name,
+                table_status
+            )));
+        }
+        eprintln!(
+            "Waiting for the table '{}' to be deleted... (status: {})",
+            table_name, table_status
+        );
+        tokio::time::sleep(WAIT_POLLING_INTERVAL).await;
+    }
+}
+
+pub async fn update_table(
+    cx: &app::Context,
+    table_name_to_update: String,
+    mode_string: Option<String>,
+    wcu: Option<i64>,
+    rcu: Option<i64>,
+    table_class_string: Option<String>,
+    sse_kms_key: Option<String>,
+    sse_disable: bool,
+) -> Result<(), DyneinControlError> {
+    // Validate the table class before calling any API.
+    let table_class: Option<TableClass> = table_class_string
+        .as_deref()
+        .map(table::parse_table_class)
+        .transpose()
+        .map_err(DyneinControlError::InvalidInput)?;
+
+    // Retrieve TableDescription of the table to update, current (before update) status.
+    let desc: TableDescription = describe_table_api(cx, table_name_to_update.clone()).await?;
+
+    // TableClassSummary is missing for tables which have never changed the class, i.e. STANDARD tables.
+    let current_class = desc
+        .table_class_summary
+        .as_ref()
+        .and_then(|s| s.table_class.clone())
+        .unwrap_or(TableClass::Standard);
+    let table_class = match table_class {
+        Some(class) if class == current_class => {
+            println!(
+                "The table '{}' is already in {} class.",
+                table_name_to_update,
+                class.as_str()
+            );
+            if mode_string.is_none()
+                && wcu.is_none()
+                && rcu.is_none()
+                && sse_kms_key.is_none()
+                && !sse_disable
+            {
+                return Ok(());
+            }
+            None
+        }
+        class => class,
+    };
+
+    // With --sse-kms-key, the table is re-encrypted with the given KMS key. With --sse-disable, it's switched back to an AWS owned key,
+    // which is a security-relevant change, hence confirmed unless --yes is given.
+    let sse_specification: Option<SseSpecification> = if let Some(kms_key) = sse_kms_key {
+        Some(
+            SseSpecification::builder()
+                .enabled(true)
+                .sse_type(SseType::Kms)
+                .kms_master_key_id(kms_key)
+                .build(),
+        )
+    } else if sse_disable {
+        if table::is_kms_encrypted(&desc.sse_description) {
+            let msg = format!(
+                "You're trying to switch encryption of the table '{}' from {} to an AWS owned key. Are you OK?",
+                table_name_to_update,
+                table::sse_summary(&desc.sse_description)
+            );
+            // Dry run doesn't change anything, hence no confirmation.
+            if !cx.dry_run && !app::confirm(cx, &msg)? {
+                println!("The table update operation has been canceled.");
+                return Ok(());
+            }
+            Some(SseSpecification::builder().enabled(false).build())
+        } else {
+            println!(
+                "The table '{}' is already encrypted with an AWS owned key.",
+                table_name_to_update
+            );
+            if mode_string.is_none() && wcu.is_none() && rcu.is_none() && table_class.is_none() {
+                return Ok(());
+            }
+            None
+        }
+    } else {
+        None
+    };
+
+    // Map given string into "Mode" enum. Note that in cmd.rs clap already limits acceptable values.
+    let switching_to_mode: Option<table::Mode> = match mode_string {
+        None => None,
+        Some(ms) => match ms.as_str() {
+            "provisioned" => Some(table::Mode::Provisioned),
+            "ondemand"    => Some(table::Mode::OnDemand),
+            _ => panic!("You shouldn't see this message as --mode can takes only 'provisioned' or 'ondemand'."),
+        },
+    };
+
+    // Capacity units cannot be applied to OnDemand mode, so reject them rather than silently ignoring.
+    if switching_to_mode == Some(table::Mode::OnDemand) && (wcu.is_some() || rcu.is_some()) {
+        return Err(DyneinControlError::InvalidInput(String::from(
+            "--wcu/--rcu options cannot be used with --mode ondemand.",
+        )));
+    }
+
+    // When the table is already in the given mode, the mode switch is not needed.
+    // Avoid calling UpdateTable API if there's nothing else (i.e. capacity units) to update.
+    let current_mode = table::extract_mode(&desc.billing_mode_summary);
+    let switching_to_mode = match switching_to_mode {
+        Some(mode) if mode == current_mode => {
+            if wcu.is_none()
+                && rcu.is_none()
+                && table_class.is_none()
+                && sse_specification.is_none()
+            {
+                println!(
+                    "The table '{}' is already in {:?} mode.",
+                    table_name_to_update, current_mode
+                );
+                return Ok(());
+            }
+            None
+        }
+        mode => mode,
+    };
+
+    // Configure ProvisionedThroughput struct based on argumsnts (mode/wcu/rcu).
+    let provisioned_throughput: Option<ProvisionedThroughput> = match &switching_to_mode {
+        // when --mode is not given, no mode switch happens. Check the table's current mode.
+        None => {
+            match table::extract_mode(&desc.billing_mode_summary) {
+                // When currently OnDemand mode and you're not going to change the it, set None for CU.
+                table::Mode::OnDemand => {
+                    if wcu.is_some() || rcu.is_some() {
+                        println!("Ignoring --rcu/--wcu options as the table mode is OnDemand.");
+                    };
+                    None
+                }
+                // When only the table class or encryption is updated, keep capacity units as they are.
+                table::Mode::Provisioned
+                    if (table_class.is_some() || sse_specification.is_some())
+                        && wcu.is_none()
+                        && rcu.is_none() =>
+                {
+                    None
+                }
+                // When currently Provisioned mode and you're not going to change the it,
+                // pass given rcu/wcu, and use current values if missing.
+                // Current capacity units may be missing (e.g. while the table is switching its mode), then ask for both options.
+                table::Mode::Provisioned => {
+                    let current = desc.provisioned_throughput.as_ref();
+                    let read = rcu.or_else(|| current.and_then(|pt| pt.read_capacity_units));
+                    let write = wcu.or_else(|| current.and_then(|pt| pt.write_capacity_units));
+                    match (read, write) {
+                        (Some(read), Some(write)) => Some(
+                            ProvisionedThroughput::builder()
+                                .read_capacity_units(read)
+                                .write_capacity_units(write)
+                                .build()
+                                .unwrap(),
+                        ),
+                        _ => {
+                            return Err(DyneinControlError::InvalidInput(format!(
+                                "Current capacity units of the table '{}' are unknown. Specify both --rcu and --wcu options.",
+                                table_name_to_update
+                            )))
+                        }
+                    }
+                }
+            }
+        }
+        // When the user trying to switch mode.
+        Some(target_mode) => match target_mode {
+            // when switching Provisioned->OnDemand mode, ProvisionedThroughput can be None.
+            table::Mode::OnDemand => None,
+            // when switching OnDemand->Provisioned mode, set given wcu/rcu, fill with "5" as a default if not given.
+            table::Mode::Provisioned => Some(
+                ProvisionedThroughput::builder()
+                    .read_capacity_units(rcu.unwrap_or(5))
+                    .write_capacity_units(wcu.unwrap_or(5))
+                    .build()
+                    .unwrap(),
+            ),
+        },
+    };
+
+    // TODO: support updating CU of the table with GSI. If the table has GSIs, you must specify CU for them at the same time.
+    // error message: One or more parameter values were invalid: ProvisionedThroughput must be specified for index: xyz_index,abc_index2
+    //   if table has gsi
+    //     build GlobalSecondaryIndexUpdates { [... current values ...] }
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+    let request = update_table_request(
+        &ddb,
+        table_name_to_update,
+        switching_to_mode,
+        provisioned_throughput,
+        table_class,
+        sse_specification,
+    );
+    if cx.dry_run {
+        let input = request.as_input().clone().build();
+        app::print_dry_run(
+            "UpdateTable",
+            &input.expect("UpdateTableInput should be valid"),
+        );
+        return Ok(());
+    }
+
+    let res = request.send().await.map_err(api_error("UpdateTable"))?;
+    debug!("Returned result: {:#?}", res);
+    let desc = res
+        .table_description
+        .expect("Table Description returned from API should be valid.");
+    table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+    Ok(())
+}
+
+/// UpdateTable API accepts following parameters (ref: https://docs.aws.amazon.com/amazondynamodb/latest/APIReference/API_UpdateTable.html):
+///   * [x] TableName (required)
 ///   * [x] BillingMode
 ///   * [x] ProvisionedThroughput > obj
 ///   * [-] AttributeDefinitions > array of AttributeDefinition obj
 ///   * [-] GlobalSecondaryIndexUpdates > Create/Update/Delete and details of the update on GSIs
 ///   * [-] ReplicaUpdates > Create/Update/Delete and details of the update on Global Tbles replicas
-///   * [] SSESpecification > obj
+///   * [x] SSESpecification > obj
 ///   * [] StreamSpecification > obj
+///   * [x] TableClass
 /// [+] = supported, [-] = implemented (or plan to so) in another location, [] = not yet supported
 /// Especially note that you should explicitly pass GSI update parameter to make any change on GSI.
-async fn update_table_api(
+/// The request is returned without being sent, so that the caller can print it with --dry-run.
+fn update_table_request(
+    ddb: &DynamoDbSdkClient,
+    table_name_to_update: String,
+    switching_to_mode: Option<table::Mode>,
+    provisioned_throughput: Option<ProvisionedThroughput>,
+    table_class: Option<TableClass>,
+    sse_specification: Option<SseSpecification>,
+) -> UpdateTableFluentBuilder {
+    debug!("Trying to update the table '{}'.", &table_name_to_update);
+
+    ddb.update_table()
+        .table_name(table_name_to_update)
+        .set_billing_mode(switching_to_mode.map(|v| v.into()))
+        .set_provisioned_throughput(provisioned_throughput)
+        .set_table_class(table_class)
+        .set_sse_specification(sse_specification)
+}
+
+pub async fn delete_table(
+    cx: &app::Context,
+    name: String,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    debug!("Trying to delete a table '{}'", &name);
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+    let request = ddb.delete_table().table_name(&name);
+    // Dry run doesn't change anything, hence no confirmation.
+    if cx.dry_run {
+        let input = request.as_input().clone().build();
+        app::print_dry_run(
+            "DeleteTable",
+            &input.expect("DeleteTableInput should be valid"),
+        );
+        return Ok(());
+    }
+
+    let msg = format!("You're trying to delete a table '{}'. Are you OK?", &name);
+    if !app::confirm(cx, &msg)? {
+        println!("The table delete operation has been canceled.");
+        return Ok(());
+    }
+
+    let res = request.send().await.map_err(|e| {
+        debug!("DeleteTable API call got an error -- {:#?}", e);
+        DyneinControlError::DeleteTable(e)
+    })?;
+    debug!("Returned result: {:#?}", res);
+    println!(
+        "Delete operation for the table '{}' has been started.",
+        res.table_description.unwrap().table_name.unwrap()
+    );
+    if wait {
+        wait_until_table_deleted(cx, &name).await?;
+        println!("The table '{}' has been deleted.", name);
+    }
+    Ok(())
+}
+
+/// Executed when you call `$ dy admin delete table (table1) (table2) ...`. DeleteTable API calls are executed in parallel (async + join_all)
+/// after a single confirmation. A failure of a table doesn't abort others, and results are summarized at the end.
+pub async fn delete_tables(
+    cx: &app::Context,
+    names: Vec<String>,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    debug!("Trying to delete tables {:?}", &names);
+
+    if cx.dry_run {
+        for name in &names {
+            let input = DeleteTableInput::builder().table_name(name).build();
+            app::print_dry_run(
+                "DeleteTable",
+                &input.expect("DeleteTableInput should be valid"),
+            );
+        }
+        return Ok(());
+    }
+
+    let msg = format!(
+        "You're trying to delete {} tables: {}. Are you OK?",
+        names.len(),
+        names
+            .iter()
+            .map(|name| format!("'{}'", name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+    if !app::confirm(cx, &msg)? {
+        println!("The table delete operation has been canceled.");
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+
+    let results = join_all(
+        names
+            .iter()
+            .map(|name| ddb.delete_table().table_name(name).send()),
+    )
+    .await;
+
+    // Tables that failed to be deleted, with the reason for ones that failed while waiting for the deletion.
+    let mut failures: Vec<String> = vec![];
+    for (name, result) in names.iter().zip(results) {
+        match result {
+            Ok(res) => {
+                debug!("Returned result: {:#?}", res);
+                println!(
+                    "Delete operation for the table '{}' has been started.",
+                    name
+                );
+            }
+            Err(e) => {
+                error!(
+                    "Failed to delete the table '{}': {}",
+                    name,
+                    DisplayErrorContext(e)
+                );
+                failures.push(name.to_owned());
+            }
+        }
+    }
+
+    println!(
+        "Started deleting {} of {} tables.",
+        names.len() - failures.len(),
+        names.len()
+    );
+    if wait {
+        let started: Vec<&String> = names
+            .iter()
+            .filter(|name| !failures.contains(*name))
+            .collect();
+        let results = join_all(
+            started
+                .iter()
+                .map(|name| wait_until_table_deleted(cx, name)),
+        )
+        .await;
+        // A table that fails while waiting doesn't prevent results of the others from being collected.
+        let mut deleted = 0;
+        for (name, result) in started.iter().zip(results) {
+            match result {
+                Ok(_) => deleted += 1,
+                Err(e) => failures.push(format!("{} ({})", name, e)),
+            }
+        }
+        println!("Deleted {} tables.", deleted);
+    }
+    if !failures.is_empty() {
+        return Err(DyneinControlError::PartialFailure(format!(
+            "Failed to delete: {}",
+            failures.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Deletes a GSI of the table given by --table option, via UpdateTable API with GlobalSecondaryIndexUpdates.
+pub async fn delete_index(cx: &app::Context, index_name: String) -> Result<(), DyneinControlError> {
+    let table_name = cx.effective_table_name();
+    debug!(
+        "Trying to delete an index '{}' of the table '{}'",
+        &index_name, &table_name
+    );
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+
+    let gsi_update = GlobalSecondaryIndexUpdate::builder()
+        .delete(
+            DeleteGlobalSecondaryIndexAction::builder()
+                .index_name(&index_name)
+                .build()
+                .unwrap(),
+        )
+        .build();
+    let request = ddb
+        .update_table()
+        .table_name(&table_name)
+        .global_secondary_index_updates(gsi_update);
+    // Dry run doesn't change anything, hence no confirmation.
+    if cx.dry_run {
+        let input = request.as_input().clone().build();
+        app::print_dry_run(
+            "UpdateTable",
+            &input.expect("UpdateTableInput should be valid"),
+        );
+        return Ok(());
+    }
+
+    let msg = format!(
+        "You're trying to delete an index '{}' of the table '{}'. Are you OK?",
+        &index_name, &table_name
+    );
+    if !app::confirm(cx, &msg)? {
+        println!("The index delete operation has been canceled.");
+        return Ok(());
+    }
+
+    let res = request.send().await.map_err(api_error("UpdateTable"))?;
+    debug!("Returned result: {:#?}", res);
+    table::print_table_description(
+        cx.effective_region().await.as_ref(),
+        &res.table_description.unwrap(),
+    );
+    Ok(())
+}
+
+/// Executed when you call `$ dy create-replica` or `$ dy delete-replica`. Adds or removes a replica of the table
+/// (i.e. global table version 2019.11.21) in the given region, and prints the table description with the updated replicas.
+pub async fn update_replica(
+    cx: &app::Context,
+    replica_region: String,
+    create: bool,
+) -> Result<(), DyneinControlError> {
+    let replica_region = app::resolve_region_alias(&replica_region);
+    app::validate_region_name(&replica_region).map_err(DyneinControlError::InvalidInput)?;
+    let table_name = cx.effective_table_name();
+    let region = cx.effective_region().await;
+    if replica_region == region.as_ref() {
+        return Err(DyneinControlError::InvalidInput(format!(
+            "The table '{}' itself is in the region '{}'. Specify another region for the replica.",
+            table_name, replica_region
+        )));
+    }
+    debug!(
+        "Trying to {} a replica of the table '{}' in the region '{}'",
+        if create { "create" } else { "delete" },
+        &table_name,
+        &replica_region
+    );
+
+    let replica_update = if create {
+        ReplicationGroupUpdate::builder()
+            .create(
+                CreateReplicationGroupMemberAction::builder()
+                    .region_name(&replica_region)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    } else {
+        ReplicationGroupUpdate::builder()
+            .delete(
+                DeleteReplicationGroupMemberAction::builder()
+                    .region_name(&replica_region)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    };
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+    let request = ddb
+        .update_table()
+        .table_name(&table_name)
+        .replica_updates(replica_update);
+    // Dry run doesn't change anything, hence no confirmation.
+    if cx.dry_run {
+        let input = request.as_input().clone().build();
+        app::print_dry_run(
+            "UpdateTable",
+            &input.expect("UpdateTableInput should be valid"),
+        );
+        return Ok(());
+    }
+
+    if !create {
+        let msg = format!(
+            "You're trying to delete the replica of the table '{}' in the region '{}', including its items. Are you OK?",
+            &table_name, &replica_region
+        );
+        if !app::confirm(cx, &msg)? {
+            println!("The replica delete operation has been canceled.");
+            return Ok(());
+        }
+    }
+
+    let res = request.send().await.map_err(api_error("UpdateTable"))?;
+    debug!("Returned result: {:#?}", res);
+    table::print_table_description(region.as_ref(), &res.table_description.unwrap());
+    Ok(())
+}
+
+/// Executed when you call `$ dy stream enable` or `$ dy stream disable`. view_type is used only when enabling a stream.
+pub async fn update_stream(
     cx: &app::Context,
-    table_name_to_update: String,
-    switching_to_mode: Option<table::Mode>,
-    provisioned_throughput: Option<ProvisionedThroughput>,
-) -> Result<
-    TableDescription,
-    aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::update_table::UpdateTableError>,
-> {
-    debug!("Trying to update the table '{}'.", &table_name_to_update);
+    view_type: Option<String>,
+    enabled: bool,
+) -> Result<(), DyneinControlError> {
+    // Map given string into "StreamViewType". Note that in cmd.rs clap already limits acceptable values.
+    let stream_view_type: Option<StreamViewType> = match view_type.as_deref() {
+        None => None,
+        Some("NEW_IMAGE") => Some(StreamViewType::NewImage),
+        Some("OLD_IMAGE") => Some(StreamViewType::OldImage),
+        Some("NEW_AND_OLD_IMAGES") => Some(StreamViewType::NewAndOldImages),
+        Some("KEYS_ONLY") => Some(StreamViewType::KeysOnly),
+        Some(v) => {
+            return Err(DyneinControlError::InvalidInput(format!(
+                "Unknown stream view type: '{}'. Available values: [NEW_IMAGE, OLD_IMAGE, NEW_AND_OLD_IMAGES, KEYS_ONLY].",
+                v
+            )))
+        }
+    };
+    let stream_specification = StreamSpecification::builder()
+        .stream_enabled(enabled)
+        .set_stream_view_type(if enabled { stream_view_type } else { None })
+        .build()
+        .unwrap();
+
+    let table_name = cx.effective_table_name();
+    debug!(
+        "Trying to update the stream of the table '{}': {:?}",
+        &table_name, &stream_specification
+    );
 
     let config = cx.effective_sdk_config().await;
     let ddb = DynamoDbSdkClient::new(&config);
+    let request = ddb
+        .update_table()
+        .table_name(table_name)
+        .stream_specification(stream_specification);
 
-    ddb.update_table()
-        .table_name(table_name_to_update)
-        .set_billing_mode(switching_to_mode.map(|v| v.into()))
-        .set_provisioned_throughput(provisioned_throughput)
-        .send()
+    if cx.dry_run {
+        let input = request
+            .as_input()
+            .clone()
+            .build()
+            .expect("UpdateTableInput should be valid");
+        app::print_dry_run("UpdateTable", &input);
+        return Ok(());
+    }
+
+    let res = request.send().await.map_err(api_error("UpdateTable"))?;
+    debug!("Returned result: {:#?}", res);
+    table::print_table_description(
+        cx.effective_region().await.as_ref(),
+        &res.table_description.unwrap(),
+    );
+    Ok(())
+}
+
+/// Executed when you call `$ dy pitr enable` or `$ dy pitr disable`.
+pub async fn update_pitr(cx: &app::Context, enabled: bool) -> Result<(), DyneinControlError> {
+    let table_name = cx.effective_table_name();
+    if cx.dry_run {
+        let input = api::update_continuous_backups_input(&table_name, enabled);
+        app::print_dry_run("UpdateContinuousBackups", &input);
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let backups = api::update_continuous_backups(&config, &table_name, enabled)
         .await
-        .map(|res| {
-            res.table_description
-                .expect("Table Description returned from API should be valid.")
-        })
+        .map_err(api_error("UpdateContinuousBackups"))?;
+    debug!("Returned result: {:#?}", backups);
+    println!(
+        "PITR of the table '{}' has been updated: {}",
+        table_name,
+        table::pitr_summary(&backups)
+    );
+    Ok(())
 }
 
-pub async fn delete_table(cx: &app::Context, name: String, skip_confirmation: bool) {
-    debug!("Trying to delete a table '{}'", &name);
+/// Executed when you call `$ dy pitr restore`. Restores the target table as of restore_time (RFC3339) into a new table,
+/// or as of the latest restorable time when restore_time is not given.
+/// The restore time is validated against the recoverable window of the table before calling RestoreTableToPointInTime API.
+pub async fn restore_to_point_in_time(
+    cx: &app::Context,
+    restore_time: Option<String>,
+    restore_name: Option<String>,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    let source_table_name = cx.effective_table_name();
+    let config = cx.effective_sdk_config().await;
 
-    let msg = format!("You're trying to delete a table '{}'. Are you OK?", &name);
-    if !skip_confirmation && !Confirm::new().with_prompt(&msg).interact().unwrap() {
-        println!("The table delete operation has been canceled.");
-        return;
+    let restore_date_time: Option<DateTime> = restore_time
+        .map(|t| {
+            chrono::DateTime::parse_from_rfc3339(&t)
+                .map(|parsed| DateTime::from_millis(parsed.timestamp_millis()))
+                .map_err(|e| {
+                    DyneinControlError::InvalidInput(format!(
+                        "Invalid --time '{}': {}. It should be RFC3339 format, e.g. '2024-01-01T00:00:00Z'.",
+                        t, e
+                    ))
+                })
+        })
+        .transpose()?;
+
+    let backups = api::describe_continuous_backups(&config, &source_table_name)
+        .await
+        .map_err(api_error("DescribeContinuousBackups"))?;
+    let (earliest, latest) = match backups.point_in_time_recovery_description {
+        Some(PointInTimeRecoveryDescription {
+            point_in_time_recovery_status: Some(PointInTimeRecoveryStatus::Enabled),
+            earliest_restorable_date_time: Some(earliest),
+            latest_restorable_date_time: Some(latest),
+            ..
+        }) => (earliest, latest),
+        _ => {
+            return Err(DyneinControlError::InvalidInput(format!(
+                "PITR is not enabled on the table '{}'. You can enable it by `dy pitr enable`.",
+                source_table_name
+            )))
+        }
+    };
+
+    if let Some(restore_date_time) = restore_date_time {
+        let secs = restore_date_time.as_secs_f64();
+        if secs < earliest.as_secs_f64() || latest.as_secs_f64() < secs {
+            return Err(DyneinControlError::InvalidInput(format!(
+                "The restore time {} is out of the recoverable window of the table '{}': {} - {}",
+                table::epoch_to_rfc3339(secs),
+                source_table_name,
+                table::epoch_to_rfc3339(earliest.as_secs_f64()),
+                table::epoch_to_rfc3339(latest.as_secs_f64())
+            )));
+        }
+    }
+
+    let epoch: u64 = time::SystemTime::now()
+        .duration_since(time::SystemTime::UNIX_EPOCH)
+        .expect("should be able to generate UNIX EPOCH")
+        .as_secs();
+    let target_table_name = match restore_name {
+        None => format!("{}--restore-{}", source_table_name, epoch),
+        Some(restore) => restore,
+    };
+
+    if cx.dry_run {
+        let input = api::restore_table_to_point_in_time_input(
+            &source_table_name,
+            &target_table_name,
+            restore_date_time,
+        );
+        app::print_dry_run("RestoreTableToPointInTime", &input);
+        return Ok(());
     }
 
+    let desc = api::restore_table_to_point_in_time(
+        &config,
+        &source_table_name,
+        &target_table_name,
+        restore_date_time,
+    )
+    .await
+    .map_err(api_error("RestoreTableToPointInTime"))?;
+    debug!("Returned result: {:#?}", desc);
+    println!(
+        "Table restoration from: '{}' as of {} has been started",
+        &source_table_name,
+        restore_date_time.map_or(String::from("the latest restorable time"), |t| {
+            table::epoch_to_rfc3339(t.as_secs_f64())
+        })
+    );
+    let desc = if wait {
+        wait_until_table_active(cx, &target_table_name).await?
+    } else {
+        desc
+    };
+    table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+    Ok(())
+}
+
+/// Executed when you call `$ dy export --s3-bucket`. Exports the target table as of export_time (RFC3339) into the S3 bucket,
+/// or as of the current time when export_time is not given. It doesn't wait for the export, which may take a while, to complete.
+/// As ExportTableToPointInTime API requires PITR, its status is checked before starting the export.
+pub async fn export_to_s3(
+    cx: &app::Context,
+    s3_bucket: String,
+    s3_prefix: Option<String>,
+    export_time: Option<String>,
+) -> Result<(), DyneinControlError> {
+    let export_time: Option<DateTime> = export_time
+        .map(|t| {
+            chrono::DateTime::parse_from_rfc3339(&t)
+                .map(|parsed| DateTime::from_millis(parsed.timestamp_millis()))
+                .map_err(|e| {
+                    DyneinControlError::InvalidInput(format!(
+                        "Invalid --time '{}': {}. It should be RFC3339 format, e.g. '2024-01-01T00:00:00Z'.",
+                        t, e
+                    ))
+                })
+        })
+        .transpose()?;
+
+    let table_name = cx.effective_table_name();
+    // ExportTableToPointInTime API identifies the table by its ARN rather than its name.
+    let table_arn = describe_table_api(cx, table_name.clone())
+        .await?
+        .table_arn
+        .expect("Table ARN returned from API should be valid.");
+
     let config = cx.effective_sdk_config().await;
     let ddb = DynamoDbSdkClient::new(&config);
+    let request = ddb
+        .export_table_to_point_in_time()
+        .table_arn(&table_arn)
+        .s3_bucket(&s3_bucket)
+        .set_s3_prefix(s3_prefix.clone())
+        .set_export_time(export_time);
+    if cx.dry_run {
+        let input = request.as_input().clone().build();
+        app::print_dry_run(
+            "ExportTableToPointInTime",
+            &input.expect("ExportTableToPointInTimeInput should be valid"),
+        );
+        return Ok(());
+    }
 
-    match ddb.delete_table().table_name(name).send().await {
-        Err(e) => {
-            debug!("DeleteTable API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
+    let backups = api::describe_continuous_backups(&config, &table_name)
+        .await
+        .map_err(api_error("DescribeContinuousBackups"))?;
+    if !matches!(
+        backups.point_in_time_recovery_description,
+        Some(PointInTimeRecoveryDescription {
+            point_in_time_recovery_status: Some(PointInTimeRecoveryStatus::Enabled),
+            ..
+        })
+    ) {
+        return Err(DyneinControlError::InvalidInput(format!(
+            "PITR is not enabled on the table '{}', which is required to export it to S3. You can enable it by `dy pitr enable`.",
+            table_name
+        )));
+    }
+
+    let res = request
+        .send()
+        .await
+        .map_err(api_error("ExportTableToPointInTime"))?;
+    debug!("Returned result: {:#?}", res);
+    let export = res
+        .export_description
+        .expect("Export Description returned from API should be valid.");
+    println!(
+        "Export of the table '{}' into s3://{}/{} has been started.",
+        table_name,
+        s3_bucket,
+        s3_prefix.unwrap_or_default()
+    );
+    println!(
+        "export_arn: {}",
+        export.export_arn.as_deref().unwrap_or_default()
+    );
+    println!(
+        "status: {}",
+        export
+            .export_status
+            .as_ref()
+            .map_or("UNKNOWN", |s| s.as_str())
+    );
+    Ok(())
+}
+
+/// Executed when you call `$ dy import --s3-bucket`. Creates a new OnDemand table with the given keys and imports objects under the prefix into it.
+/// Same as `dy export --s3-bucket`, it doesn't wait for the import to complete. [API: ImportTable]
+pub async fn import_from_s3(
+    cx: &app::Context,
+    s3_bucket: String,
+    s3_prefix: Option<String>,
+    format: Option<String>,
+    keys: Vec<String>,
+) -> Result<(), DyneinControlError> {
+    let input_format = match format.as_deref() {
+        None | Some("dynamodb_json") => InputFormat::DynamodbJson,
+        Some("ion") => InputFormat::Ion,
+        Some("csv") => InputFormat::Csv,
+        Some(f) => {
+            return Err(DyneinControlError::InvalidInput(format!(
+                "--format '{}' is not supported for imports from S3. Available values: [dynamodb_json, ion, csv].",
+                f
+            )))
         }
-        Ok(res) => {
-            debug!("Returned result: {:#?}", res);
-            println!(
-                "Delete operation for the table '{}' has been started.",
-                res.table_description.unwrap().table_name.unwrap()
-            );
+    };
+    let (key_schema, attribute_definitions) = table::generate_essential_key_definitions(&keys)
+        .map_err(DyneinControlError::InvalidInput)?;
+    print_key_name_warnings(&key_schema);
+
+    let table_name = cx.effective_table_name();
+    let s3_bucket_source = S3BucketSource::builder()
+        .s3_bucket(&s3_bucket)
+        .set_s3_key_prefix(s3_prefix.clone())
+        .build()
+        .unwrap();
+    let table_creation_parameters = TableCreationParameters::builder()
+        .table_name(&table_name)
+        .set_key_schema(Some(key_schema))
+        .set_attribute_definitions(Some(attribute_definitions))
+        .billing_mode(BillingMode::PayPerRequest)
+        .build()
+        .expect("TableCreationParameters should be valid");
+
+    let config = cx.effective_sdk_config().await;
+    let ddb = DynamoDbSdkClient::new(&config);
+    let request = ddb
+        .import_table()
+        .s3_bucket_source(s3_bucket_source)
+        .input_format(input_format)
+        .table_creation_parameters(table_creation_parameters);
+    if cx.dry_run {
+        let input = request.as_input().clone().build();
+        app::print_dry_run(
+            "ImportTable",
+            &input.expect("ImportTableInput should be valid"),
+        );
+        return Ok(());
+    }
+
+    let res = request.send().await.map_err(api_error("ImportTable"))?;
+    debug!("Returned result: {:#?}", res);
+    let import = res
+        .import_table_description
+        .expect("Import Table Description returned from API should be valid.");
+    println!(
+        "Import into the new table '{}' from s3://{}/{} has been started.",
+        table_name,
+        s3_bucket,
+        s3_prefix.unwrap_or_default()
+    );
+    println!(
+        "import_arn: {}",
+        import.import_arn.as_deref().unwrap_or_default()
+    );
+    println!(
+        "status: {}",
+        import
+            .import_status
+            .as_ref()
+            .map_or("UNKNOWN", |s| s.as_str())
+    );
+    Ok(())
+}
+
+/// Executed when you call `$ dy ttl`. Enables TTL on the given attribute, or disables TTL when enabled is false.
+/// As UpdateTimeToLive API requires the attribute name even for disabling, the current one is used when attribute is not given.
+pub async fn update_ttl(
+    cx: &app::Context,
+    attribute: Option<String>,
+    enabled: bool,
+) -> Result<(), DyneinControlError> {
+    let table_name = cx.effective_table_name();
+    let attribute = match attribute {
+        Some(attribute) => attribute,
+        None => match describe_ttl_api(cx, &table_name)
+            .await
+            .and_then(|ttl| ttl.attribute_name)
+        {
+            Some(attribute) => attribute,
+            None => {
+                println!("TTL is not enabled on the table '{}'.", table_name);
+                return Ok(());
+            }
+        },
+    };
+
+    if cx.dry_run {
+        let input = api::update_time_to_live_input(&table_name, &attribute, enabled);
+        app::print_dry_run("UpdateTimeToLive", &input);
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let spec = api::update_time_to_live(&config, &table_name, &attribute, enabled)
+        .await
+        .map_err(api_error("UpdateTimeToLive"))?;
+    debug!("Returned result: {:#?}", spec);
+    let action = if enabled { "enabled" } else { "disabled" };
+    println!(
+        "TTL has been {} on the table '{}' with the attribute '{}'. It may take up to one hour for the change to fully process.",
+        action, table_name, attribute
+    );
+    Ok(())
+}
+
+/// Executed when you call `$ dy insights enable/disable`. Updates CloudWatch Contributor Insights of the target table,
+/// or its GSI when index_name is given.
+pub async fn update_contributor_insights(
+    cx: &app::Context,
+    index_name: Option<String>,
+    enabled: bool,
+) -> Result<(), DyneinControlError> {
+    let table_name = cx.effective_table_name();
+    if cx.dry_run {
+        let input =
+            api::update_contributor_insights_input(&table_name, index_name.as_deref(), enabled);
+        app::print_dry_run("UpdateContributorInsights", &input);
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let status =
+        api::update_contributor_insights(&config, &table_name, index_name.as_deref(), enabled)
+            .await
+            .map_err(api_error("UpdateContributorInsights"))?;
+    debug!("Returned result: {:#?}", status);
+    let target = match &index_name {
+        Some(index_name) => {
+            format!("the index '{}' of the table '{}'", index_name, table_name)
         }
+        None => format!("the table '{}'", table_name),
+    };
+    println!(
+        "Contributor Insights of {} has been updated: {}",
+        target,
+        status.as_ref().map_or("UNKNOWN", |status| status.as_str())
+    );
+    Ok(())
+}
+
+/// Executed when you call `$ dy tag add`. Adds tags given in the form of "key=value" to the target table.
+pub async fn tag_resource(cx: &app::Context, tags: Vec<String>) -> Result<(), DyneinControlError> {
+    let tags: Vec<Tag> = table::parse_tags(&tags).map_err(DyneinControlError::InvalidInput)?;
+
+    let table_name = cx.effective_table_name();
+    let table_arn = describe_table_api(cx, table_name.clone())
+        .await?
+        .table_arn
+        .expect("table ARN should exist");
+    if cx.dry_run {
+        let input = api::tag_resource_input(&table_arn, tags);
+        app::print_dry_run("TagResource", &input);
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let tag_count = tags.len();
+    api::tag_resource(&config, &table_arn, tags)
+        .await
+        .map_err(api_error("TagResource"))?;
+    println!("Added {} tag(s) to the table '{}'.", tag_count, table_name);
+    Ok(())
+}
+
+/// Executed when you call `$ dy tag list`. Prints tags of the target table in "key<TAB>value" format.
+pub async fn list_tags(cx: &app::Context) -> Result<(), DyneinControlError> {
+    let table_name = cx.effective_table_name();
+    let table_arn = describe_table_api(cx, table_name.clone())
+        .await?
+        .table_arn
+        .expect("table ARN should exist");
+    let config = cx.effective_sdk_config().await;
+    let tags: Vec<Tag> = api::list_tags_of_resource(&config, &table_arn)
+        .await
+        .map_err(api_error("ListTagsOfResource"))?;
+    if tags.is_empty() {
+        println!("No tag found on the table '{}'.", table_name);
+        return Ok(());
     }
+
+    let mut tw = TabWriter::new(io::stdout());
+    tw.write_all(((["Key", "Value"].join("\t")) + "\n").as_bytes())?;
+    for tag in tags {
+        tw.write_all(([tag.key, tag.value].join("\t") + "\n").as_bytes())?;
+    }
+    tw.flush()?;
+    Ok(())
 }
 
 /// Takes on-demand Backup for the table. It takes --all-tables option but it doesn't take any effect.
 ///
-/// OnDemand backup is a type of backups that can be manually created. Another type is called PITR (Point-In-Time-Restore) but dynein doesn't support it for now.
+/// OnDemand backup is a type of backups that can be manually created. Another type is called PITR (Point-In-Time-Restore), which is managed by `$ dy pitr`.
 /// For more information about DynamoDB on-demand backup: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/BackupRestore.html
-pub async fn backup(cx: &app::Context, all_tables: bool) {
+pub async fn backup(
+    cx: &app::Context,
+    all_tables: bool,
+    name: Option<String>,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    if let Some(name) = &name {
+        validate_backup_name(name).map_err(DyneinControlError::InvalidInput)?;
+    }
+
     // this "backup" function is called only when --list is NOT given. So, --all-tables would be ignored.
     if all_tables {
         println!("NOTE: --all-tables option is ignored without --list option. Just trying to create a backup for the target table...")
@@ -445,40 +2959,77 @@ pub async fn backup(cx: &app::Context, all_tables: bool) {
         .expect("should be able to generate UNIX EPOCH")
         .as_secs();
 
+    // Retrieve encryption settings of the source table in advance, in order to verify the backup is encrypted in the same way.
+    let source_desc: TableDescription = describe_table_api(cx, table_name.clone()).await?;
+
     let config = cx.effective_sdk_config().await;
-    let ddb = DynamoDbSdkClient::new(&config);
+    let backup_name = name.unwrap_or_else(|| format!("{}--dynein-{}", table_name, epoch));
+    debug!("backup name: {}", backup_name);
 
-    let req = ddb
-        .create_backup()
-        .table_name(&table_name)
-        .backup_name(format!("{}--dynein-{}", table_name, epoch));
-    debug!("backup req: {:?}", req);
+    if cx.dry_run {
+        let input = api::create_backup_input(&table_name, &backup_name);
+        app::print_dry_run("CreateBackup", &input);
+        return Ok(());
+    }
 
-    match req.send().await {
-        Err(e) => {
-            debug!("CreateBackup API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
-        }
-        Ok(res) => {
-            debug!("Returned result: {:#?}", res);
-            let details = res.backup_details.expect("should have some details");
-            println!("Backup creation has been started:");
-            println!(
-                "  Backup Name: {} (status: {})",
-                details.backup_name, details.backup_status
-            );
-            println!("  Backup ARN: {}", details.backup_arn);
-            println!(
-                "  Backup Size: {} bytes",
-                details.backup_size_bytes.expect("should have table size")
-            );
-        }
+    let details = api::create_backup(&config, &table_name, &backup_name)
+        .await
+        .map_err(api_error("CreateBackup"))?;
+    debug!("Returned result: {:#?}", details);
+    println!("Backup creation has been started:");
+    println!(
+        "  Backup Name: {} (status: {})",
+        details.backup_name, details.backup_status
+    );
+    println!("  Backup ARN: {}", details.backup_arn);
+    let size = details.backup_size_bytes.expect("should have table size");
+    println!(
+        "  Backup Size: {} ({} bytes)",
+        table::humanize_size(size),
+        size
+    );
+    let ddb = DynamoDbSdkClient::new(&config);
+    verify_backup_encryption(&ddb, &source_desc, &details.backup_arn).await;
+    if wait {
+        wait_until_backup_available(cx, &details.backup_arn).await?;
+        println!("The backup '{}' is now AVAILABLE.", details.backup_name);
+    }
+    Ok(())
+}
+
+/// Validates a backup name given by users in the same way as DynamoDB does, i.e. 3-255 characters of [a-zA-Z0-9_.-].
+fn validate_backup_name(name: &str) -> Result<(), String> {
+    let len = name.chars().count();
+    if !(3..=255).contains(&len) {
+        return Err(format!(
+            "Backup name should be 3-255 characters long, but '{}' has {} characters.",
+            name, len
+        ));
+    }
+    match name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+    {
+        Some(c) => Err(format!(
+            "Backup name can contain only alphanumerics, '_', '-' and '.', but '{}' contains '{}'.",
+            name, c
+        )),
+        None => Ok(()),
     }
 }
 
 /// List backups for a specified table. With --all-tables option all backups for all tables in the region are shown.
-pub async fn list_backups(cx: &app::Context, all_tables: bool) -> Result<(), IOError> {
-    let backups = list_backups_api(cx, all_tables).await;
+/// With --max-items, only the given number of the newest backups are shown, newest first.
+pub async fn list_backups(
+    cx: &app::Context,
+    all_tables: bool,
+    max_items: Option<usize>,
+) -> Result<(), DyneinControlError> {
+    let backups = list_backups_api(cx, all_tables).await?;
+    let (backups, omitted) = match max_items {
+        Some(max_items) => newest_backups(backups, max_items),
+        None => (backups, 0),
+    };
     let mut tw = TabWriter::new(io::stdout());
     // First defining header
     tw.write_all(
@@ -500,56 +3051,367 @@ pub async fn list_backups(cx: &app::Context, all_tables: bool) -> Result<(), IOE
             ),
             backup.backup_name.expect("backup name should exist")
                 + &format!(
-                    " ({} bytes)",
-                    backup.backup_size_bytes.expect("size should exist")
+                    " ({})",
+                    table::humanize_size(backup.backup_size_bytes.expect("size should exist"))
                 ),
             String::from("\n"),
         ];
         tw.write_all(line.join("\t").as_bytes())?;
     }
-    tw.flush()?;
+    tw.flush()?;
+    if omitted > 0 {
+        println!(
+            "... and {} older backup(s) are not shown. Increase --max-items to see them.",
+            omitted
+        );
+    }
+    Ok(())
+}
+
+/// Sorts backups newest first by their creation time and keeps at most max_items of them.
+/// Returns the kept backups and the number of omitted ones, which is noted after the list.
+fn newest_backups(
+    mut backups: Vec<BackupSummary>,
+    max_items: usize,
+) -> (Vec<BackupSummary>, usize) {
+    backups.sort_by(|a, b| {
+        let created_at = |b: &BackupSummary| {
+            b.backup_creation_date_time
+                .map_or(f64::MIN, |t| t.as_secs_f64())
+        };
+        created_at(b).total_cmp(&created_at(a))
+    });
+    let omitted = backups.len().saturating_sub(max_items);
+    backups.truncate(max_items);
+    (backups, omitted)
+}
+
+/// Executed when you call `$ dy backup --delete`. Deletes AVAILABLE backups of the target table (or all tables with all_tables),
+/// optionally only ones created before the given time (RFC3339). DeleteBackup API calls are executed in parallel after a confirmation.
+pub async fn delete_backups(
+    cx: &app::Context,
+    all_tables: bool,
+    before: Option<String>,
+) -> Result<(), DyneinControlError> {
+    let before: Option<chrono::DateTime<chrono::FixedOffset>> = before
+        .map(|t| {
+            chrono::DateTime::parse_from_rfc3339(&t).map_err(|e| {
+                DyneinControlError::InvalidInput(format!(
+                    "Invalid --before '{}': {}. It should be RFC3339 format, e.g. '2024-01-01T00:00:00Z'.",
+                    t, e
+                ))
+            })
+        })
+        .transpose()?;
+
+    let backups: Vec<BackupSummary> = list_backups_api(cx, all_tables)
+        .await?
+        .into_iter()
+        .filter(|b| b.backup_status == Some(BackupStatus::Available))
+        .filter(|b| match (&before, b.backup_creation_date_time) {
+            (Some(before), Some(created_at)) => {
+                created_at.as_secs_f64() < before.timestamp_millis() as f64 / 1000.0
+            }
+            (Some(_), None) => false,
+            (None, _) => true,
+        })
+        .collect();
+    if backups.is_empty() {
+        println!("No AVAILABLE state backup found to delete.");
+        return Ok(());
+    };
+
+    println!("Following {} backups will be deleted:", backups.len());
+    for b in &backups {
+        println!(
+            "  {} ({}, {})",
+            b.backup_name.as_deref().unwrap_or_default(),
+            table::epoch_to_rfc3339(b.backup_creation_date_time.unwrap().as_secs_f64()),
+            table::humanize_size(b.backup_size_bytes.unwrap_or_default())
+        );
+    }
+
+    if cx.dry_run {
+        for b in &backups {
+            let input = api::delete_backup_input(b.backup_arn.as_deref().unwrap());
+            app::print_dry_run("DeleteBackup", &input);
+        }
+        return Ok(());
+    }
+
+    let msg = format!(
+        "You're trying to delete {} backups. Are you OK?",
+        backups.len()
+    );
+    if !app::confirm(cx, &msg)? {
+        println!("The backup delete operation has been canceled.");
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let results: Vec<_> = stream::iter(backups.iter())
+        .map(|b| api::delete_backup(&config, b.backup_arn.as_deref().unwrap()))
+        .buffered(DELETE_BACKUPS_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut deleted_count = 0;
+    let mut reclaimed_bytes: i64 = 0;
+    let mut failures: Vec<&str> = vec![];
+    for (b, result) in backups.iter().zip(results) {
+        match result {
+            Ok(_) => {
+                deleted_count += 1;
+                reclaimed_bytes += b.backup_size_bytes.unwrap_or_default();
+            }
+            Err(e) => {
+                let backup_name = b.backup_name.as_deref().unwrap_or_default();
+                error!(
+                    "Failed to delete the backup '{}': {}",
+                    backup_name,
+                    DisplayErrorContext(e)
+                );
+                failures.push(backup_name);
+            }
+        }
+    }
+
+    println!(
+        "Deleted {} backups ({} reclaimed).",
+        deleted_count,
+        table::humanize_size(reclaimed_bytes)
+    );
+    if !failures.is_empty() {
+        return Err(DyneinControlError::PartialFailure(format!(
+            "Failed to delete: {}",
+            failures.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Executed when you call `$ dy clone-schema <source> <new>`. Creates an empty table with the same schema as the source table.
+pub async fn clone_schema(
+    cx: &app::Context,
+    source_table: String,
+    new_table: String,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    let source_desc: TableDescription = describe_table_api(cx, source_table.clone()).await?;
+    let input = table::clone_schema_input(&source_desc, &new_table);
+    debug!("Trying to create a table with the input: {:?}", &input);
+    if cx.dry_run {
+        app::print_dry_run("CreateTable", &input);
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let desc = api::create_table_with_input(&config, input)
+        .await
+        .map_err(|e| {
+            debug!("CreateTable API call got an error -- {:#?}", e);
+            DyneinControlError::CreateTable(e)
+        })?;
+    println!(
+        "Started creating the table '{}' with the same schema as '{}'.",
+        new_table, source_table
+    );
+    let desc = if wait {
+        wait_until_table_active(cx, &new_table).await?
+    } else {
+        desc
+    };
+    table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+    Ok(())
+}
+
+/// Executed when you call `$ dy copy-table <source> <new>`. Takes an on-demand backup of the source table,
+/// waits for it to be AVAILABLE, then restores it into the new table. Only the same region copy is supported,
+/// as RestoreTableFromBackup API can restore a backup only in the region where the backup exists.
+pub async fn copy_table(
+    cx: &app::Context,
+    source_table: String,
+    new_table: String,
+    dest_region: Option<String>,
+    wait: bool,
+) -> Result<(), DyneinControlError> {
+    let region = cx.effective_region().await;
+    if let Some(dest_region) = dest_region.as_deref().map(app::resolve_region_alias) {
+        if dest_region != region.as_ref() {
+            return Err(DyneinControlError::InvalidInput(format!(
+                "Copying a table into another region ('{}' -> '{}') is not supported, as backups can be restored only in the same region. \
+                Consider exporting the table to S3 and importing it in the destination region.",
+                region.as_ref(),
+                dest_region
+            )));
+        }
+    }
+
+    let epoch: u64 = time::SystemTime::now()
+        .duration_since(time::SystemTime::UNIX_EPOCH)
+        .expect("should be able to generate UNIX EPOCH")
+        .as_secs();
+    let backup_name = format!("{}--dynein-copy-{}", source_table, epoch);
+
+    if cx.dry_run {
+        let input = api::create_backup_input(&source_table, &backup_name);
+        app::print_dry_run("CreateBackup", &input);
+        // The ARN of the backup is known only after CreateBackup API returns, hence a placeholder is shown.
+        let backup_arn = format!("<ARN of the backup '{}'>", backup_name);
+        let input = api::restore_table_from_backup_input(&backup_arn, &new_table, None, None, None);
+        app::print_dry_run("RestoreTableFromBackup", &input);
+        return Ok(());
+    }
+
+    let config = cx.effective_sdk_config().await;
+    let details = api::create_backup(&config, &source_table, &backup_name)
+        .await
+        .map_err(api_error("CreateBackup"))?;
+    println!(
+        "Backup '{}' of the table '{}' has been started.",
+        backup_name, source_table
+    );
+    wait_until_backup_available(cx, &details.backup_arn)
+        .await
+        .map_err(|e| {
+            eprintln!(
+                "NOTE: Copying into the table '{}' has not been started. The backup '{}' is kept, which you can restore by `dy restore` or delete by `dy backup --delete`.",
+                new_table, backup_name
+            );
+            e
+        })?;
+
+    let desc =
+        api::restore_table_from_backup(&config, &details.backup_arn, &new_table, None, None, None)
+            .await
+            .map_err(|e| {
+                debug!("RestoreTableFromBackup API call got an error -- {:#?}", e);
+                DyneinControlError::RestoreTableFromBackup(e)
+            })?;
+    println!(
+        "Copying the table '{}' into '{}' has been started. The backup '{}' is kept, which you can delete by `dy backup --delete`.",
+        source_table, new_table, backup_name
+    );
+    let desc = if wait {
+        wait_until_table_active(cx, &new_table).await?
+    } else {
+        desc
+    };
+    table::print_table_description(region.as_ref(), &desc);
     Ok(())
 }
 
+/// Polls DescribeBackup API until the backup becomes AVAILABLE. Returns an error after WAIT_TIMEOUT or when the backup is deleted.
+async fn wait_until_backup_available(
+    cx: &app::Context,
+    backup_arn: &str,
+) -> Result<(), DyneinControlError> {
+    let config = cx.effective_sdk_config().await;
+    let started_at = time::Instant::now();
+    loop {
+        let backup = api::describe_backup(&config, backup_arn)
+            .await
+            .map_err(api_error("DescribeBackup"))?;
+        let status = backup
+            .backup_details
+            .map(|details| details.backup_status)
+            .unwrap_or(BackupStatus::Creating);
+        match status {
+            BackupStatus::Available => return Ok(()),
+            BackupStatus::Deleted => {
+                return Err(DyneinControlError::PartialFailure(format!(
+                    "The backup '{}' has been deleted.",
+                    backup_arn
+                )))
+            }
+            _ => {}
+        }
+        if started_at.elapsed() >= WAIT_TIMEOUT {
+            return Err(DyneinControlError::WaitTimeout(format!(
+                "Timed out after {} seconds waiting for the backup '{}' to become AVAILABLE (status: {}).",
+                WAIT_TIMEOUT.as_secs(),
+                backup_arn,
+                status
+            )));
+        }
+        eprintln!(
+            "Waiting for the backup to become AVAILABLE... (status: {})",
+            status
+        );
+        tokio::time::sleep(WAIT_POLLING_INTERVAL).await;
+    }
+}
+
 /// This function restores DynamoDB table from specified backup data.
 /// If you don't specify backup data (name) explicitly, dynein will list backups and you can select out of them.
-/// Currently overwriting properties during rstore is not supported.
-pub async fn restore(cx: &app::Context, backup_name: Option<String>, restore_name: Option<String>) {
+/// Billing mode and capacity units of the restored table can be overwritten by mode_string, wcu, and rcu.
+/// Without them, the restored table has the same settings as the source table.
+#[allow(clippy::too_many_arguments)]
+pub async fn restore(
+    cx: &app::Context,
+    backup_name: Option<String>,
+    restore_name: Option<String>,
+    mode_string: Option<String>,
+    wcu: Option<i64>,
+    rcu: Option<i64>,
+    wait: bool,
+    copy_tags: bool,
+) -> Result<(), DyneinControlError> {
+    // Map given string into "Mode" enum. Note that in cmd.rs clap already limits acceptable values.
+    let mode: Option<table::Mode> = match mode_string.as_deref() {
+        None => None,
+        Some("ondemand") => Some(table::Mode::OnDemand),
+        Some("provisioned") => Some(table::Mode::Provisioned),
+        Some(_) => panic!(
+            "You shouldn't see this message as --mode can takes only 'provisioned' or 'ondemand'."
+        ),
+    };
+    // Capacity units are validated before choosing a backup, so that users don't have to choose it again.
+    // Restoring into Provisioned mode requires explicit capacity units, which are applied to GSIs as well.
+    let provisioned_throughput_override: Option<ProvisionedThroughput> =
+        resolve_billing(mode.as_ref(), wcu, rcu, true)?;
+
     // let backups = list_backups_api(&cx, false).await;
     let available_backups: Vec<BackupSummary> = list_backups_api(cx, false)
-        .await
+        .await?
         .into_iter()
         .filter(|b: &BackupSummary| b.to_owned().backup_status == Some(BackupStatus::Available))
         .collect();
     // let available_backups: Vec<BackupSummary> = backups.iter().filter(|b| b.backup_status.to_owned().unwrap() == "AVAILABLE").collect();
     if available_backups.is_empty() {
-        app::bye(0, "No AVAILABLE state backup found for the table.");
+        println!("No AVAILABLE state backup found for the table.");
+        return Ok(());
     };
 
     let source_table_name = cx.effective_table_name();
     let backup_arn = match backup_name {
-        Some(bname) => fetch_arn_from_backup_name(bname, available_backups),
+        Some(bname) => match fetch_arn_from_backup_name(&bname, &available_backups) {
+            Some(arn) => arn,
+            None => {
+                return Err(DyneinControlError::InvalidInput(format!(
+                    "No backup named '{}' found for the table '{}'. Available backups:\n{}",
+                    bname,
+                    source_table_name,
+                    backup_candidates(&available_backups)
+                )))
+            }
+        },
         None => {
-            let selection_texts: Vec<String> = available_backups
-                .iter()
-                .map(|b| {
-                    format!(
-                        "{} ({}, {} bytes)",
-                        b.to_owned().backup_name.unwrap(),
-                        table::epoch_to_rfc3339(b.backup_creation_date_time.unwrap().as_secs_f64()),
-                        b.backup_size_bytes.unwrap()
-                    )
-                })
-                .collect();
+            let selection_texts: Vec<String> = backup_selection_texts(&available_backups);
 
             debug!("available selections: {:#?}", selection_texts);
 
-            let selection = Select::with_theme(&ColorfulTheme::default())
-                .with_prompt("Select backup data to restore:")
-                .default(0) /* &mut Select */
-                .items(&selection_texts[..]) /* &mut Select */
-                .interact() /* Result<usize, Error> */
-                .unwrap();
+            // The interactive selector cannot work without a terminal (e.g. in a pipe or CI), hence --backup-name is required.
+            let selection = match app::select("Select backup data to restore:", &selection_texts) {
+                Err(app::DyneinConfirmationError::NotInteractive) => {
+                    return Err(DyneinControlError::InvalidInput(format!(
+                        "--backup-name is required when stdin is not a terminal. Available backups of the table '{}':\n{}",
+                        source_table_name,
+                        backup_candidates(&available_backups)
+                    )))
+                }
+                selection => selection?,
+            };
 
             available_backups[selection].backup_arn.clone().unwrap()
         }
@@ -566,81 +3428,1082 @@ pub async fn restore(cx: &app::Context, backup_name: Option<String>, restore_nam
     };
 
     let config = cx.effective_sdk_config().await;
-    let ddb = DynamoDbSdkClient::new(&config);
 
-    match ddb
-        .restore_table_from_backup()
-        .backup_arn(backup_arn.clone())
-        .target_table_name(target_table_name)
-        .send()
-        .await
-    {
-        Err(e) => {
-            debug!("RestoreTableFromBackup API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
+    // Restoring into Provisioned mode requires capacity units of GSIs as well as the table. GSIs get the same capacity units as the table.
+    let gsi_override = match &provisioned_throughput_override {
+        Some(provisioned_throughput) => {
+            generate_gsi_override(cx, &backup_arn, provisioned_throughput).await?
         }
-        Ok(res) => {
-            debug!("Returned result: {:#?}", res);
-            println!("Table restoration from: '{}' has been started", &backup_arn);
-            let desc = res.table_description.unwrap();
-            table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+        None => None,
+    };
+
+    // Backups don't carry tags of the source table, hence they are read from the source table itself if it still exists.
+    let source_table_arn = available_backups
+        .iter()
+        .find(|b| b.backup_arn.as_deref() == Some(backup_arn.as_str()))
+        .and_then(|b| b.table_arn.clone());
+    let tags: Vec<Tag> = match (copy_tags, &source_table_arn) {
+        (true, Some(source_table_arn)) => {
+            match api::list_tags_of_resource(&config, source_table_arn).await {
+                Ok(tags) => tags,
+                Err(e) => {
+                    eprintln!(
+                        "WARN: Tags of the source table '{}' are not copied as they cannot be retrieved: {}",
+                        source_table_name,
+                        DisplayErrorContext(e)
+                    );
+                    vec![]
+                }
+            }
+        }
+        _ => vec![],
+    };
+
+    if cx.dry_run {
+        let input = api::restore_table_from_backup_input(
+            &backup_arn,
+            &target_table_name,
+            mode.map(|m| m.into()),
+            provisioned_throughput_override,
+            gsi_override,
+        );
+        app::print_dry_run("RestoreTableFromBackup", &input);
+        if !tags.is_empty() {
+            // The restored table is in the same account and region as the source table, hence its ARN can be derived.
+            let target_table_arn = source_table_arn
+                .as_deref()
+                .and_then(|arn| arn.rsplit_once('/'))
+                .map(|(prefix, _)| format!("{}/{}", prefix, target_table_name));
+            let input = TagResourceInput::builder()
+                .set_resource_arn(target_table_arn)
+                .set_tags(Some(tags))
+                .build()
+                .expect("TagResourceInput should be valid");
+            app::print_dry_run("TagResource", &input);
+        }
+        return Ok(());
+    }
+
+    let desc = api::restore_table_from_backup(
+        &config,
+        &backup_arn,
+        &target_table_name,
+        mode.map(|m| m.into()),
+        provisioned_throughput_override,
+        gsi_override,
+    )
+    .await
+    .map_err(|e| {
+        debug!("RestoreTableFromBackup API call got an error -- {:#?}", e);
+        DyneinControlError::RestoreTableFromBackup(e)
+    })?;
+    debug!("Returned result: {:#?}", desc);
+    println!("Table restoration from: '{}' has been started", &backup_arn);
+    // Tags are applied after the restored table becomes ACTIVE, hence --copy-tags-to-restore implies --wait.
+    let desc = if wait || !tags.is_empty() {
+        wait_until_table_active(cx, &target_table_name)
+            .await
+            .map_err(|e| {
+                if !tags.is_empty() {
+                    eprintln!(
+                        "NOTE: Tags have not been copied to the table '{}'. Add them by `dy tag add` once it becomes ACTIVE.",
+                        target_table_name
+                    );
+                }
+                e
+            })?
+    } else {
+        desc
+    };
+    table::print_table_description(cx.effective_region().await.as_ref(), &desc);
+
+    if !tags.is_empty() {
+        let tag_count = tags.len();
+        let target_table_arn = desc.table_arn.expect("table ARN should exist");
+        match api::tag_resource(&config, &target_table_arn, tags).await {
+            Ok(_) => println!(
+                "Copied {} tag(s) of the table '{}' to the restored table '{}'.",
+                tag_count, source_table_name, target_table_name
+            ),
+            Err(e) => {
+                debug!("TagResource API call got an error -- {:#?}", e);
+                return Err(DyneinControlError::PartialFailure(format!(
+                    "The table '{}' has been restored, but failed to copy tags to it: {}. Add them by `dy tag add` instead.",
+                    target_table_name,
+                    DisplayErrorContext(e)
+                )));
+            }
         }
     }
+    Ok(())
 }
 
 /* =================================================
 Private functions
 ================================================= */
 
+/// Resolves provisioned throughput from --mode, --wcu and --rcu options of commands that create tables, e.g. `dy admin create table` and `dy restore`.
+/// Capacity units are acceptable only with Provisioned mode, and must be greater than 0. Returns None for OnDemand mode or no mode (i.e. unchanged).
+/// Unless capacity_required, missing capacity units are filled with the default (5) in Provisioned mode.
+fn resolve_billing(
+    mode: Option<&table::Mode>,
+    wcu: Option<i64>,
+    rcu: Option<i64>,
+    capacity_required: bool,
+) -> Result<Option<ProvisionedThroughput>, DyneinControlError> {
+    if mode != Some(&table::Mode::Provisioned) {
+        if wcu.is_some() || rcu.is_some() {
+            return Err(DyneinControlError::InvalidInput(String::from(
+                "--wcu/--rcu options are acceptable only with --mode provisioned.",
+            )));
+        }
+        return Ok(None);
+    }
+
+    for (option, units) in [("--wcu", wcu), ("--rcu", rcu)] {
+        match units {
+            Some(units) if units <= 0 => {
+                return Err(DyneinControlError::InvalidInput(format!(
+                    "{} should be greater than 0, but {} was given.",
+                    option, units
+                )))
+            }
+            None if capacity_required => {
+                return Err(DyneinControlError::InvalidInput(String::from(
+                    "--wcu and --rcu options are required with --mode provisioned.",
+                )))
+            }
+            _ => (),
+        }
+    }
+    if wcu.is_none() && rcu.is_none() {
+        println!("Using default capacity units for Provisioned mode (WCU: 5, RCU: 5).");
+    };
+    Ok(Some(
+        ProvisionedThroughput::builder()
+            .read_capacity_units(rcu.unwrap_or(5))
+            .write_capacity_units(wcu.unwrap_or(5))
+            .build()
+            .unwrap(),
+    ))
+}
+
 /// Basically called by list_tables function, which is called from `$ dy list`.
 /// To make ListTables API result reusable, separated API logic into this standalone function.
-async fn list_tables_api(cx: &app::Context, override_region: Option<&str>) -> Vec<String> {
+async fn list_tables_api(
+    cx: &app::Context,
+    override_region: Option<&str>,
+) -> Result<Vec<String>, DyneinControlError> {
+    try_list_tables_api(cx, override_region).await.map_err(|e| {
+        debug!("ListTables API call got an error -- {:#?}", e);
+        DyneinControlError::ListTables(e)
+    })
+}
+
+/// Lists tables in the region via ListTables API, and filters and sorts them by --prefix, --exclude-prefix and --sort options.
+/// The table currently used (i.e. switched by `dy use`) is marked with is_current.
+async fn listed_tables(
+    cx: &app::Context,
+    override_region: Option<&str>,
+    filter: &TableNameFilter,
+    options: &ListTablesOptions,
+) -> Result<Vec<ListedTable>, DyneinControlError> {
+    // Filter is applied first, so that tables filtered out are not described for sorting.
+    let table_names = filter.apply(list_tables_api(cx, override_region).await?);
+    // Tables are described only once even when both --sort size/items and --show-size are given.
+    let sizes =
+        if options.show_size || matches!(options.sort.as_deref(), Some("size") | Some("items")) {
+            describe_table_sizes(cx, override_region, &table_names)
+                .await?
+                .into_iter()
+                .map(Some)
+                .collect()
+        } else {
+            vec![None; table_names.len()]
+        };
+    let mut tables: Vec<(String, Option<ListedTableSize>)> =
+        table_names.into_iter().zip(sizes).collect();
+    sort_tables(options.sort.as_deref(), &mut tables);
+    let region = match override_region {
+        Some(r) => r.to_owned(),
+        None => cx.effective_region().await.to_string(),
+    };
+
+    let table_in_config = cx.cached_using_table_schema().await;
+    if table_in_config.is_none() {
+        debug!("No table information (currently using table) is found on config file");
+    }
+    Ok(tables
+        .into_iter()
+        .map(|(name, size)| ListedTable {
+            is_current: table_in_config
+                .as_ref()
+                .map_or(false, |t| t.region == region && t.name == name),
+            name,
+            region: region.clone(),
+            // Sizes retrieved only for --sort are not shown.
+            size: size.filter(|_| options.show_size),
+        })
+        .collect())
+}
+
+/// Retrieves size and item count of each table by DescribeTable API, with bounded concurrency given by --concurrency option.
+/// Sizes are returned in the same order as table_names.
+async fn describe_table_sizes(
+    cx: &app::Context,
+    override_region: Option<&str>,
+    table_names: &[String],
+) -> Result<Vec<ListedTableSize>, DyneinControlError> {
+    // The context is cloned only when the region is overridden.
+    let cx = match override_region {
+        Some(r) => Owned(cx.clone().with_region(r)),
+        None => Borrowed(cx),
+    };
+    let descs = stream::iter(table_names.iter().cloned())
+        .map(|t| try_describe_table_api(&cx, t))
+        .buffered(cx.concurrency)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<TableDescription>, _>>()
+        .map_err(DyneinControlError::DescribeTable)?;
+    Ok(descs
+        .iter()
+        .map(|desc| ListedTableSize {
+            size_bytes: desc.table_size_bytes.unwrap_or_default(),
+            item_count: desc.item_count.unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Sorts tables by the key given by --sort option. Sizes must be retrieved beforehand to sort by size or items.
+/// For size and items, tables are sorted in descending order (ties are broken by name).
+fn sort_tables(sort: Option<&str>, tables: &mut [(String, Option<ListedTableSize>)]) {
+    let by_size = match sort {
+        None => return,
+        Some("name") => {
+            tables.sort_by(|a, b| a.0.cmp(&b.0));
+            return;
+        }
+        Some("size") => true,
+        Some("items") => false,
+        Some(_) => panic!(
+            "You shouldn't see this message as --sort can take only 'name', 'size' or 'items'."
+        ),
+    };
+    let key = |size: &Option<ListedTableSize>| {
+        let size = size.unwrap_or_default();
+        if by_size {
+            size.size_bytes
+        } else {
+            size.item_count
+        }
+    };
+    tables.sort_by(|a, b| key(&b.1).cmp(&key(&a.1)).then_with(|| a.0.cmp(&b.0)));
+}
+
+/// Same as list_tables_api, but returns an error to the caller instead of exiting the process.
+async fn try_list_tables_api(
+    cx: &app::Context,
+    override_region: Option<&str>,
+) -> Result<Vec<String>, SdkError<ListTablesError, HttpResponse>> {
     let config = if let Some(override_region) = override_region {
         cx.effective_sdk_config_with_region(override_region).await
     } else {
         cx.effective_sdk_config().await
     };
-    let ddb = DynamoDbSdkClient::new(&config);
+    api::list_tables(&config).await
+}
+
+/// Generates GSI definitions for RestoreTableFromBackup API from GSIs of the backup's source table, with the given capacity units.
+/// Returns None when the source table has no GSI.
+async fn generate_gsi_override(
+    cx: &app::Context,
+    backup_arn: &str,
+    provisioned_throughput: &ProvisionedThroughput,
+) -> Result<Option<Vec<GlobalSecondaryIndex>>, DyneinControlError> {
+    let config = cx.effective_sdk_config().await;
+    let backup = api::describe_backup(&config, backup_arn)
+        .await
+        .map_err(api_error("DescribeBackup"))?;
+    let gsis = match backup
+        .source_table_feature_details
+        .and_then(|details| details.global_secondary_indexes)
+    {
+        Some(gsis) => gsis,
+        None => return Ok(None),
+    };
+    Ok(Some(
+        gsis.into_iter()
+            .map(|gsi| {
+                GlobalSecondaryIndex::builder()
+                    .set_index_name(gsi.index_name)
+                    .set_key_schema(gsi.key_schema)
+                    .set_projection(gsi.projection)
+                    .provisioned_throughput(provisioned_throughput.clone())
+                    .build()
+                    .expect("GSI of the backup should have index name and key schema")
+            })
+            .collect(),
+    ))
+}
+
+/// Retrieves settings of the table that are not included in TableDescription, i.e. TTL, PITR and Contributor Insights, in parallel.
+async fn describe_supplemental_settings(
+    cx: &app::Context,
+    table_name: &str,
+) -> table::SupplementalSettings {
+    let (ttl, pitr, contributor_insights) = futures::join!(
+        describe_ttl_api(cx, table_name),
+        describe_continuous_backups_api(cx, table_name),
+        describe_contributor_insights_api(cx, table_name)
+    );
+    table::SupplementalSettings {
+        ttl,
+        pitr,
+        contributor_insights,
+    }
+}
 
-    match ddb.list_tables().send().await {
+/// Retrieves continuous backups settings (including PITR) of the table, which are shown in `$ dy desc` output.
+/// Same as describe_ttl_api, a failure (e.g. DynamoDB Local doesn't support the API) is just logged and ignored.
+async fn describe_continuous_backups_api(
+    cx: &app::Context,
+    table_name: &str,
+) -> Option<ContinuousBackupsDescription> {
+    let region = cx.effective_region().await;
+    let config = cx.effective_sdk_config_with_region(region.as_ref()).await;
+    match api::describe_continuous_backups(&config, table_name).await {
+        Ok(backups) => Some(backups),
+        Err(e) => {
+            debug!(
+                "DescribeContinuousBackups API call got an error -- {}",
+                DisplayErrorContext(e)
+            );
+            None
+        }
+    }
+}
+
+/// Retrieves the status of CloudWatch Contributor Insights of the table, which is shown in `$ dy desc` output.
+/// Same as describe_ttl_api, a failure (e.g. DynamoDB Local doesn't support the API) is just logged and ignored.
+async fn describe_contributor_insights_api(
+    cx: &app::Context,
+    table_name: &str,
+) -> Option<ContributorInsightsStatus> {
+    let region = cx.effective_region().await;
+    let config = cx.effective_sdk_config_with_region(region.as_ref()).await;
+    match api::describe_contributor_insights(&config, table_name, None).await {
+        Ok(status) => status,
+        Err(e) => {
+            debug!(
+                "DescribeContributorInsights API call got an error -- {}",
+                DisplayErrorContext(e)
+            );
+            None
+        }
+    }
+}
+
+/// Retrieves TTL settings of the table, which are shown in `$ dy desc` output.
+/// As TTL is supplemental information for the description, a failure (e.g. lack of permission) is just logged and ignored.
+async fn describe_ttl_api(cx: &app::Context, table_name: &str) -> Option<TimeToLiveDescription> {
+    let region = cx.effective_region().await;
+    let config = cx.effective_sdk_config_with_region(region.as_ref()).await;
+    match api::describe_time_to_live(&config, table_name).await {
+        Ok(ttl) => Some(ttl),
         Err(e) => {
-            debug!("ListTables API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
+            debug!(
+                "DescribeTimeToLive API call got an error -- {}",
+                DisplayErrorContext(e)
+            );
+            None
         }
-        // ListTables API returns blank array even if no table exists in a region.
-        Ok(res) => res.table_names.expect("This message should not be shown"),
     }
 }
 
 /// This function is a private function that simply calls ListBackups API and return results
-async fn list_backups_api(cx: &app::Context, all_tables: bool) -> Vec<BackupSummary> {
+async fn list_backups_api(
+    cx: &app::Context,
+    all_tables: bool,
+) -> Result<Vec<BackupSummary>, DyneinControlError> {
     let config = cx.effective_sdk_config().await;
-    let ddb = DynamoDbSdkClient::new(&config);
+    let table_name = (!all_tables).then(|| cx.effective_table_name());
+
+    api::list_backups(&config, table_name.as_deref())
+        .await
+        .map_err(|e| {
+            debug!("ListBackups API call got an error -- {:#?}", e);
+            DyneinControlError::ListBackups(e)
+        })
+}
 
-    let mut req = ddb.list_backups();
-    if !all_tables {
-        req = req.table_name(cx.effective_table_name());
+/// Prints warnings for key names that are reserved words, which are usable but need care in expressions.
+fn print_key_name_warnings(key_schema: &[KeySchemaElement]) {
+    for warning in table::reserved_key_name_warnings(key_schema) {
+        eprintln!("{}", warning);
     }
+}
 
-    match req.send().await {
-        Err(e) => {
-            debug!("ListBackups API call got an error -- {:#?}", e);
-            app::bye_with_sdk_error(1, e);
-        }
-        Ok(res) => res
-            .backup_summaries
-            .expect("backup result should have something"),
+/// Calls DescribeBackup API to show encryption at rest of the backup, and warns if it doesn't match the source table.
+/// As the backup has already been created, failures of DescribeBackup are reported as a warning and don't abort the command.
+async fn verify_backup_encryption(
+    ddb: &DynamoDbSdkClient,
+    source_desc: &TableDescription,
+    backup_arn: &str,
+) {
+    let backup_sse: Option<SseDescription> =
+        match ddb.describe_backup().backup_arn(backup_arn).send().await {
+            Err(e) => {
+                debug!("DescribeBackup API call got an error -- {:#?}", e);
+                eprintln!(
+                    "WARN: Failed to verify encryption of the backup: {}",
+                    DisplayErrorContext(e)
+                );
+                return;
+            }
+            Ok(res) => res
+                .backup_description
+                .and_then(|d| d.source_table_feature_details)
+                .and_then(|f| f.sse_description),
+        };
+
+    println!("  Encryption: {}", table::sse_summary(&backup_sse));
+    if table::is_kms_encrypted(&source_desc.sse_description)
+        && !table::is_kms_encrypted(&backup_sse)
+    {
+        eprintln!(
+            "WARN: The source table is encrypted with a KMS key ({}), but the backup reports '{}'.",
+            table::sse_summary(&source_desc.sse_description),
+            table::sse_summary(&backup_sse)
+        );
     }
 }
 
+/// Returns descriptions of backups, e.g. "mybackup (2024-01-01T00:00:00+00:00, 1.2 MB)", to let users choose one to restore.
+fn backup_selection_texts(backups: &[BackupSummary]) -> Vec<String> {
+    backups
+        .iter()
+        .map(|b| {
+            format!(
+                "{} ({}, {})",
+                b.backup_name.as_deref().unwrap_or_default(),
+                b.backup_creation_date_time
+                    .map_or(String::from("-"), |t| table::epoch_to_rfc3339(
+                        t.as_secs_f64()
+                    )),
+                table::humanize_size(b.backup_size_bytes.unwrap_or_default())
+            )
+        })
+        .collect()
+}
+
+/// Returns indented lines of backup_selection_texts, used in error messages to show which backups can be restored.
+fn backup_candidates(backups: &[BackupSummary]) -> String {
+    backup_selection_texts(backups)
+        .iter()
+        .map(|t| format!("  {}", t))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns ARN of the backup with the given name, or None if no such backup is available.
 fn fetch_arn_from_backup_name(
-    backup_name: String,
-    available_backups: Vec<BackupSummary>,
-) -> String {
+    backup_name: &str,
+    available_backups: &[BackupSummary],
+) -> Option<String> {
     available_backups
-        .into_iter()
-        .find(|b| b.to_owned().backup_name.unwrap() == backup_name) /* Option<BackupSummary */
-        .unwrap() /* BackupSummary */
-        .backup_arn /* Option<String> */
-        .unwrap()
+        .iter()
+        .find(|b| b.backup_name.as_deref() == Some(backup_name)) /* Option<&BackupSummary> */
+        .and_then(|b| b.backup_arn.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_config::Region;
+
+    fn test_context() -> app::Context {
+        app::Context {
+            config: None,
+            cache: None,
+            overwritten_region: Some(Region::from_static("us-east-1")),
+            overwritten_table_name: Some(String::from("table1")),
+            overwritten_port: None,
+            overwritten_endpoint_url: None,
+            profile: None,
+            assume_role: None,
+            assumed_role_credentials: std::sync::Arc::default(),
+            output: None,
+            should_strict_for_query: None,
+            retry: None,
+            deadline: None,
+            max_retries: None,
+            concurrency: app::DEFAULT_CONCURRENCY,
+            assume_yes: false,
+            interactive: false,
+            no_cache: false,
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn test_table_name_filter() {
+        let names = || {
+            vec![
+                String::from("amplify-users"),
+                String::from("app-orders"),
+                String::from("app-users"),
+                String::from("logs"),
+            ]
+        };
+        assert_eq!(TableNameFilter::default().apply(names()), names());
+
+        let filter = TableNameFilter {
+            prefix: Some(String::from("app-")),
+            exclude_prefixes: vec![],
+        };
+        assert_eq!(filter.apply(names()), vec!["app-orders", "app-users"]);
+
+        let filter = TableNameFilter {
+            prefix: Some(String::from("a")),
+            exclude_prefixes: vec![String::from("amplify-"), String::from("app-o")],
+        };
+        assert_eq!(filter.apply(names()), vec!["app-users"]);
+    }
+
+    #[test]
+    fn test_index_spec_parse() {
+        assert_eq!(
+            IndexSpec::parse("rank_index:rank,N"),
+            Ok(IndexSpec {
+                name: String::from("rank_index"),
+                keys: vec![String::from("rank,N")],
+                projection: None,
+                non_key_attributes: vec![],
+            })
+        );
+        assert_eq!(
+            IndexSpec::parse("user_date_index:user,S+date,S:keys_only"),
+            Ok(IndexSpec {
+                name: String::from("user_date_index"),
+                keys: vec![String::from("user,S"), String::from("date,S")],
+                projection: Some(String::from("keys_only")),
+                non_key_attributes: vec![],
+            })
+        );
+        assert_eq!(
+            IndexSpec::parse("idx:pk,S:include=a,b"),
+            Ok(IndexSpec {
+                name: String::from("idx"),
+                keys: vec![String::from("pk,S")],
+                projection: Some(String::from("include")),
+                non_key_attributes: vec![String::from("a"), String::from("b")],
+            })
+        );
+        for spec in ["idx", "idx:", ":pk,S", "idx:pk,S:include", "idx:pk,S:none"] {
+            assert!(IndexSpec::parse(spec).is_err(), "{}", spec);
+        }
+    }
+
+    #[test]
+    fn test_index_spec_parse_gsi() {
+        assert_eq!(
+            IndexSpec::parse_gsi("user_date_index:user,S:date,N", None),
+            Ok(IndexSpec {
+                name: String::from("user_date_index"),
+                keys: vec![String::from("user,S"), String::from("date,N")],
+                projection: None,
+                non_key_attributes: vec![],
+            })
+        );
+        assert_eq!(
+            IndexSpec::parse_gsi("idx:pk,S", Some("include:a,b")),
+            Ok(IndexSpec {
+                name: String::from("idx"),
+                keys: vec![String::from("pk,S")],
+                projection: Some(String::from("include")),
+                non_key_attributes: vec![String::from("a"), String::from("b")],
+            })
+        );
+        assert_eq!(
+            IndexSpec::parse_gsi("idx:pk,S", Some("keys_only")).map(|spec| spec.projection),
+            Ok(Some(String::from("keys_only")))
+        );
+        for spec in ["idx", "idx:", ":pk,S", "idx:pk,S:", "idx:pk,S:sk,N:x,S"] {
+            assert!(IndexSpec::parse_gsi(spec, None).is_err(), "{}", spec);
+        }
+        for projection in ["include", "include:", "none"] {
+            assert!(
+                IndexSpec::parse_gsi("idx:pk,S", Some(projection)).is_err(),
+                "{}",
+                projection
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_table_gsi() {
+        let throughput = ProvisionedThroughput::builder()
+            .read_capacity_units(5)
+            .write_capacity_units(10)
+            .build()
+            .unwrap();
+        let (gsi, attribute_definitions) =
+            IndexSpec::parse_gsi("idx:pk,S:sk,N", Some("include:a,b"))
+                .unwrap()
+                .create_table_gsi(Some(throughput.clone()))
+                .unwrap();
+        assert_eq!(gsi.index_name, "idx");
+        assert_eq!(gsi.key_schema.len(), 2);
+        assert_eq!(gsi.provisioned_throughput, Some(throughput));
+        let projection = gsi.projection.unwrap();
+        assert_eq!(projection.projection_type, Some(ProjectionType::Include));
+        assert_eq!(
+            projection.non_key_attributes,
+            Some(vec![String::from("a"), String::from("b")])
+        );
+
+        // Attributes shared with the table keys are defined only once.
+        let (_, mut merged) =
+            table::generate_essential_key_definitions(&[String::from("pk,S")]).unwrap();
+        assert!(merge_attribute_definitions(&mut merged, attribute_definitions).is_ok());
+        let names: Vec<&str> = merged.iter().map(|d| d.attribute_name.as_str()).collect();
+        assert_eq!(names, vec!["pk", "sk"]);
+
+        let (_, conflicting) =
+            table::generate_essential_key_definitions(&[String::from("pk,N")]).unwrap();
+        assert!(merge_attribute_definitions(&mut merged, conflicting).is_err());
+    }
+
+    #[test]
+    fn test_index_provisioned_throughput() {
+        let units = |pt: Option<ProvisionedThroughput>| {
+            pt.map(|pt| (pt.read_capacity_units, pt.write_capacity_units))
+        };
+        assert_eq!(
+            units(index_provisioned_throughput(
+                &table::Mode::OnDemand,
+                None,
+                None,
+                None,
+                None
+            )),
+            None
+        );
+        // Capacity units of the base table are inherited unless given.
+        assert_eq!(
+            units(index_provisioned_throughput(
+                &table::Mode::Provisioned,
+                Some(10),
+                Some(20),
+                Some(3),
+                None
+            )),
+            Some((10, 3))
+        );
+        assert_eq!(
+            units(index_provisioned_throughput(
+                &table::Mode::Provisioned,
+                None,
+                None,
+                None,
+                None
+            )),
+            Some((5, 5))
+        );
+    }
+
+    #[test]
+    fn test_sort_tables() {
+        let size = |size_bytes, item_count| {
+            Some(ListedTableSize {
+                size_bytes,
+                item_count,
+            })
+        };
+        let tables = || {
+            vec![
+                (String::from("b"), size(100, 1)),
+                (String::from("c"), size(10, 5)),
+                (String::from("a"), size(100, 3)),
+            ]
+        };
+        let sorted = |sort| {
+            let mut tables = tables();
+            sort_tables(sort, &mut tables);
+            tables.into_iter().map(|(name, _)| name).collect::<Vec<_>>()
+        };
+        assert_eq!(sorted(None), vec!["b", "c", "a"]);
+        assert_eq!(sorted(Some("name")), vec!["a", "b", "c"]);
+        // The largest comes first, and ties are broken by name.
+        assert_eq!(sorted(Some("size")), vec!["a", "b", "c"]);
+        assert_eq!(sorted(Some("items")), vec!["c", "a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_table_invalid_input() {
+        let cx = test_context();
+        let result = create_table(
+            &cx,
+            String::from("table1"),
+            vec![],
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+            false,
+            None,
+            vec![],
+            false,
+            false,
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(DyneinControlError::InvalidInput(_))));
+
+        let result = create_table(
+            &cx,
+            String::from("table1"),
+            vec![String::from("pk,S")],
+            Some(String::from("ondemand")),
+            Some(5),
+            None,
+            vec![],
+            None,
+            None,
+            false,
+            None,
+            vec![],
+            false,
+            false,
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(DyneinControlError::InvalidInput(_))));
+
+        // GSI keys conflicting with the table keys are rejected before calling any API.
+        let result = create_table(
+            &cx,
+            String::from("table1"),
+            vec![String::from("pk,S")],
+            None,
+            None,
+            None,
+            vec![IndexSpec::parse_gsi("idx:pk,N", None).unwrap()],
+            None,
+            None,
+            false,
+            None,
+            vec![],
+            false,
+            false,
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(DyneinControlError::InvalidInput(_))));
+
+        let result = create_table(
+            &cx,
+            String::from("table1"),
+            vec![String::from("pk,S")],
+            None,
+            None,
+            None,
+            vec![IndexSpec::parse_gsi("idx:sk,N", None).unwrap()],
+            Some(5),
+            None,
+            false,
+            None,
+            vec![],
+            false,
+            false,
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(DyneinControlError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_restore_invalid_input() {
+        let cx = test_context();
+        let result = restore(&cx, None, None, None, Some(5), Some(5), false).await;
+        assert!(matches!(result, Err(DyneinControlError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_backup_selection_texts() {
+        let backups = vec![
+            BackupSummary::builder()
+                .backup_name("daily")
+                .backup_creation_date_time(DateTime::from_secs(1704067200))
+                .backup_size_bytes(2048)
+                .build(),
+            BackupSummary::builder().backup_name("pending").build(),
+        ];
+        assert_eq!(
+            backup_selection_texts(&backups),
+            vec![
+                String::from("daily (2024-01-01T00:00:00+00:00, 2.0 KB)"),
+                String::from("pending (-, 0 B)"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_newest_backups() {
+        let backup = |name: &str, created_at: i64| {
+            BackupSummary::builder()
+                .backup_name(name)
+                .backup_creation_date_time(DateTime::from_secs(created_at))
+                .build()
+        };
+        let backups = vec![
+            backup("old", 1704067200),
+            backup("newest", 1704240000),
+            backup("new", 1704153600),
+        ];
+        let names = |backups: &[BackupSummary]| {
+            backups
+                .iter()
+                .map(|b| b.backup_name.clone().unwrap())
+                .collect::<Vec<String>>()
+        };
+
+        let (kept, omitted) = newest_backups(backups.clone(), 2);
+        assert_eq!(names(&kept), vec!["newest", "new"]);
+        assert_eq!(omitted, 1);
+
+        let (kept, omitted) = newest_backups(backups, 5);
+        assert_eq!(names(&kept), vec!["newest", "new", "old"]);
+        assert_eq!(omitted, 0);
+    }
+
+    #[test]
+    fn test_fetch_arn_from_backup_name() {
+        let backups = vec![BackupSummary::builder()
+            .backup_name("daily")
+            .backup_arn("arn:aws:dynamodb:us-east-1:111111111111:table/t/backup/01")
+            .build()];
+        assert_eq!(
+            fetch_arn_from_backup_name("daily", &backups).as_deref(),
+            Some("arn:aws:dynamodb:us-east-1:111111111111:table/t/backup/01")
+        );
+        assert_eq!(fetch_arn_from_backup_name("dialy", &backups), None);
+    }
+
+    #[test]
+    fn test_validate_backup_name() {
+        assert!(validate_backup_name("app_users-2024.01.01").is_ok());
+        assert!(validate_backup_name("abc").is_ok());
+        assert!(validate_backup_name(&"a".repeat(255)).is_ok());
+        assert!(validate_backup_name("ab").is_err());
+        assert!(validate_backup_name(&"a".repeat(256)).is_err());
+        assert!(validate_backup_name("before release").is_err());
+        assert!(validate_backup_name("app/users").is_err());
+    }
+
+    #[test]
+    fn test_resolve_billing() {
+        let provisioned = Some(&table::Mode::Provisioned);
+        let invalid_input =
+            |result: Result<Option<ProvisionedThroughput>, DyneinControlError>| match result {
+                Err(DyneinControlError::InvalidInput(msg)) => msg,
+                _ => panic!("expected InvalidInput error"),
+            };
+
+        assert_eq!(resolve_billing(None, None, None, true).unwrap(), None);
+        assert_eq!(
+            invalid_input(resolve_billing(
+                Some(&table::Mode::OnDemand),
+                Some(10),
+                None,
+                false
+            )),
+            "--wcu/--rcu options are acceptable only with --mode provisioned."
+        );
+        assert_eq!(
+            invalid_input(resolve_billing(provisioned, Some(10), None, true)),
+            "--wcu and --rcu options are required with --mode provisioned."
+        );
+        assert_eq!(
+            invalid_input(resolve_billing(provisioned, Some(10), Some(0), true)),
+            "--rcu should be greater than 0, but 0 was given."
+        );
+
+        let throughput = resolve_billing(provisioned, Some(10), Some(20), true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(throughput.write_capacity_units, 10);
+        assert_eq!(throughput.read_capacity_units, 20);
+        // Without capacity_required, missing units are filled with the default.
+        let throughput = resolve_billing(provisioned, Some(10), None, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(throughput.read_capacity_units, 5);
+    }
+
+    #[tokio::test]
+    async fn test_delete_backups_invalid_before() {
+        let cx = test_context();
+        let result = delete_backups(&cx, false, Some(String::from("yesterday"))).await;
+        match result {
+            Err(DyneinControlError::InvalidInput(msg)) => {
+                assert!(msg.starts_with("Invalid --before 'yesterday'"))
+            }
+            _ => panic!("expected InvalidInput error"),
+        }
+    }
+
+    #[test]
+    fn test_ensure_table_active() {
+        let not_active = |result: Result<(), DyneinControlError>| match result {
+            Err(DyneinControlError::TableNotActive(msg)) => msg,
+            _ => panic!("expected TableNotActive error"),
+        };
+
+        let desc = TableDescription::builder()
+            .table_status(TableStatus::Active)
+            .build();
+        assert!(ensure_table_active("t", &desc).is_ok());
+
+        let desc = TableDescription::builder()
+            .table_status(TableStatus::Creating)
+            .build();
+        assert_eq!(
+            not_active(ensure_table_active("t", &desc)),
+            "The table 't' is not ACTIVE (status: CREATING)."
+        );
+
+        // A GSI being backfilled makes the table not ready even though the table itself is ACTIVE.
+        let desc = TableDescription::builder()
+            .table_status(TableStatus::Active)
+            .global_secondary_indexes(
+                GlobalSecondaryIndexDescription::builder()
+                    .index_name("idx")
+                    .index_status(IndexStatus::Creating)
+                    .backfilling(true)
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            not_active(ensure_table_active("t", &desc)),
+            "The table 't' is not ACTIVE (status: ACTIVE, indexes in progress: idx (CREATING, backfilling))."
+        );
+    }
+
+    #[test]
+    fn test_create_table_options() {
+        let (key_schema, attribute_definitions) =
+            table::generate_essential_key_definitions(&[String::from("pk,S")]).unwrap();
+        let throughput = ProvisionedThroughput::builder()
+            .read_capacity_units(5)
+            .write_capacity_units(10)
+            .build()
+            .unwrap();
+
+        let options = CreateTableOptions::default();
+        assert!(options.validate().is_ok());
+        let input =
+            options.create_table_input("t", key_schema.clone(), attribute_definitions.clone());
+        assert_eq!(input.billing_mode, Some(BillingMode::PayPerRequest));
+        assert_eq!(input.provisioned_throughput, None);
+
+        let options = CreateTableOptions {
+            mode: table::Mode::Provisioned,
+            provisioned_throughput: Some(throughput.clone()),
+            stream_specification: Some(
+                StreamSpecification::builder()
+                    .stream_enabled(true)
+                    .stream_view_type(StreamViewType::NewImage)
+                    .build()
+                    .unwrap(),
+            ),
+            ..Default::default()
+        };
+        assert!(options.validate().is_ok());
+        let input = options.create_table_input("t", key_schema, attribute_definitions);
+        assert_eq!(input.billing_mode, Some(BillingMode::Provisioned));
+        assert_eq!(input.provisioned_throughput, Some(throughput.clone()));
+        assert_eq!(
+            input.stream_specification.and_then(|s| s.stream_view_type),
+            Some(StreamViewType::NewImage)
+        );
+
+        // Capacity units must be consistent with the billing mode.
+        let provisioned_without_capacity = CreateTableOptions {
+            mode: table::Mode::Provisioned,
+            ..Default::default()
+        };
+        assert!(matches!(
+            provisioned_without_capacity.validate(),
+            Err(DyneinControlError::InvalidInput(_))
+        ));
+        let ondemand_with_capacity = CreateTableOptions {
+            provisioned_throughput: Some(throughput.clone()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            ondemand_with_capacity.validate(),
+            Err(DyneinControlError::InvalidInput(_))
+        ));
+
+        // So must capacity units of GSIs.
+        let (gsi, _) = IndexSpec::parse_gsi("idx:pk,S", None)
+            .unwrap()
+            .create_table_gsi(None)
+            .unwrap();
+        let provisioned_gsi_without_capacity = CreateTableOptions {
+            mode: table::Mode::Provisioned,
+            provisioned_throughput: Some(throughput),
+            global_secondary_indexes: Some(vec![gsi]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            provisioned_gsi_without_capacity.validate(),
+            Err(DyneinControlError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_expected_table_description() {
+        let (key_schema, attribute_definitions) =
+            table::generate_essential_key_definitions(&[String::from("pk,S")]).unwrap();
+        let expected = expected_table_description(
+            "t",
+            &key_schema,
+            &attribute_definitions,
+            &[],
+            &table::Mode::OnDemand,
+        );
+        assert!(table::compare_table_schemas(&expected, &expected).is_empty());
+
+        let provisioned = expected_table_description(
+            "t",
+            &key_schema,
+            &attribute_definitions,
+            &[],
+            &table::Mode::Provisioned,
+        );
+        assert_eq!(
+            table::compare_table_schemas(&expected, &provisioned),
+            vec!["~ mode: OnDemand -> Provisioned"]
+        );
+
+        // GSIs given by --gsi are a part of the expected schema.
+        let (gsi, _) = IndexSpec::parse_gsi("idx:pk,S", Some("keys_only"))
+            .unwrap()
+            .create_table_gsi(None)
+            .unwrap();
+        let with_gsi = expected_table_description(
+            "t",
+            &key_schema,
+            &attribute_definitions,
+            &[gsi],
+            &table::Mode::OnDemand,
+        );
+        assert_eq!(table::compare_table_schemas(&expected, &with_gsi).len(), 1);
+    }
 }
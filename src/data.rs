@@ -20,16 +20,20 @@ use std::{
     error::Error,
     fmt,
     io::{self, Write},
+    sync::OnceLock,
     vec::Vec,
 };
 
 use crate::parser::{AttributeDefinition, AttributeType, DyneinParser, ParseError};
 use aws_sdk_dynamodb::{
     operation::scan::ScanOutput,
+    primitives::Blob,
     types::{AttributeValue, ReturnValue},
     Client as DynamoDbSdkClient,
 };
+use base64::{engine::general_purpose, Engine as _};
 use log::{debug, error};
+use regex::Regex;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use serde_json::Value as JsonValue;
 use tabwriter::TabWriter;
@@ -72,6 +76,7 @@ pub enum DyneinQueryParamsError {
     NoSuchIndex(String /* index name */, String /* table name */),
     NoSortKeyDefined,
     InvalidSortKeyOption(ParseError),
+    InvalidKeyValue(DyneinKeyValueError),
 }
 
 impl From<ParseError> for DyneinQueryParamsError {
@@ -80,6 +85,39 @@ impl From<ParseError> for DyneinQueryParamsError {
     }
 }
 
+impl From<DyneinKeyValueError> for DyneinQueryParamsError {
+    fn from(err: DyneinKeyValueError) -> Self {
+        DyneinQueryParamsError::InvalidKeyValue(err)
+    }
+}
+
+/// Returned when a primary key value given from command line cannot be converted into the data type defined in the key schema.
+#[derive(Debug, PartialEq)]
+pub struct DyneinKeyValueError {
+    message: String,
+}
+
+impl DyneinKeyValueError {
+    fn new(key: &key::Key, val: &str, reason: &str) -> Self {
+        Self {
+            message: format!(
+                "The value '{}' is invalid for the key '{}': {}. \
+                Please execute 'dy desc' command to see key schema.",
+                val,
+                key.display(),
+                reason
+            ),
+        }
+    }
+}
+
+impl fmt::Display for DyneinKeyValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+impl Error for DyneinKeyValueError {}
+
 impl fmt::Display for DyneinQueryParamsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self {
@@ -111,6 +149,9 @@ impl fmt::Display for DyneinQueryParamsError {
                     err
                 )
             }
+            DyneinQueryParamsError::InvalidKeyValue(err) => {
+                write!(f, "{}", err)
+            }
         }
     }
 }
@@ -190,7 +231,7 @@ pub async fn scan_api(
         .await
         .unwrap_or_else(|e| {
             debug!("Scan API call got an error -- {:?}", e);
-            app::bye_with_sdk_error(1, e);
+            app::bye_with_sdk_error(cx, 1, e);
         })
 }
 
@@ -273,7 +314,7 @@ pub async fn query(cx: &app::Context, params: QueryParams) {
         }
         Err(e) => {
             debug!("Query API call got an error -- {:?}", e);
-            app::bye_with_sdk_error(1, e);
+            app::bye_with_sdk_error(cx, 1, e);
         }
     }
 }
@@ -329,7 +370,7 @@ pub async fn get_item(
         },
         Err(e) => {
             debug!("GetItem API call got an error -- {:?}", e);
-            app::bye_with_sdk_error(1, e);
+            app::bye_with_sdk_error(cx, 1, e);
         }
     }
 }
@@ -381,7 +422,7 @@ pub async fn put_item(cx: &app::Context, pval: String, sval: Option<String>, ite
         }
         Err(e) => {
             debug!("PutItem API call got an error -- {:?}", e);
-            app::bye_with_sdk_error(1, e);
+            app::bye_with_sdk_error(cx, 1, e);
         }
     }
 }
@@ -416,7 +457,7 @@ pub async fn delete_item(cx: &app::Context, pval: String, sval: Option<String>)
         }
         Err(e) => {
             debug!("Deletetem API call got an error -- {:?}", e);
-            app::bye_with_sdk_error(1, e);
+            app::bye_with_sdk_error(cx, 1, e);
         }
     }
 }
@@ -476,7 +517,7 @@ pub async fn update_item(
         }
         Err(e) => {
             debug!("UpdateItem API call got an error -- {:?}", e);
-            app::bye_with_sdk_error(1, e);
+            app::bye_with_sdk_error(cx, 1, e);
         }
     }
 }
@@ -590,7 +631,10 @@ fn identify_target(
     let mut target = HashMap::<String, AttributeValue>::new();
     target.insert(
         ts.pk.name.to_string(),
-        build_attrval_scalar(&ts.pk.kind.to_string(), pval),
+        build_attrval_scalar(&ts.pk, pval).unwrap_or_else(|e| {
+            error!("{}", e);
+            std::process::exit(1);
+        }),
     );
 
     // if sort key value is given from command line, add sort key to target HashMap to identify an item.
@@ -598,7 +642,10 @@ fn identify_target(
         match ts.sk.as_ref() {
             Some(sk) => target.insert(
                 sk.name.to_string(),
-                build_attrval_scalar(&sk.kind.to_string(), sval),
+                build_attrval_scalar(sk, sval).unwrap_or_else(|e| {
+                    error!("{}", e);
+                    std::process::exit(1);
+                }),
             ),
             None => {
                 error!("Partition and Sort keys are given to identify an item, but table '{t}' uses Partition key only. Check `dy desc {t}`", t = &ts.name);
@@ -616,20 +663,40 @@ fn identify_target(
 // top 3 scalar types that can be used for primary keys.
 //   ref: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.CoreComponents.html
 //        https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.NamingRulesDataTypes.html#HowItWorks.DataTypes
-fn build_attrval_scalar(_ktype: &str, _kval: &str) -> AttributeValue {
+// The data type is taken from the key schema of the table (or index), so the given value is coerced into it:
+//   S => as is, N => validated as a number, B => decoded as base64 string.
+fn build_attrval_scalar(key: &key::Key, kval: &str) -> Result<AttributeValue, DyneinKeyValueError> {
     debug!(
         "Constructing an AttributeValue for (type: {:?}, val: {:?})",
-        _ktype, _kval
+        key.kind, kval
     );
 
-    match _ktype {
-        "S" => AttributeValue::S(String::from(_kval)),
-        "N" => AttributeValue::N(String::from(_kval)), // NOTE: pass string, not number
-        // "B" => { attrval.b = Some(Bytes::from(_kval.clone().as_str())) },
-        _ => panic!("ERROR: Unknown DynamoDB Data Type: {}", _ktype),
+    match key.kind {
+        key::KeyType::S => Ok(AttributeValue::S(String::from(kval))),
+        key::KeyType::N => {
+            if is_valid_number(kval) {
+                Ok(AttributeValue::N(String::from(kval))) // NOTE: pass string, not number
+            } else {
+                Err(DyneinKeyValueError::new(key, kval, "not a valid number"))
+            }
+        }
+        key::KeyType::B => general_purpose::STANDARD
+            .decode(kval)
+            .map(|bytes| AttributeValue::B(Blob::new(bytes)))
+            .map_err(|e| {
+                DyneinKeyValueError::new(key, kval, &format!("not a valid base64 string ({})", e))
+            }),
     }
 }
 
+/// Check if the given string is a number literal accepted by DynamoDB, e.g. "123", "-1.5", or "1e10".
+fn is_valid_number(s: &str) -> bool {
+    static NUMBER_REGEX: OnceLock<Regex> = OnceLock::new();
+    NUMBER_REGEX
+        .get_or_init(|| Regex::new(r"^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?$").unwrap())
+        .is_match(s)
+}
+
 // for SS and NS DynamoDB Attributes.
 // :( serde_json::value::string -- to_string() --> "\"a\""
 // :) serde_json::value::string -- as_str() --> some("a") -- unwrap() --> "a"
@@ -769,22 +836,26 @@ fn strip_items(
 
 /// `strip_item` function strips non-existing data types in AttributeValue struct:
 ///
-///     { "pkA": AttributeValue {
-///         b: None,
-///         bool: None,
-///         bs: None,
-///         l: None,
-///         m: None,
-///         n: None,
-///         ns: None,
-///         null: None,
-///         s: Some("e0a170d9-5ce3-443b-bbce-d0d49c71d151"),
-///         ss: None
-///     }}
+/// ```text
+/// { "pkA": AttributeValue {
+///     b: None,
+///     bool: None,
+///     bs: None,
+///     l: None,
+///     m: None,
+///     n: None,
+///     ns: None,
+///     null: None,
+///     s: Some("e0a170d9-5ce3-443b-bbce-d0d49c71d151"),
+///     ss: None
+/// }}
+/// ```
 ///
 /// to something like this:
 ///
-///     { "pkA": { "S": "e0a170d9-5ce3-443b-bbce-d0d49c71d151" }
+/// ```text
+/// { "pkA": { "S": "e0a170d9-5ce3-443b-bbce-d0d49c71d151" }
+/// ```
 fn strip_item(item: &HashMap<String, AttributeValue>) -> HashMap<String, serde_json::Value> {
     item.iter()
         .map(|attr| {
@@ -822,7 +893,7 @@ fn generate_query_expressions(
             names.insert("#DYNEIN_PKNAME".to_owned(), ts.pk.name.to_owned());
             vals.insert(
                 ":DYNEIN_PKVAL".to_owned(),
-                build_attrval_scalar(&ts.pk.kind.to_string(), pval),
+                build_attrval_scalar(&ts.pk, pval)?,
             );
             sort_key_of_target_table_or_index = ts.sk.clone();
         }
@@ -841,7 +912,7 @@ fn generate_query_expressions(
                         );
                         vals.insert(
                             String::from(":DYNEIN_PKVAL"),
-                            build_attrval_scalar(&existing_idx.pk.kind.to_string(), pval),
+                            build_attrval_scalar(&existing_idx.pk, pval)?,
                         );
                         sort_key_of_target_table_or_index = existing_idx.sk.clone();
                         break;
@@ -1643,6 +1714,33 @@ mod tests {
         assert_eq!(actual.vals, None);
     }
 
+    #[test]
+    fn test_build_attrval_scalar() {
+        let key = |kind| key::Key {
+            name: "pk".to_owned(),
+            kind,
+        };
+
+        assert_eq!(
+            build_attrval_scalar(&key(key::KeyType::S), "123"),
+            Ok(AttributeValue::S("123".to_owned()))
+        );
+        for n in ["123", "-1.5", "+0.25", ".5", "1e10", "6.02E-23"] {
+            assert_eq!(
+                build_attrval_scalar(&key(key::KeyType::N), n),
+                Ok(AttributeValue::N(n.to_owned()))
+            );
+        }
+        for n in ["abc", "", "1.2.3", "0x10", "NaN", "inf"] {
+            assert!(build_attrval_scalar(&key(key::KeyType::N), n).is_err());
+        }
+        assert_eq!(
+            build_attrval_scalar(&key(key::KeyType::B), "3q2+7w=="),
+            Ok(AttributeValue::B(Blob::new(vec![0xde, 0xad, 0xbe, 0xef])))
+        );
+        assert!(build_attrval_scalar(&key(key::KeyType::B), "not base64!").is_err());
+    }
+
     #[test]
     fn test_dispatch_jsonvalue_to_attrval() {
         let string_list = r#"
@@ -15,39 +15,107 @@
  */
 
 use ::serde::{Deserialize, Serialize};
+use aws_sdk_dynamodb::operation::create_table::CreateTableInput;
 use aws_sdk_dynamodb::types::{
-    AttributeDefinition, BillingMode, BillingModeSummary, GlobalSecondaryIndexDescription,
-    KeySchemaElement, KeyType, LocalSecondaryIndexDescription, ProvisionedThroughputDescription,
-    ScalarAttributeType, StreamSpecification, TableDescription,
+    AttributeDefinition, BillingMode, BillingModeSummary, ContinuousBackupsDescription,
+    ContributorInsightsStatus, GlobalSecondaryIndex, GlobalSecondaryIndexDescription,
+    KeySchemaElement, KeyType, LocalSecondaryIndex, LocalSecondaryIndexDescription, Projection,
+    ProvisionedThroughput, ProvisionedThroughputDescription, ReplicaDescription,
+    ScalarAttributeType, SseDescription, SseStatus, StreamSpecification, TableClass,
+    TableClassSummary, TableDescription, Tag, TimeToLiveDescription,
 };
 use chrono::DateTime;
-use log::error;
+use console::style;
+use serde_json::{json, Value as JsonValue};
+use thiserror::Error;
 
+use crate::app::{IndexType, TableSchema};
 use crate::ddb::key;
+use crate::template::{self, TemplateError};
 
 /* =================================================
 struct / enum / const
 ================================================= */
 
-// TableDescription doesn't implement Serialize
+/// A DescribeTable response lacks a field required to show the table, e.g. a response of a mocked endpoint without AttributeDefinitions.
+#[derive(Error, Debug)]
+#[error("DescribeTable response of the table '{table_name}' doesn't include '{field}'.")]
+pub struct IncompleteDescriptionError {
+    pub table_name: String,
+    pub field: &'static str, // e.g. "AttributeDefinitions"
+}
+
+/// Summary of a table shown by `dy desc`, built from TableDescription by [`table_summary`].
+/// Unlike TableDescription, it implements Serialize, and keys and indexes are in readable forms such as "id (S)".
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PrintDescribeTable {
+    pub name: String,
+    pub region: String,
+    pub arn: Option<String>, // full table ARN, e.g. for IAM policies. May be missing on mocked endpoints.
+    pub status: String,
+    pub schema: PrintPrimaryKeys,
+
+    pub mode: Mode,
+    pub capacity: Option<PrintCapacity>,
+    // TableClassSummary is returned only after the table class has been set, otherwise the table is in STANDARD class.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_class: Option<String>, // e.g. "STANDARD_INFREQUENT_ACCESS"
+
+    pub gsi: Option<Vec<PrintSecondaryIndex>>,
+    pub lsi: Option<Vec<PrintSecondaryIndex>>,
+
+    pub stream: Option<String>,
+    // Replicas of a global table (version 2019.11.21), e.g. "us-west-2 (ACTIVE)". Omitted for tables without replicas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<Vec<String>>,
+    // TTL, PITR and Contributor Insights settings are not included in TableDescription. They're shown only when retrieved, see SupplementalSettings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pitr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contributor_insights: Option<String>, // e.g. "ENABLED" or "DISABLED"
+    // SSEDescription is returned only when the table is encrypted with a KMS key, i.e. not with the default AWS owned key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sse: Option<String>,
+
+    // Item count, size and creation time may be missing, e.g. on DynamoDB Local or mocked endpoints. Shown as 0 or ~ (null) in that case.
+    pub count: i64,
+    pub size_bytes: i64,
+    pub size_human: String, // e.g. "1.2 MB", while size_bytes keeps the raw number for machine processing.
+    pub created_at: Option<String>,
+}
+
+/// Compact form of the table description used by `dy desc --only-keys`, which shows only primary keys of the table and its indexes.
 #[derive(Serialize, Deserialize, Debug)]
-struct PrintDescribeTable {
+struct PrintTableKeys {
     name: String,
-    region: String,
-    status: String,
     schema: PrintPrimaryKeys,
+    gsi: Option<Vec<PrintIndexKeys>>,
+    lsi: Option<Vec<PrintIndexKeys>>,
+}
 
-    mode: Mode,
-    capacity: Option<PrintCapacityUnits>,
-
-    gsi: Option<Vec<PrintSecondaryIndex>>,
-    lsi: Option<Vec<PrintSecondaryIndex>>,
+#[derive(Serialize, Deserialize, Debug)]
+struct PrintIndexKeys {
+    name: String,
+    schema: PrintPrimaryKeys,
+}
 
-    stream: Option<String>,
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+enum PrintTable {
+    Full(PrintDescribeTable),
+    Keys(PrintTableKeys),
+}
 
-    count: i64,
-    size_bytes: i64,
-    created_at: String,
+/// Settings of a table that are not included in TableDescription but retrieved by separate APIs,
+/// i.e. DescribeTimeToLive, DescribeContinuousBackups and DescribeContributorInsights.
+/// `dy desc` retrieves them to show along with the table description.
+#[derive(Debug, Default)]
+pub struct SupplementalSettings {
+    pub ttl: Option<TimeToLiveDescription>,
+    pub pitr: Option<ContinuousBackupsDescription>,
+    pub contributor_insights: Option<ContributorInsightsStatus>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -66,99 +134,1175 @@ impl From<Mode> for BillingMode {
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-struct PrintPrimaryKeys {
-    pk: String,
-    sk: Option<String>,
+pub struct PrintPrimaryKeys {
+    pub pk: String,
+    pub sk: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct PrintCapacityUnits {
-    wcu: i64,
-    rcu: i64,
+impl PrintPrimaryKeys {
+    /// Partition and sort keys in a line, e.g. "userId (S) / createdAt (N)", which is handy to build queries of a composite key.
+    /// Returns None for a table (or an index) without sort key.
+    pub fn composite(&self) -> Option<String> {
+        self.sk.as_ref().map(|sk| format!("{} / {}", self.pk, sk))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct PrintCapacityUnits {
+    pub wcu: i64,
+    pub rcu: i64,
+    // Recent history of throughput changes, which helps to plan capacity changes as decreases are limited per day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_increased_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_decreased_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_decreases_today: Option<i64>,
+}
+
+/// Capacity shown in desc output. While billing mode is being switched, DynamoDB may return a provisioned
+/// billing mode without provisioned throughput. In that case the capacity is rendered as "transitioning".
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum PrintCapacity {
+    Units(PrintCapacityUnits),
+    Transitioning(String),
 }
 
+const CAPACITY_TRANSITIONING: &str = "transitioning";
+
+/// Top-level fields of PrintDescribeTable, which can be selected by `dy desc --fields`.
+pub const DESCRIBE_TABLE_FIELDS: [&str; 20] = [
+    "name",
+    "region",
+    "arn",
+    "status",
+    "schema",
+    "mode",
+    "capacity",
+    "table_class",
+    "gsi",
+    "lsi",
+    "stream",
+    "replicas",
+    "ttl",
+    "pitr",
+    "contributor_insights",
+    "sse",
+    "count",
+    "size_bytes",
+    "size_human",
+    "created_at",
+];
+
 #[derive(Serialize, Deserialize, Debug)]
-struct PrintSecondaryIndex {
-    name: String,
-    schema: PrintPrimaryKeys,
-    capacity: Option<PrintCapacityUnits>,
+pub struct PrintSecondaryIndex {
+    pub name: String,
+    pub schema: PrintPrimaryKeys,
+    pub capacity: Option<PrintCapacity>,
+    pub projection: String, // e.g. "ALL", "KEYS_ONLY" or "INCLUDE (attr1, attr2)"
+    // Only GSIs have status and backfilling. A GSI being backfilled cannot be queried yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backfilling: Option<bool>,
 }
 
 /// Receives region (just to show in one line for reference) and TableDescription,
 /// print them in readable YAML format. NOTE: '~' representes 'null' or 'no value' in YAML syntax.
 pub fn print_table_description(region: &str, desc: &TableDescription) {
-    let attr_defs = desc.attribute_definitions.as_ref().unwrap();
+    println!(
+        "{}",
+        serde_yaml::to_string(&build_print_table(
+            region,
+            desc,
+            &SupplementalSettings::default()
+        ))
+        .unwrap()
+    );
+}
+
+/// Returns the summary of the table printed by `dy desc`, for programs that embed dynein as a library.
+/// Settings retrieved by other APIs than DescribeTable (e.g. TTL and PITR) are not included.
+/// Returns an error rather than panicking when the description lacks fields to show the table, see [`check_table_description`].
+pub fn table_summary(
+    region: &str,
+    desc: &TableDescription,
+) -> Result<PrintDescribeTable, IncompleteDescriptionError> {
+    check_table_description(desc)?;
+    Ok(build_print_table(
+        region,
+        desc,
+        &SupplementalSettings::default(),
+    ))
+}
+
+/// Prints the table description in the format given by --output option, i.e. YAML (default), JSON or raw.
+/// When only_keys is true, only primary keys of the table and its indexes are printed, except for raw which prints everything.
+/// When fields (--fields) are given, only the top-level fields are printed.
+/// Supplemental settings (e.g. TTL) are printed as well when they're retrieved.
+pub fn print_table_description_with_output(
+    region: &str,
+    desc: &TableDescription,
+    settings: &SupplementalSettings,
+    output: Option<&str>,
+    only_keys: bool,
+    fields: Option<&[String]>,
+) {
+    if output == Some("raw") {
+        let raw = raw_table_description(desc);
+        println!("{}", serde_json::to_string_pretty(&raw).unwrap());
+        return;
+    }
+    let table = build_print(region, desc, settings, only_keys);
+    match fields {
+        Some(fields) => print_document(&project_fields(&table, fields), output),
+        None => print_document(&table, output),
+    }
+}
+
+/// Prints descriptions of multiple tables as one document, i.e. YAML multi-document stream by default or a JSON array when output is "json" or "raw".
+/// Each description comes with the region of the table, as tables given by ARNs may be in different regions.
+pub fn print_table_descriptions(
+    descs: &[(&str, &TableDescription, &SupplementalSettings)],
+    output: Option<&str>,
+    only_keys: bool,
+    fields: Option<&[String]>,
+) {
+    if output == Some("raw") {
+        let raws: Vec<JsonValue> = descs
+            .iter()
+            .map(|(_, desc, _)| raw_table_description(desc))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&raws).unwrap());
+        return;
+    }
+    let tables: Vec<PrintTable> = descs
+        .iter()
+        .map(|(region, desc, settings)| build_print(region, desc, settings, only_keys))
+        .collect();
+    match fields {
+        Some(fields) => {
+            let projected: Vec<JsonValue> = tables
+                .iter()
+                .map(|table| project_fields(table, fields))
+                .collect();
+            print_documents(&projected, output)
+        }
+        None => print_documents(&tables, output),
+    }
+}
+
+/// Validates field names given by `dy desc --fields`, which should be top-level fields of the YAML/JSON output.
+pub fn validate_fields(fields: &[String]) -> Result<(), String> {
+    match fields
+        .iter()
+        .find(|f| !DESCRIBE_TABLE_FIELDS.contains(&f.as_str()))
+    {
+        Some(f) => Err(format!(
+            "Unknown field: '{}'. Available fields: [{}].",
+            f,
+            DESCRIBE_TABLE_FIELDS.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Keeps only the given top-level fields of the table description, used by `dy desc --fields`.
+/// Fields omitted in the description (e.g. ttl when it's not retrieved) are shown as null.
+fn project_fields<T: Serialize>(table: &T, fields: &[String]) -> JsonValue {
+    let value = serde_json::to_value(table).unwrap();
+    JsonValue::Object(
+        fields
+            .iter()
+            .map(|f| {
+                (
+                    f.to_owned(),
+                    value.get(f).cloned().unwrap_or(JsonValue::Null),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn print_document<T: Serialize>(document: &T, output: Option<&str>) {
+    match output {
+        Some("json") => println!("{}", serde_json::to_string_pretty(document).unwrap()),
+        _ => println!("{}", serde_yaml::to_string(document).unwrap()),
+    }
+}
+
+fn print_documents<T: Serialize>(documents: &[T], output: Option<&str>) {
+    match output {
+        Some("json") => println!("{}", serde_json::to_string_pretty(documents).unwrap()),
+        _ => {
+            for document in documents {
+                print!("---\n{}", serde_yaml::to_string(document).unwrap());
+            }
+        }
+    }
+}
+
+/// Builds the complete TableDescription as JSON for `--output raw`, with the same field names as DescribeTable API response.
+/// Unlike the curated output, it includes fields dynein doesn't show otherwise, e.g. RestoreSummary, ArchivalSummary and Replicas.
+/// As TableDescription doesn't implement Serialize, fields are converted one by one and missing ones are omitted.
+pub fn raw_table_description(desc: &TableDescription) -> JsonValue {
+    raw_object(vec![
+        (
+            "AttributeDefinitions",
+            desc.attribute_definitions.as_ref().map(|defs| {
+                defs.iter()
+                    .map(|d| {
+                        json!({
+                            "AttributeName": d.attribute_name,
+                            "AttributeType": d.attribute_type.as_str(),
+                        })
+                    })
+                    .collect()
+            }),
+        ),
+        ("TableName", desc.table_name.as_ref().map(|v| json!(v))),
+        ("KeySchema", desc.key_schema.as_deref().map(raw_key_schema)),
+        (
+            "TableStatus",
+            desc.table_status.as_ref().map(|v| json!(v.as_str())),
+        ),
+        (
+            "CreationDateTime",
+            desc.creation_date_time.as_ref().map(raw_date_time),
+        ),
+        (
+            "ProvisionedThroughput",
+            desc.provisioned_throughput.as_ref().map(raw_throughput),
+        ),
+        ("TableSizeBytes", desc.table_size_bytes.map(|v| json!(v))),
+        ("ItemCount", desc.item_count.map(|v| json!(v))),
+        ("TableArn", desc.table_arn.as_ref().map(|v| json!(v))),
+        ("TableId", desc.table_id.as_ref().map(|v| json!(v))),
+        (
+            "BillingModeSummary",
+            desc.billing_mode_summary.as_ref().map(|s| {
+                raw_object(vec![
+                    (
+                        "BillingMode",
+                        s.billing_mode.as_ref().map(|v| json!(v.as_str())),
+                    ),
+                    (
+                        "LastUpdateToPayPerRequestDateTime",
+                        s.last_update_to_pay_per_request_date_time
+                            .as_ref()
+                            .map(raw_date_time),
+                    ),
+                ])
+            }),
+        ),
+        (
+            "LocalSecondaryIndexes",
+            desc.local_secondary_indexes.as_ref().map(|indexes| {
+                indexes
+                    .iter()
+                    .map(|i| {
+                        raw_object(vec![
+                            ("IndexName", i.index_name.as_ref().map(|v| json!(v))),
+                            ("KeySchema", i.key_schema.as_deref().map(raw_key_schema)),
+                            ("Projection", i.projection.as_ref().map(raw_projection)),
+                            ("IndexSizeBytes", i.index_size_bytes.map(|v| json!(v))),
+                            ("ItemCount", i.item_count.map(|v| json!(v))),
+                            ("IndexArn", i.index_arn.as_ref().map(|v| json!(v))),
+                        ])
+                    })
+                    .collect()
+            }),
+        ),
+        (
+            "GlobalSecondaryIndexes",
+            desc.global_secondary_indexes.as_ref().map(|indexes| {
+                indexes
+                    .iter()
+                    .map(|i| {
+                        raw_object(vec![
+                            ("IndexName", i.index_name.as_ref().map(|v| json!(v))),
+                            ("KeySchema", i.key_schema.as_deref().map(raw_key_schema)),
+                            ("Projection", i.projection.as_ref().map(raw_projection)),
+                            (
+                                "IndexStatus",
+                                i.index_status.as_ref().map(|v| json!(v.as_str())),
+                            ),
+                            ("Backfilling", i.backfilling.map(|v| json!(v))),
+                            (
+                                "ProvisionedThroughput",
+                                i.provisioned_throughput.as_ref().map(raw_throughput),
+                            ),
+                            ("IndexSizeBytes", i.index_size_bytes.map(|v| json!(v))),
+                            ("ItemCount", i.item_count.map(|v| json!(v))),
+                            ("IndexArn", i.index_arn.as_ref().map(|v| json!(v))),
+                        ])
+                    })
+                    .collect()
+            }),
+        ),
+        (
+            "StreamSpecification",
+            desc.stream_specification.as_ref().map(|s| {
+                raw_object(vec![
+                    ("StreamEnabled", Some(json!(s.stream_enabled))),
+                    (
+                        "StreamViewType",
+                        s.stream_view_type.as_ref().map(|v| json!(v.as_str())),
+                    ),
+                ])
+            }),
+        ),
+        (
+            "LatestStreamLabel",
+            desc.latest_stream_label.as_ref().map(|v| json!(v)),
+        ),
+        (
+            "LatestStreamArn",
+            desc.latest_stream_arn.as_ref().map(|v| json!(v)),
+        ),
+        (
+            "GlobalTableVersion",
+            desc.global_table_version.as_ref().map(|v| json!(v)),
+        ),
+        (
+            "Replicas",
+            desc.replicas.as_ref().map(|replicas| {
+                replicas
+                    .iter()
+                    .map(|r| {
+                        raw_object(vec![
+                            ("RegionName", r.region_name.as_ref().map(|v| json!(v))),
+                            (
+                                "ReplicaStatus",
+                                r.replica_status.as_ref().map(|v| json!(v.as_str())),
+                            ),
+                            (
+                                "ReplicaStatusDescription",
+                                r.replica_status_description.as_ref().map(|v| json!(v)),
+                            ),
+                            (
+                                "ReplicaStatusPercentProgress",
+                                r.replica_status_percent_progress.as_ref().map(|v| json!(v)),
+                            ),
+                            (
+                                "KMSMasterKeyId",
+                                r.kms_master_key_id.as_ref().map(|v| json!(v)),
+                            ),
+                            (
+                                "ProvisionedThroughputOverride",
+                                r.provisioned_throughput_override.as_ref().map(|o| {
+                                    raw_object(vec![(
+                                        "ReadCapacityUnits",
+                                        o.read_capacity_units.map(|v| json!(v)),
+                                    )])
+                                }),
+                            ),
+                            (
+                                "GlobalSecondaryIndexes",
+                                r.global_secondary_indexes.as_ref().map(|indexes| {
+                                    indexes
+                                        .iter()
+                                        .map(|i| {
+                                            raw_object(vec![
+                                                (
+                                                    "IndexName",
+                                                    i.index_name.as_ref().map(|v| json!(v)),
+                                                ),
+                                                (
+                                                    "ProvisionedThroughputOverride",
+                                                    i.provisioned_throughput_override.as_ref().map(
+                                                        |o| {
+                                                            raw_object(vec![(
+                                                                "ReadCapacityUnits",
+                                                                o.read_capacity_units
+                                                                    .map(|v| json!(v)),
+                                                            )])
+                                                        },
+                                                    ),
+                                                ),
+                                            ])
+                                        })
+                                        .collect()
+                                }),
+                            ),
+                            (
+                                "ReplicaInaccessibleDateTime",
+                                r.replica_inaccessible_date_time.as_ref().map(raw_date_time),
+                            ),
+                            (
+                                "ReplicaTableClassSummary",
+                                r.replica_table_class_summary
+                                    .as_ref()
+                                    .map(raw_table_class_summary),
+                            ),
+                        ])
+                    })
+                    .collect()
+            }),
+        ),
+        (
+            "RestoreSummary",
+            desc.restore_summary.as_ref().map(|s| {
+                raw_object(vec![
+                    (
+                        "SourceBackupArn",
+                        s.source_backup_arn.as_ref().map(|v| json!(v)),
+                    ),
+                    (
+                        "SourceTableArn",
+                        s.source_table_arn.as_ref().map(|v| json!(v)),
+                    ),
+                    ("RestoreDateTime", Some(raw_date_time(&s.restore_date_time))),
+                    ("RestoreInProgress", Some(json!(s.restore_in_progress))),
+                ])
+            }),
+        ),
+        (
+            "SSEDescription",
+            desc.sse_description.as_ref().map(|s| {
+                raw_object(vec![
+                    ("Status", s.status.as_ref().map(|v| json!(v.as_str()))),
+                    ("SSEType", s.sse_type.as_ref().map(|v| json!(v.as_str()))),
+                    (
+                        "KMSMasterKeyArn",
+                        s.kms_master_key_arn.as_ref().map(|v| json!(v)),
+                    ),
+                    (
+                        "InaccessibleEncryptionDateTime",
+                        s.inaccessible_encryption_date_time
+                            .as_ref()
+                            .map(raw_date_time),
+                    ),
+                ])
+            }),
+        ),
+        (
+            "ArchivalSummary",
+            desc.archival_summary.as_ref().map(|s| {
+                raw_object(vec![
+                    (
+                        "ArchivalDateTime",
+                        s.archival_date_time.as_ref().map(raw_date_time),
+                    ),
+                    (
+                        "ArchivalReason",
+                        s.archival_reason.as_ref().map(|v| json!(v)),
+                    ),
+                    (
+                        "ArchivalBackupArn",
+                        s.archival_backup_arn.as_ref().map(|v| json!(v)),
+                    ),
+                ])
+            }),
+        ),
+        (
+            "TableClassSummary",
+            desc.table_class_summary
+                .as_ref()
+                .map(raw_table_class_summary),
+        ),
+        (
+            "DeletionProtectionEnabled",
+            desc.deletion_protection_enabled.map(|v| json!(v)),
+        ),
+    ])
+}
+
+/// Builds a JSON object from the given fields, omitting missing (None) ones.
+fn raw_object(fields: Vec<(&str, Option<JsonValue>)>) -> JsonValue {
+    JsonValue::Object(
+        fields
+            .into_iter()
+            .filter_map(|(k, v)| v.map(|v| (String::from(k), v)))
+            .collect(),
+    )
+}
+
+fn raw_key_schema(keys: &[KeySchemaElement]) -> JsonValue {
+    keys.iter()
+        .map(|k| {
+            json!({
+                "AttributeName": k.attribute_name,
+                "KeyType": k.key_type.as_str(),
+            })
+        })
+        .collect()
+}
+
+fn raw_projection(projection: &Projection) -> JsonValue {
+    raw_object(vec![
+        (
+            "ProjectionType",
+            projection
+                .projection_type
+                .as_ref()
+                .map(|v| json!(v.as_str())),
+        ),
+        (
+            "NonKeyAttributes",
+            projection.non_key_attributes.as_ref().map(|v| json!(v)),
+        ),
+    ])
+}
+
+fn raw_throughput(throughput: &ProvisionedThroughputDescription) -> JsonValue {
+    raw_object(vec![
+        (
+            "LastIncreaseDateTime",
+            throughput
+                .last_increase_date_time
+                .as_ref()
+                .map(raw_date_time),
+        ),
+        (
+            "LastDecreaseDateTime",
+            throughput
+                .last_decrease_date_time
+                .as_ref()
+                .map(raw_date_time),
+        ),
+        (
+            "NumberOfDecreasesToday",
+            throughput.number_of_decreases_today.map(|v| json!(v)),
+        ),
+        (
+            "ReadCapacityUnits",
+            throughput.read_capacity_units.map(|v| json!(v)),
+        ),
+        (
+            "WriteCapacityUnits",
+            throughput.write_capacity_units.map(|v| json!(v)),
+        ),
+    ])
+}
+
+fn raw_table_class_summary(summary: &TableClassSummary) -> JsonValue {
+    raw_object(vec![
+        (
+            "TableClass",
+            summary.table_class.as_ref().map(|v| json!(v.as_str())),
+        ),
+        (
+            "LastUpdateDateTime",
+            summary.last_update_date_time.as_ref().map(raw_date_time),
+        ),
+    ])
+}
+
+fn raw_date_time(t: &aws_sdk_dynamodb::primitives::DateTime) -> JsonValue {
+    json!(epoch_to_rfc3339(t.as_secs_f64()))
+}
+
+/// Renders the table description with a template given by --format option, e.g. '{{.name}} {{.status}}'.
+/// Fields are the same as ones in the YAML output of print_table_description (or print_table_description_with_output with only_keys).
+pub fn render_table_description(
+    region: &str,
+    desc: &TableDescription,
+    settings: &SupplementalSettings,
+    template: &str,
+    only_keys: bool,
+) -> Result<String, TemplateError> {
+    let fields = serde_json::to_value(build_print(region, desc, settings, only_keys)).unwrap();
+    template::render(template, &fields)
+}
+
+/// Builds rows of "key, value" pairs for `dy desc --output table`, which are meant to be printed as aligned columns (e.g. with TabWriter).
+/// Secondary indexes are listed as indented sub-rows under the "gsi" / "lsi" rows.
+pub fn table_description_rows(
+    region: &str,
+    desc: &TableDescription,
+    settings: &SupplementalSettings,
+    only_keys: bool,
+) -> Vec<(String, String)> {
+    let mut rows: Vec<(String, String)> = vec![];
+    let mut push = |k: &str, v: String| rows.push((String::from(k), v));
+    match build_print(region, desc, settings, only_keys) {
+        PrintTable::Keys(t) => {
+            push("name", t.name);
+            let composite = t.schema.composite();
+            push("pk", t.schema.pk);
+            push("sk", t.schema.sk.unwrap_or_else(|| String::from("~")));
+            if let Some(composite) = composite {
+                push("composite", composite);
+            }
+            for (kind, indexes) in [("gsi", t.gsi), ("lsi", t.lsi)] {
+                let indexes = match indexes {
+                    Some(indexes) => indexes,
+                    None => continue,
+                };
+                push(kind, String::new());
+                for idx in indexes {
+                    push(
+                        &format!("  {}", idx.name),
+                        primary_keys_summary(&idx.schema),
+                    );
+                }
+            }
+        }
+        PrintTable::Full(t) => {
+            push("name", t.name);
+            push("region", t.region);
+            if let Some(arn) = t.arn {
+                push("arn", arn);
+            }
+            push("status", t.status);
+            let composite = t.schema.composite();
+            push("pk", t.schema.pk);
+            push("sk", t.schema.sk.unwrap_or_else(|| String::from("~")));
+            if let Some(composite) = composite {
+                push("composite", composite);
+            }
+            push("mode", format!("{:?}", t.mode));
+            if let Some(capacity) = &t.capacity {
+                push("capacity", capacity_summary(capacity));
+            }
+            if let Some(table_class) = t.table_class {
+                push("table_class", table_class);
+            }
+            for (kind, indexes) in [("gsi", t.gsi), ("lsi", t.lsi)] {
+                let indexes = match indexes {
+                    Some(indexes) => indexes,
+                    None => continue,
+                };
+                push(kind, String::new());
+                for idx in indexes {
+                    let mut line = format!(
+                        "{}, projection: {}",
+                        primary_keys_summary(&idx.schema),
+                        idx.projection
+                    );
+                    if let Some(capacity) = &idx.capacity {
+                        line = format!("{}, {}", line, capacity_summary(capacity));
+                    }
+                    if let Some(status) = &idx.status {
+                        line = format!("{}, {}", line, status);
+                    }
+                    if idx.backfilling == Some(true) {
+                        line = format!("{} (backfilling)", line);
+                    }
+                    push(&format!("  {}", idx.name), line);
+                }
+            }
+            push("stream", t.stream.unwrap_or_else(|| String::from("~")));
+            for (k, v) in [
+                ("replicas", t.replicas.map(|replicas| replicas.join(", "))),
+                ("ttl", t.ttl),
+                ("pitr", t.pitr),
+                ("contributor_insights", t.contributor_insights),
+                ("sse", t.sse),
+            ] {
+                if let Some(v) = v {
+                    push(k, v);
+                }
+            }
+            push("count", t.count.to_string());
+            push("size_bytes", t.size_bytes.to_string());
+            push("size_human", t.size_human);
+            push(
+                "created_at",
+                t.created_at.unwrap_or_else(|| String::from("~")),
+            );
+        }
+    }
+    rows
+}
+
+/// Compares schemas of two tables, i.e. keys, billing mode, stream settings and indexes, for migration validation.
+/// Returns differences field by field in a diff-like form, e.g. "~ mode: OnDemand -> Provisioned", "- gsi.by_email: ..." (only in a)
+/// or "+ lsi.by_date: ..." (only in b). An empty result means the schemas are the same.
+pub fn compare_table_schemas(a: &TableDescription, b: &TableDescription) -> Vec<String> {
+    let (fields_a, fields_b) = (schema_fields(a), schema_fields(b));
+    let mut differences: Vec<String> = vec![];
+    for (field, value_a) in &fields_a {
+        match fields_b.iter().find(|(f, _)| f == field) {
+            Some((_, value_b)) if value_a == value_b => (),
+            Some((_, value_b)) => {
+                differences.push(format!("~ {}: {} -> {}", field, value_a, value_b))
+            }
+            None => differences.push(format!("- {}: {}", field, value_a)),
+        }
+    }
+    for (field, value_b) in &fields_b {
+        if !fields_a.iter().any(|(f, _)| f == field) {
+            differences.push(format!("+ {}: {}", field, value_b));
+        }
+    }
+    differences
+}
+
+/// Flattens schema related fields of the table into (field, value) pairs to be compared by compare_table_schemas.
+/// Indexes are identified by their names, e.g. "gsi.by_email". Capacity and status are not a part of the schema.
+fn schema_fields(desc: &TableDescription) -> Vec<(String, String)> {
+    let t = build_print_table("", desc, &SupplementalSettings::default());
+    let mut fields: Vec<(String, String)> = vec![
+        (String::from("pk"), t.schema.pk),
+        (
+            String::from("sk"),
+            t.schema.sk.unwrap_or_else(|| String::from("~")),
+        ),
+        (String::from("mode"), format!("{:?}", t.mode)),
+        (
+            String::from("stream"),
+            t.stream.unwrap_or_else(|| String::from("~")),
+        ),
+    ];
+    for (kind, indexes) in [("gsi", t.gsi), ("lsi", t.lsi)] {
+        for idx in indexes.unwrap_or_default() {
+            fields.push((
+                format!("{}.{}", kind, idx.name),
+                format!(
+                    "{}, projection: {}",
+                    primary_keys_summary(&idx.schema),
+                    idx.projection
+                ),
+            ));
+        }
+    }
+    fields
+}
+
+fn primary_keys_summary(keys: &PrintPrimaryKeys) -> String {
+    match &keys.sk {
+        Some(sk) => format!("pk: {}, sk: {}", keys.pk, sk),
+        None => format!("pk: {}", keys.pk),
+    }
+}
+
+fn capacity_summary(capacity: &PrintCapacity) -> String {
+    match capacity {
+        PrintCapacity::Units(units) => format!("wcu: {}, rcu: {}", units.wcu, units.rcu),
+        PrintCapacity::Transitioning(s) => s.to_owned(),
+    }
+}
+
+fn build_print(
+    region: &str,
+    desc: &TableDescription,
+    settings: &SupplementalSettings,
+    only_keys: bool,
+) -> PrintTable {
+    if only_keys {
+        PrintTable::Keys(build_print_table_keys(desc))
+    } else {
+        PrintTable::Full(build_print_table(region, desc, settings))
+    }
+}
+
+/// Unlike build_print_table, this function doesn't touch fields that may be missing while the table is in transitional states
+/// (e.g. item count, size and capacity), so that `dy desc --only-keys` works for any table.
+fn build_print_table_keys(desc: &TableDescription) -> PrintTableKeys {
+    let attr_defs = desc.attribute_definitions.as_deref().unwrap_or_default();
+    let key_schema = desc.key_schema.as_deref().unwrap_or_default();
+    let index_keys = |indexes: Option<Vec<PrintSecondaryIndex>>| {
+        indexes.map(|xs| {
+            xs.into_iter()
+                .map(|idx| PrintIndexKeys {
+                    name: idx.name,
+                    schema: idx.schema,
+                })
+                .collect()
+        })
+    };
+
+    PrintTableKeys {
+        name: desc
+            .table_name
+            .to_owned()
+            .unwrap_or_else(|| String::from("~")),
+        schema: PrintPrimaryKeys {
+            pk: display_key("HASH", key_schema, attr_defs).unwrap_or_else(|| String::from("~")),
+            sk: display_key("RANGE", key_schema, attr_defs),
+        },
+        gsi: index_keys(extract_secondary_indexes(
+            &Mode::OnDemand, // capacity is not shown
+            attr_defs,
+            &desc.global_secondary_indexes,
+        )),
+        lsi: index_keys(extract_secondary_indexes(
+            &Mode::OnDemand,
+            attr_defs,
+            &desc.local_secondary_indexes,
+        )),
+    }
+}
+
+/// Builds the compact form of `dy desc --only-keys` from a table schema in the local cache, used by `dy desc --cache-only`.
+fn build_print_table_keys_from_schema(schema: &TableSchema) -> PrintTableKeys {
+    let index_keys = |gsi: bool| {
+        let xs: Vec<PrintIndexKeys> = schema
+            .indexes
+            .iter()
+            .flatten()
+            .filter(|idx| matches!(idx.kind, IndexType::Gsi) == gsi)
+            .map(|idx| PrintIndexKeys {
+                name: idx.name.to_owned(),
+                schema: PrintPrimaryKeys {
+                    pk: idx.pk.display(),
+                    sk: idx.sk.as_ref().map(|k| k.display()),
+                },
+            })
+            .collect();
+        if xs.is_empty() {
+            None
+        } else {
+            Some(xs)
+        }
+    };
+
+    PrintTableKeys {
+        name: schema.name.to_owned(),
+        schema: PrintPrimaryKeys {
+            pk: schema.pk.display(),
+            sk: schema.sk.as_ref().map(|k| k.display()),
+        },
+        gsi: index_keys(true),
+        lsi: index_keys(false),
+    }
+}
+
+/// Prints cached table schemas in the same form as `dy desc --only-keys`, as the cache holds only keys of tables and indexes.
+/// Multiple schemas are printed as one document, i.e. YAML multi-document stream or JSON array.
+pub fn print_cached_table_schemas(schemas: &[TableSchema], output: Option<&str>) {
+    let tables: Vec<PrintTableKeys> = schemas
+        .iter()
+        .map(build_print_table_keys_from_schema)
+        .collect();
+    match (output, tables.as_slice()) {
+        (Some("json"), [table]) => println!("{}", serde_json::to_string_pretty(table).unwrap()),
+        (Some("json"), _) => println!("{}", serde_json::to_string_pretty(&tables).unwrap()),
+        (_, [table]) => println!("{}", serde_yaml::to_string(table).unwrap()),
+        _ => {
+            for table in tables {
+                print!("---\n{}", serde_yaml::to_string(&table).unwrap());
+            }
+        }
+    }
+}
+
+fn build_print_table(
+    region: &str,
+    desc: &TableDescription,
+    settings: &SupplementalSettings,
+) -> PrintDescribeTable {
+    let attr_defs = desc.attribute_definitions.as_deref().unwrap_or_default();
+    let key_schema = desc.key_schema.as_deref().unwrap_or_default();
     let mode = extract_mode(&desc.billing_mode_summary);
 
-    let print_table: PrintDescribeTable = PrintDescribeTable {
-        name: String::from(desc.table_name.as_ref().unwrap()),
+    PrintDescribeTable {
+        name: desc
+            .table_name
+            .to_owned()
+            .unwrap_or_else(|| String::from("~")),
         region: String::from(region),
-        status: String::from(desc.table_status.as_ref().unwrap().as_str()),
+        arn: desc.table_arn.to_owned(),
+        status: desc
+            .table_status
+            .as_ref()
+            .map_or(String::from("UNKNOWN"), |s| String::from(s.as_str())),
         schema: PrintPrimaryKeys {
-            pk: key::typed_key("HASH", desc)
-                .expect("pk should exist")
-                .display(),
-            sk: key::typed_key("RANGE", desc).map(|k| k.display()),
+            pk: display_key("HASH", key_schema, attr_defs).unwrap_or_else(|| String::from("~")),
+            sk: display_key("RANGE", key_schema, attr_defs),
         },
 
         mode: mode.clone(),
         capacity: extract_capacity(&mode, &desc.provisioned_throughput),
+        table_class: desc
+            .table_class_summary
+            .as_ref()
+            .and_then(|s| s.table_class.as_ref())
+            .map(|c| String::from(c.as_str())),
 
         gsi: extract_secondary_indexes(&mode, attr_defs, &desc.global_secondary_indexes),
         lsi: extract_secondary_indexes(&mode, attr_defs, &desc.local_secondary_indexes),
         stream: extract_stream(&desc.latest_stream_arn, &desc.stream_specification),
+        replicas: extract_replicas(&desc.replicas),
+        ttl: settings.ttl.as_ref().map(ttl_summary),
+        pitr: settings.pitr.as_ref().map(pitr_summary),
+        contributor_insights: settings
+            .contributor_insights
+            .as_ref()
+            .map(|status| String::from(status.as_str())),
+        sse: desc
+            .sse_description
+            .is_some()
+            .then(|| sse_summary(&desc.sse_description)),
 
-        size_bytes: desc.table_size_bytes.unwrap(),
-        count: desc.item_count.unwrap(),
-        created_at: epoch_to_rfc3339(desc.creation_date_time.unwrap().as_secs_f64()),
+        size_bytes: desc.table_size_bytes.unwrap_or_default(),
+        size_human: humanize_size(desc.table_size_bytes.unwrap_or_default()),
+        count: desc.item_count.unwrap_or_default(),
+        created_at: desc
+            .creation_date_time
+            .map(|t| epoch_to_rfc3339(t.as_secs_f64())),
+    }
+}
+
+/// The table can't be shown properly without its name, the partition key and AttributeDefinitions (to know types of keys).
+/// Returns an error that names the missing field, so that `dy desc` can report it as a failure of the table rather than showing a broken schema.
+/// Other fields are shown as "~" when missing, hence the summary is built without panicking even for an incomplete description.
+pub fn check_table_description(desc: &TableDescription) -> Result<(), IncompleteDescriptionError> {
+    let has_pk = desc
+        .key_schema
+        .iter()
+        .flatten()
+        .any(|k| k.key_type == KeyType::Hash);
+    let missing = if desc.table_name.is_none() {
+        Some("TableName")
+    } else if !has_pk {
+        Some("KeySchema")
+    } else if desc.attribute_definitions.is_none() {
+        Some("AttributeDefinitions")
+    } else {
+        None
     };
-    println!("{}", serde_yaml::to_string(&print_table).unwrap());
+    match missing {
+        Some(field) => Err(IncompleteDescriptionError {
+            table_name: desc.table_name.to_owned().unwrap_or_default(),
+            field,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Displays the key of the schema as "<name> (<type>)" like key::Key::display. Unlike key::typed_key_for_schema, it doesn't panic
+/// when the key is not in AttributeDefinitions, e.g. AttributeDefinitions are missing in the response, and shows the type as "~" instead.
+fn display_key(
+    pk_or_sk: &str,
+    ks: &[KeySchemaElement],
+    attr_defs: &[AttributeDefinition],
+) -> Option<String> {
+    let target_key = ks.iter().find(|x| x.key_type == pk_or_sk.into())?;
+    if attr_defs
+        .iter()
+        .any(|at| at.attribute_name == target_key.attribute_name)
+    {
+        key::typed_key_for_schema(pk_or_sk, ks, attr_defs).map(|k| k.display())
+    } else {
+        Some(format!("{} (~)", target_key.attribute_name))
+    }
+}
+
+/// Generates CreateTableInput for a new table that has the same schema as the given table, used by `dy clone-schema`.
+/// Key schema, attribute definitions, indexes, capacity mode (and capacity units) and stream settings are copied.
+/// Unlike generate_essential_key_definitions, GSIs may use any attributes, which are already in the source's AttributeDefinitions.
+pub fn clone_schema_input(desc: &TableDescription, new_table_name: &str) -> CreateTableInput {
+    let mode = extract_mode(&desc.billing_mode_summary);
+    // Capacity units may be missing while the source table is switching its mode. Fallback to 5, same as `dy admin create table`.
+    let throughput = |pt: &Option<ProvisionedThroughputDescription>| match mode {
+        Mode::OnDemand => None,
+        Mode::Provisioned => Some(
+            ProvisionedThroughput::builder()
+                .read_capacity_units(
+                    pt.as_ref()
+                        .and_then(|pt| pt.read_capacity_units)
+                        .unwrap_or(5),
+                )
+                .write_capacity_units(
+                    pt.as_ref()
+                        .and_then(|pt| pt.write_capacity_units)
+                        .unwrap_or(5),
+                )
+                .build()
+                .unwrap(),
+        ),
+    };
+
+    let gsis: Option<Vec<GlobalSecondaryIndex>> =
+        desc.global_secondary_indexes.as_ref().map(|gsis| {
+            gsis.iter()
+                .map(|gsi| {
+                    GlobalSecondaryIndex::builder()
+                        .set_index_name(gsi.index_name.clone())
+                        .set_key_schema(gsi.key_schema.clone())
+                        .set_projection(gsi.projection.clone())
+                        .set_provisioned_throughput(throughput(&gsi.provisioned_throughput))
+                        .build()
+                        .expect("GSI of the source table should have index name and key schema")
+                })
+                .collect()
+        });
+    let lsis: Option<Vec<LocalSecondaryIndex>> = desc.local_secondary_indexes.as_ref().map(|lsis| {
+        lsis.iter()
+            .map(|lsi| {
+                LocalSecondaryIndex::builder()
+                    .set_index_name(lsi.index_name.clone())
+                    .set_key_schema(lsi.key_schema.clone())
+                    .set_projection(lsi.projection.clone())
+                    .build()
+                    .expect("LSI of the source table should have index name, key schema and projection")
+            })
+            .collect()
+    });
+    // StreamSpecification is returned only when a stream has been enabled. Copy it only if the stream is currently enabled.
+    let stream_specification = desc
+        .stream_specification
+        .clone()
+        .filter(|spec| spec.stream_enabled);
+
+    CreateTableInput::builder()
+        .table_name(new_table_name)
+        .billing_mode(mode.clone().into())
+        .set_provisioned_throughput(throughput(&desc.provisioned_throughput))
+        .set_key_schema(desc.key_schema.clone())
+        .set_attribute_definitions(desc.attribute_definitions.clone())
+        .set_global_secondary_indexes(gsis)
+        .set_local_secondary_indexes(lsis)
+        .set_stream_specification(stream_specification)
+        .build()
+        .expect("CreateTableInput should be valid")
+}
+
+/// Returns warnings for key names that are reserved words of DynamoDB. Such keys are still usable, hence they're not rejected
+/// by generate_essential_key_definitions, and the CLI prints these warnings instead.
+pub fn reserved_key_name_warnings(key_schema: &[KeySchemaElement]) -> Vec<String> {
+    key_schema
+        .iter()
+        .filter(|k| key::is_reserved_word(&k.attribute_name))
+        .map(|k| format!(
+            "WARN: '{}' is a reserved word of DynamoDB. It can be used as a key, but expressions need to refer to it via an expression attribute name (dynein does it for you).",
+            k.attribute_name
+        ))
+        .collect()
 }
 
 /// Using Vec of String which is passed via command line,
 /// generate KeySchemaElement(s) & AttributeDefinition(s), that are essential information to create DynamoDB tables or GSIs.
+/// Key definitions are validated locally (e.g. type letters should be one of S, N, or B), so that typos are caught before any API call.
+/// Key roles can be given explicitly as the third field (e.g. `date,N,RANGE id,S,HASH`), otherwise they're decided by position.
 pub fn generate_essential_key_definitions(
     given_keys: &[String],
-) -> (Vec<KeySchemaElement>, Vec<AttributeDefinition>) {
+) -> Result<(Vec<KeySchemaElement>, Vec<AttributeDefinition>), String> {
     let mut key_schema: Vec<KeySchemaElement> = vec![];
     let mut attribute_definitions: Vec<AttributeDefinition> = vec![];
+    let explicit_roles = given_keys.iter().any(|k| k.split(',').count() == 3);
     for (key_id, key_str) in given_keys.iter().enumerate() {
         let key_and_type = key_str.split(',').collect::<Vec<&str>>();
-        if key_and_type.len() >= 3 {
-            error!(
-                "Invalid format for --keys option: '{}'. Valid format is '--keys myPk,S mySk,N'",
+        if key_and_type.len() > 3 {
+            return Err(format!(
+                "Invalid format for --keys option: '{}'. Valid format is '--keys myPk,S mySk,N' or '--keys myPk,S,HASH mySk,N,RANGE'",
                 &key_str
-            );
-            std::process::exit(1);
+            ));
         }
+        // Mixing explicit and positional roles is ambiguous, so explicit roles should be given to all keys or none of them.
+        if explicit_roles && key_and_type.len() != 3 {
+            return Err(format!(
+                "Invalid format for --keys option: '{}'. When a key role (HASH/RANGE) is given to any key, all keys should have it, e.g. '--keys myPk,S,HASH mySk,N,RANGE'",
+                &key_str
+            ));
+        }
+        if key_and_type[0].is_empty() {
+            return Err(format!(
+                "Invalid format for --keys option: '{}'. Attribute name of the key should not be empty.",
+                &key_str
+            ));
+        }
+        key::validate_attribute_name(key_and_type[0])?;
+        // If data type of key is omitted, dynein assumes it as String (S).
+        let attribute_type = match key_and_type.get(1).map(|t| t.to_uppercase()).as_deref() {
+            None | Some("S") => ScalarAttributeType::S,
+            Some("N") => ScalarAttributeType::N,
+            Some("B") => ScalarAttributeType::B,
+            Some(_) => {
+                return Err(format!(
+                    "invalid key type '{}', expected S, N, or B",
+                    key_and_type[1]
+                ))
+            }
+        };
 
-        // assumes first given key is Partition key, and second given key is Sort key (if any).
+        // unless roles are given explicitly, assumes first given key is Partition key, and second given key is Sort key (if any).
+        let key_type = match key_and_type.get(2).map(|r| r.to_uppercase()).as_deref() {
+            Some("HASH") => KeyType::Hash,
+            Some("RANGE") => KeyType::Range,
+            Some(_) => {
+                return Err(format!(
+                    "invalid key role '{}', expected HASH or RANGE",
+                    key_and_type[2]
+                ))
+            }
+            None if key_id == 0 => KeyType::Hash,
+            None => KeyType::Range,
+        };
         key_schema.push(
             KeySchemaElement::builder()
                 .attribute_name(String::from(key_and_type[0]))
-                .key_type(if key_id == 0 {
-                    KeyType::Hash
-                } else {
-                    KeyType::Range
-                })
+                .key_type(key_type)
                 .build()
                 .unwrap(),
         );
 
-        // If data type of key is omitted, dynein assumes it as String (S).
         attribute_definitions.push(
             AttributeDefinition::builder()
                 .attribute_name(String::from(key_and_type[0]))
-                .attribute_type(if key_and_type.len() == 2 {
-                    ScalarAttributeType::from(key_and_type[1].to_uppercase().as_ref())
-                } else {
-                    ScalarAttributeType::S
-                })
+                .attribute_type(attribute_type)
                 .build()
                 .unwrap(),
         )
     }
-    (key_schema, attribute_definitions)
+
+    let count_of = |t: KeyType| key_schema.iter().filter(|k| k.key_type == t).count();
+    if count_of(KeyType::Hash) != 1 || count_of(KeyType::Range) > 1 {
+        return Err(format!(
+            "Invalid keys: '{}'. Exactly one HASH key and at most one RANGE key should be given.",
+            given_keys.join(" ")
+        ));
+    }
+    // DynamoDB requires the partition key to be the first element of KeySchema.
+    key_schema.sort_by_key(|k| k.key_type != KeyType::Hash);
+    Ok((key_schema, attribute_definitions))
+}
+
+/// Parses tags given via command line in the form of "key=value", e.g. `--tag env=prod`.
+/// The value can be empty (e.g. "key="), but the key cannot. Only the first '=' separates the key and the value.
+pub fn parse_tags(given_tags: &[String]) -> Result<Vec<Tag>, String> {
+    given_tags
+        .iter()
+        .map(|tag_str| match tag_str.split_once('=') {
+            Some((key, value)) if !key.is_empty() => {
+                Ok(Tag::builder().key(key).value(value).build().unwrap())
+            }
+            _ => Err(format!(
+                "Invalid tag: '{}'. Tags should be given in the form of 'key=value', e.g. 'env=prod'.",
+                tag_str
+            )),
+        })
+        .collect()
+}
+
+/// Parses a table ARN (e.g. copied from the console) into its region and table name,
+/// e.g. "arn:aws:dynamodb:us-east-1:123456789012:table/app_users" into ("us-east-1", "app_users").
+/// ARNs of other resources, including indexes and streams of a table, are rejected.
+pub fn parse_table_arn(arn: &str) -> Result<(String, String), String> {
+    let invalid = || {
+        format!(
+            "Invalid table ARN: '{}'. It should be in the form of 'arn:aws:dynamodb:<region>:<account-id>:table/<table-name>'.",
+            arn
+        )
+    };
+    match arn.splitn(6, ':').collect::<Vec<&str>>().as_slice() {
+        ["arn", _partition, "dynamodb", region, _account, resource] if !region.is_empty() => {
+            match resource.strip_prefix("table/") {
+                Some(name) if !name.is_empty() && !name.contains('/') => {
+                    Ok((region.to_string(), name.to_string()))
+                }
+                _ => Err(invalid()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses a table class given by users, i.e. "standard" or "standard_ia" (case-insensitive).
+/// The full name "standard_infrequent_access" used in DynamoDB API is accepted as well.
+pub fn parse_table_class(s: &str) -> Result<TableClass, String> {
+    match s.to_lowercase().as_str() {
+        "standard" => Ok(TableClass::Standard),
+        "standard_ia" | "standard_infrequent_access" => Ok(TableClass::StandardInfrequentAccess),
+        _ => Err(format!(
+            "Unknown table class: '{}'. Available values: [standard, standard_ia].",
+            s
+        )),
+    }
 }
 
 /// Map "BilingModeSummary" field in table description returned from DynamoDB API,
@@ -167,13 +1311,12 @@ pub fn extract_mode(bs: &Option<BillingModeSummary>) -> Mode {
     match bs {
         // if BillingModeSummary field doesn't exist, the table is Provisioned Mode.
         None => Mode::Provisioned,
-        Some(x) => {
-            if x.billing_mode == Some(BillingMode::PayPerRequest) {
-                Mode::OnDemand
-            } else {
-                Mode::Provisioned
-            }
-        }
+        Some(x) => match &x.billing_mode {
+            Some(BillingMode::PayPerRequest) => Mode::OnDemand,
+            // During a mode switch billing_mode may lag behind or be missing. Treat it as Provisioned here and
+            // let extract_capacity detect the half-populated state, rather than guessing capacity.
+            _ => Mode::Provisioned,
+        },
     }
 }
 
@@ -186,16 +1329,20 @@ fn extract_secondary_indexes<T: IndexDesc>(
     if let Some(indexes) = option_indexes {
         let mut xs = Vec::<PrintSecondaryIndex>::new();
         for idx in indexes {
-            let ks = &idx.retrieve_key_schema().as_ref().unwrap();
+            let ks = idx.retrieve_key_schema().as_deref().unwrap_or_default();
             let idx = PrintSecondaryIndex {
-                name: String::from(idx.retrieve_index_name().as_ref().unwrap()),
+                name: idx
+                    .retrieve_index_name()
+                    .to_owned()
+                    .unwrap_or_else(|| String::from("~")),
                 schema: PrintPrimaryKeys {
-                    pk: key::typed_key_for_schema("HASH", ks, attr_defs)
-                        .expect("pk should exist")
-                        .display(),
-                    sk: key::typed_key_for_schema("RANGE", ks, attr_defs).map(|k| k.display()),
+                    pk: display_key("HASH", ks, attr_defs).unwrap_or_else(|| String::from("~")),
+                    sk: display_key("RANGE", ks, attr_defs),
                 },
                 capacity: idx.extract_index_capacity(mode),
+                projection: projection_summary(idx.retrieve_projection()),
+                status: idx.retrieve_index_status(),
+                backfilling: idx.retrieve_backfilling(),
             };
             xs.push(idx);
         }
@@ -221,30 +1368,215 @@ fn extract_stream(arn: &Option<String>, spec: &Option<StreamSpecification>) -> O
     }
 }
 
+/// Lists replicas of a global table with their statuses, e.g. "us-west-2 (ACTIVE)".
+/// Returns None for tables without replicas, i.e. tables which are not global tables (version 2019.11.21).
+fn extract_replicas(replicas: &Option<Vec<ReplicaDescription>>) -> Option<Vec<String>> {
+    let replicas: Vec<String> = replicas
+        .iter()
+        .flatten()
+        .map(|r| {
+            format!(
+                "{} ({})",
+                r.region_name.as_deref().unwrap_or("UNKNOWN"),
+                r.replica_status.as_ref().map_or("UNKNOWN", |s| s.as_str())
+            )
+        })
+        .collect();
+    if replicas.is_empty() {
+        None
+    } else {
+        Some(replicas)
+    }
+}
+
+/// Colorizes the status at the beginning of the text, e.g. "CREATING (backfilling)":
+/// green for ACTIVE, yellow for CREATING/UPDATING and red for DELETING. Other texts are returned as they are.
+/// Callers should check app::color_enabled, as colors are always applied here.
+pub fn colorize_status(text: &str) -> String {
+    let (status, rest) = text.split_at(text.find(' ').unwrap_or(text.len()));
+    let styled = match status {
+        "ACTIVE" => style(status).green(),
+        "CREATING" | "UPDATING" => style(status).yellow(),
+        "DELETING" => style(status).red(),
+        _ => return text.to_owned(),
+    };
+    format!("{}{}", styled.force_styling(true), rest)
+}
+
+/// Colorizes the status in a row of table_description_rows, i.e. the value of "status" row, or the last part of an index row.
+pub fn colorize_status_in_row(key: &str, value: &str) -> String {
+    if key == "status" {
+        return colorize_status(value);
+    }
+    // Rows of indexes are indented, e.g. "  by_pk" -> "pk: pk (S), projection: ALL, ACTIVE".
+    match value.rsplit_once(", ") {
+        Some((head, last)) if key.starts_with("  ") => {
+            format!("{}, {}", head, colorize_status(last))
+        }
+        _ => value.to_owned(),
+    }
+}
+
+/// Summarizes SSEDescription into a single line, e.g. "ENABLED (KMS, arn:aws:kms:...)".
+/// DynamoDB encrypts all data at rest, and SSEDescription is missing when the data is encrypted with an AWS owned key.
+pub fn sse_summary(sse: &Option<SseDescription>) -> String {
+    match sse {
+        None => String::from("DEFAULT (AWS owned key)"),
+        Some(sse) => {
+            let status = sse
+                .status
+                .as_ref()
+                .map_or("UNKNOWN", |status| status.as_str());
+            match (&sse.sse_type, &sse.kms_master_key_arn) {
+                (Some(sse_type), Some(arn)) => format!("{} ({}, {})", status, sse_type, arn),
+                (Some(sse_type), None) => format!("{} ({})", status, sse_type),
+                (None, _) => String::from(status),
+            }
+        }
+    }
+}
+
+/// Summarizes Projection of a secondary index into a single line, e.g. "ALL", "KEYS_ONLY" or "INCLUDE (attr1, attr2)".
+/// The same projection is used when the index is re-created, e.g. by `dy clone-schema`.
+pub fn projection_summary(projection: &Option<Projection>) -> String {
+    let projection = match projection {
+        Some(projection) => projection,
+        None => return String::from("UNKNOWN"),
+    };
+    let projection_type = projection
+        .projection_type
+        .as_ref()
+        .map_or("UNKNOWN", |t| t.as_str());
+    match &projection.non_key_attributes {
+        Some(attrs) if !attrs.is_empty() => format!("{} ({})", projection_type, attrs.join(", ")),
+        _ => String::from(projection_type),
+    }
+}
+
+/// Summarizes TimeToLiveDescription into a single line, e.g. "ENABLED (expiredAt)" or "DISABLED".
+pub fn ttl_summary(ttl: &TimeToLiveDescription) -> String {
+    let status = ttl
+        .time_to_live_status
+        .as_ref()
+        .map_or("UNKNOWN", |status| status.as_str());
+    match &ttl.attribute_name {
+        Some(attr) => format!("{} ({})", status, attr),
+        None => String::from(status),
+    }
+}
+
+/// Summarizes ContinuousBackupsDescription into a single line focusing on PITR (point-in-time recovery),
+/// e.g. "ENABLED (restorable: 2024-01-01T00:00:00+00:00 - 2024-01-31T00:00:00+00:00)" or "DISABLED".
+pub fn pitr_summary(backups: &ContinuousBackupsDescription) -> String {
+    match &backups.point_in_time_recovery_description {
+        None => String::from("DISABLED"),
+        Some(pitr) => {
+            let status = pitr
+                .point_in_time_recovery_status
+                .as_ref()
+                .map_or("UNKNOWN", |status| status.as_str());
+            match (
+                pitr.earliest_restorable_date_time,
+                pitr.latest_restorable_date_time,
+            ) {
+                (Some(earliest), Some(latest)) => format!(
+                    "{} (restorable: {} - {})",
+                    status,
+                    epoch_to_rfc3339(earliest.as_secs_f64()),
+                    epoch_to_rfc3339(latest.as_secs_f64())
+                ),
+                _ => String::from(status),
+            }
+        }
+    }
+}
+
+/// Returns true when the data is encrypted with a KMS key (i.e. AWS managed key or customer managed key), rather than an AWS owned key.
+pub fn is_kms_encrypted(sse: &Option<SseDescription>) -> bool {
+    sse.as_ref()
+        .map_or(false, |sse| sse.status == Some(SseStatus::Enabled))
+}
+
+/// Formats an epoch in seconds, keeping its fractional part, e.g. "2023-11-14T22:13:20.123+00:00".
+/// The fractional part is rounded to microseconds, as f64 epochs of today cannot represent nanoseconds exactly.
+/// Epochs out of the representable range (or NaN) are rendered as INVALID_TIMESTAMP instead of panicking.
 pub fn epoch_to_rfc3339(epoch: f64) -> String {
-    let utc_datetime = DateTime::from_timestamp(epoch as i64, 0).unwrap();
-    utc_datetime.to_rfc3339()
+    if !epoch.is_finite() {
+        return format!("{} ({})", INVALID_TIMESTAMP, epoch);
+    }
+    let mut secs = epoch.floor();
+    let mut micros = ((epoch - secs) * 1_000_000.0).round() as u32;
+    if micros >= 1_000_000 {
+        secs += 1.0;
+        micros -= 1_000_000;
+    }
+    // i64::MAX as f64 is rounded up to 2^63, thus `<` keeps the cast below from saturating.
+    if secs < i64::MIN as f64 || i64::MAX as f64 <= secs {
+        return format!("{} ({})", INVALID_TIMESTAMP, epoch);
+    }
+    match DateTime::from_timestamp(secs as i64, micros * 1_000) {
+        Some(utc_datetime) => utc_datetime.to_rfc3339(),
+        None => format!("{} ({})", INVALID_TIMESTAMP, epoch),
+    }
+}
+
+const INVALID_TIMESTAMP: &str = "invalid timestamp";
+
+/// Renders a byte count in a human readable form with binary units, e.g. "512 B", "1.2 KB", "3.4 GB".
+pub fn humanize_size(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["KB", "MB", "GB", "TB", "PB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
 }
 
 fn extract_capacity(
     mode: &Mode,
     cap_desc: &Option<ProvisionedThroughputDescription>,
-) -> Option<PrintCapacityUnits> {
+) -> Option<PrintCapacity> {
     if mode == &Mode::OnDemand {
-        None
-    } else {
-        let desc = cap_desc.as_ref().unwrap();
-        Some(PrintCapacityUnits {
-            wcu: desc.write_capacity_units.unwrap(),
-            rcu: desc.read_capacity_units.unwrap(),
-        })
+        return None;
     }
+    // Provisioned mode without (complete) provisioned throughput means the table is in the middle of a mode switch.
+    let units = cap_desc.as_ref().and_then(|desc| {
+        match (desc.write_capacity_units, desc.read_capacity_units) {
+            (Some(wcu), Some(rcu)) => Some(PrintCapacityUnits {
+                wcu,
+                rcu,
+                last_increased_at: throughput_changed_at(&desc.last_increase_date_time),
+                last_decreased_at: throughput_changed_at(&desc.last_decrease_date_time),
+                number_of_decreases_today: desc.number_of_decreases_today,
+            }),
+            _ => None,
+        }
+    });
+    Some(match units {
+        Some(units) => PrintCapacity::Units(units),
+        None => PrintCapacity::Transitioning(String::from(CAPACITY_TRANSITIONING)),
+    })
+}
+
+/// DynamoDB Local reports the epoch 0 for throughput that has never been changed, which is regarded as no change.
+fn throughput_changed_at(t: &Option<aws_sdk_dynamodb::primitives::DateTime>) -> Option<String> {
+    t.as_ref()
+        .filter(|t| t.as_secs_f64() > 0.0)
+        .map(|t| epoch_to_rfc3339(t.as_secs_f64()))
 }
 
 trait IndexDesc {
     fn retrieve_index_name(&self) -> &Option<String>;
     fn retrieve_key_schema(&self) -> &Option<Vec<KeySchemaElement>>;
-    fn extract_index_capacity(&self, m: &Mode) -> Option<PrintCapacityUnits>;
+    fn retrieve_projection(&self) -> &Option<Projection>;
+    fn extract_index_capacity(&self, m: &Mode) -> Option<PrintCapacity>;
+    fn retrieve_index_status(&self) -> Option<String>;
+    fn retrieve_backfilling(&self) -> Option<bool>;
 }
 
 impl IndexDesc for GlobalSecondaryIndexDescription {
@@ -254,13 +1586,22 @@ impl IndexDesc for GlobalSecondaryIndexDescription {
     fn retrieve_key_schema(&self) -> &Option<Vec<KeySchemaElement>> {
         &self.key_schema
     }
-    fn extract_index_capacity(&self, m: &Mode) -> Option<PrintCapacityUnits> {
+    fn retrieve_projection(&self) -> &Option<Projection> {
+        &self.projection
+    }
+    fn extract_index_capacity(&self, m: &Mode) -> Option<PrintCapacity> {
         if m == &Mode::OnDemand {
             None
         } else {
             extract_capacity(m, &self.provisioned_throughput)
         }
     }
+    fn retrieve_index_status(&self) -> Option<String> {
+        self.index_status.as_ref().map(|s| s.as_str().to_owned())
+    }
+    fn retrieve_backfilling(&self) -> Option<bool> {
+        self.backfilling
+    }
 }
 
 impl IndexDesc for LocalSecondaryIndexDescription {
@@ -270,7 +1611,933 @@ impl IndexDesc for LocalSecondaryIndexDescription {
     fn retrieve_key_schema(&self) -> &Option<Vec<KeySchemaElement>> {
         &self.key_schema
     }
-    fn extract_index_capacity(&self, _: &Mode) -> Option<PrintCapacityUnits> {
+    fn retrieve_projection(&self) -> &Option<Projection> {
+        &self.projection
+    }
+    fn extract_index_capacity(&self, _: &Mode) -> Option<PrintCapacity> {
         None // Unlike GSI, LSI doesn't have it's own capacity.
     }
+    fn retrieve_index_status(&self) -> Option<String> {
+        None // LSI is created together with the table, so it doesn't have its own status.
+    }
+    fn retrieve_backfilling(&self) -> Option<bool> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_dynamodb::types::{
+        ContinuousBackupsStatus, IndexStatus, PointInTimeRecoveryDescription,
+        PointInTimeRecoveryStatus, ProjectionType, ReplicaStatus, RestoreSummary, SseType,
+        TableStatus, TimeToLiveStatus,
+    };
+
+    #[test]
+    fn test_parse_table_class() {
+        assert_eq!(parse_table_class("standard"), Ok(TableClass::Standard));
+        assert_eq!(
+            parse_table_class("STANDARD_IA"),
+            Ok(TableClass::StandardInfrequentAccess)
+        );
+        assert_eq!(
+            parse_table_class("standard_infrequent_access"),
+            Ok(TableClass::StandardInfrequentAccess)
+        );
+        assert!(parse_table_class("glacier").is_err());
+    }
+
+    #[test]
+    fn test_extract_mode() {
+        assert_eq!(extract_mode(&None), Mode::Provisioned);
+        let on_demand = BillingModeSummary::builder()
+            .billing_mode(BillingMode::PayPerRequest)
+            .build();
+        assert_eq!(extract_mode(&Some(on_demand)), Mode::OnDemand);
+        let lagging = BillingModeSummary::builder().build();
+        assert_eq!(extract_mode(&Some(lagging)), Mode::Provisioned);
+    }
+
+    #[test]
+    fn test_build_print_table_keys_from_schema() {
+        use crate::app::IndexSchema;
+        let key = |name: &str, kind: key::KeyType| key::Key {
+            name: name.to_owned(),
+            kind,
+        };
+        let schema = TableSchema {
+            region: String::from("local"),
+            name: String::from("tbl"),
+            pk: key("pk", key::KeyType::S),
+            sk: None,
+            indexes: Some(vec![IndexSchema {
+                name: String::from("by_n"),
+                kind: IndexType::Gsi,
+                pk: key("n", key::KeyType::N),
+                sk: Some(key("b", key::KeyType::B)),
+            }]),
+            mode: Mode::OnDemand,
+            cached_at: None,
+        };
+
+        let keys = build_print_table_keys_from_schema(&schema);
+        assert_eq!(keys.name, "tbl");
+        assert_eq!(keys.schema.pk, "pk (S)");
+        assert_eq!(keys.schema.sk, None);
+        let gsi = keys.gsi.expect("gsi should exist");
+        assert_eq!(gsi.len(), 1);
+        assert_eq!(gsi[0].name, "by_n");
+        assert_eq!(gsi[0].schema.pk, "n (N)");
+        assert_eq!(gsi[0].schema.sk.as_deref(), Some("b (B)"));
+        assert!(keys.lsi.is_none());
+    }
+
+    #[test]
+    fn test_humanize_size() {
+        assert_eq!(humanize_size(0), "0 B");
+        assert_eq!(humanize_size(1023), "1023 B");
+        assert_eq!(humanize_size(1024), "1.0 KB");
+        assert_eq!(humanize_size(1234567), "1.2 MB");
+        assert_eq!(humanize_size(5 * 1024 * 1024 * 1024), "5.0 GB");
+    }
+
+    #[test]
+    fn test_epoch_to_rfc3339() {
+        assert_eq!(epoch_to_rfc3339(0.0), "1970-01-01T00:00:00+00:00");
+        assert_eq!(
+            epoch_to_rfc3339(1_700_000_000.0),
+            "2023-11-14T22:13:20+00:00"
+        );
+        assert_eq!(
+            epoch_to_rfc3339(1_700_000_000.123),
+            "2023-11-14T22:13:20.123+00:00"
+        );
+        assert_eq!(epoch_to_rfc3339(-0.5), "1969-12-31T23:59:59.500+00:00");
+        assert_eq!(epoch_to_rfc3339(0.9999999), "1970-01-01T00:00:01+00:00");
+        assert_eq!(
+            epoch_to_rfc3339(1e20),
+            "invalid timestamp (100000000000000000000)"
+        );
+        assert_eq!(epoch_to_rfc3339(f64::NAN), "invalid timestamp (NaN)");
+        assert_eq!(epoch_to_rfc3339(f64::INFINITY), "invalid timestamp (inf)");
+    }
+
+    #[test]
+    fn test_extract_capacity() {
+        assert_eq!(extract_capacity(&Mode::OnDemand, &None), None);
+
+        let full = ProvisionedThroughputDescription::builder()
+            .write_capacity_units(5)
+            .read_capacity_units(10)
+            .build();
+        assert_eq!(
+            extract_capacity(&Mode::Provisioned, &Some(full)),
+            Some(PrintCapacity::Units(PrintCapacityUnits {
+                wcu: 5,
+                rcu: 10,
+                last_increased_at: None,
+                last_decreased_at: None,
+                number_of_decreases_today: None,
+            }))
+        );
+
+        let changed = ProvisionedThroughputDescription::builder()
+            .write_capacity_units(5)
+            .read_capacity_units(10)
+            .last_increase_date_time(aws_sdk_dynamodb::primitives::DateTime::from_secs(
+                1_700_000_000,
+            ))
+            .last_decrease_date_time(aws_sdk_dynamodb::primitives::DateTime::from_secs(0))
+            .number_of_decreases_today(2)
+            .build();
+        assert_eq!(
+            extract_capacity(&Mode::Provisioned, &Some(changed)),
+            Some(PrintCapacity::Units(PrintCapacityUnits {
+                wcu: 5,
+                rcu: 10,
+                last_increased_at: Some(String::from("2023-11-14T22:13:20+00:00")),
+                last_decreased_at: None,
+                number_of_decreases_today: Some(2),
+            }))
+        );
+
+        let transitioning = Some(PrintCapacity::Transitioning(String::from("transitioning")));
+        assert_eq!(extract_capacity(&Mode::Provisioned, &None), transitioning);
+        let half = ProvisionedThroughputDescription::builder()
+            .write_capacity_units(5)
+            .build();
+        assert_eq!(
+            extract_capacity(&Mode::Provisioned, &Some(half)),
+            transitioning
+        );
+    }
+
+    #[test]
+    fn test_build_print_table_in_transition() {
+        // Billing mode says provisioned, but provisioned throughput is not yet populated.
+        let desc = TableDescription::builder()
+            .table_name("transitioning_table")
+            .table_status(TableStatus::Updating)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .billing_mode_summary(
+                BillingModeSummary::builder()
+                    .billing_mode(BillingMode::Provisioned)
+                    .build(),
+            )
+            .table_size_bytes(0)
+            .item_count(0)
+            .creation_date_time(aws_sdk_dynamodb::primitives::DateTime::from_secs(0))
+            .build();
+
+        let yaml = serde_yaml::to_string(&build_print_table(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+        ))
+        .unwrap();
+        assert!(yaml.contains("mode: Provisioned"));
+        assert!(yaml.contains("capacity: transitioning"));
+        assert!(yaml.contains("status: UPDATING"));
+
+        let json = serde_json::to_value(build_print_table(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+        ))
+        .unwrap();
+        assert_eq!(json["mode"], "Provisioned");
+        assert_eq!(json["capacity"], "transitioning");
+        assert_eq!(json["status"], "UPDATING");
+        // TTL, PITR and Contributor Insights are omitted unless they're retrieved separately, and SSE is omitted for the default encryption.
+        assert!(json.get("ttl").is_none());
+        assert!(json.get("pitr").is_none());
+        assert!(json.get("contributor_insights").is_none());
+        assert!(json.get("sse").is_none());
+
+        let settings = SupplementalSettings {
+            contributor_insights: Some(ContributorInsightsStatus::Enabled),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(build_print_table("us-east-1", &desc, &settings)).unwrap();
+        assert_eq!(json["contributor_insights"], "ENABLED");
+    }
+
+    #[test]
+    fn test_colorize_status() {
+        assert_eq!(colorize_status("ACTIVE"), "\u{1b}[32mACTIVE\u{1b}[0m");
+        assert_eq!(
+            colorize_status("CREATING (backfilling)"),
+            "\u{1b}[33mCREATING\u{1b}[0m (backfilling)"
+        );
+        assert_eq!(colorize_status("DELETING"), "\u{1b}[31mDELETING\u{1b}[0m");
+        assert_eq!(colorize_status("ARCHIVED"), "ARCHIVED");
+
+        assert_eq!(
+            colorize_status_in_row("status", "UPDATING"),
+            "\u{1b}[33mUPDATING\u{1b}[0m"
+        );
+        assert_eq!(
+            colorize_status_in_row("  by_pk", "pk: pk (S), projection: ALL, ACTIVE"),
+            "pk: pk (S), projection: ALL, \u{1b}[32mACTIVE\u{1b}[0m"
+        );
+        // Only status is colorized even if other values look like a status, e.g. a table named ACTIVE.
+        assert_eq!(colorize_status_in_row("name", "ACTIVE"), "ACTIVE");
+        assert_eq!(
+            colorize_status_in_row("  by_date", "pk: pk (S), sk: date (S), projection: ALL"),
+            "pk: pk (S), sk: date (S), projection: ALL"
+        );
+    }
+
+    #[test]
+    fn test_sse_summary() {
+        assert_eq!(sse_summary(&None), "DEFAULT (AWS owned key)");
+
+        let kms = SseDescription::builder()
+            .status(SseStatus::Enabled)
+            .sse_type(SseType::Kms)
+            .kms_master_key_arn("arn:aws:kms:us-east-1:123456789012:key/dummy")
+            .build();
+        assert_eq!(
+            sse_summary(&Some(kms)),
+            "ENABLED (KMS, arn:aws:kms:us-east-1:123456789012:key/dummy)"
+        );
+    }
+
+    #[test]
+    fn test_generate_essential_key_definitions() {
+        let keys = |xs: &[&str]| xs.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+
+        let (key_schema, attribute_definitions) =
+            generate_essential_key_definitions(&keys(&["pk", "sk,n"])).unwrap();
+        assert_eq!(key_schema[0].key_type, KeyType::Hash);
+        assert_eq!(key_schema[1].key_type, KeyType::Range);
+        assert_eq!(
+            attribute_definitions[0].attribute_type,
+            ScalarAttributeType::S
+        );
+        assert_eq!(
+            attribute_definitions[1].attribute_type,
+            ScalarAttributeType::N
+        );
+
+        assert_eq!(
+            generate_essential_key_definitions(&keys(&["id,X"])).unwrap_err(),
+            "invalid key type 'X', expected S, N, or B"
+        );
+        assert!(generate_essential_key_definitions(&keys(&[",S"])).is_err());
+        assert!(generate_essential_key_definitions(&keys(&["id,S,N"])).is_err());
+        assert!(generate_essential_key_definitions(&keys(&["id,S,HASH,X"])).is_err());
+        assert!(generate_essential_key_definitions(&keys(&[&"a".repeat(256)])).is_err());
+        assert!(generate_essential_key_definitions(&keys(&["my\tkey,S"])).is_err());
+        // Reserved words are accepted, and warnings are returned separately.
+        let (key_schema, _) =
+            generate_essential_key_definitions(&keys(&["name,S", "user_id,N"])).unwrap();
+        assert_eq!(
+            reserved_key_name_warnings(&key_schema),
+            vec!["WARN: 'name' is a reserved word of DynamoDB. It can be used as a key, but expressions need to refer to it via an expression attribute name (dynein does it for you)."]
+        );
+    }
+
+    #[test]
+    fn test_generate_essential_key_definitions_with_roles() {
+        let keys = |xs: &[&str]| xs.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+
+        // The partition key comes first in KeySchema regardless of the given order.
+        let (key_schema, attribute_definitions) =
+            generate_essential_key_definitions(&keys(&["date,N,range", "id,S,HASH"])).unwrap();
+        assert_eq!(key_schema[0].attribute_name, "id");
+        assert_eq!(key_schema[0].key_type, KeyType::Hash);
+        assert_eq!(key_schema[1].attribute_name, "date");
+        assert_eq!(key_schema[1].key_type, KeyType::Range);
+        assert_eq!(attribute_definitions.len(), 2);
+
+        let (key_schema, _) = generate_essential_key_definitions(&keys(&["id,S,HASH"])).unwrap();
+        assert_eq!(key_schema[0].key_type, KeyType::Hash);
+
+        assert_eq!(
+            generate_essential_key_definitions(&keys(&["id,S,PRIMARY"])).unwrap_err(),
+            "invalid key role 'PRIMARY', expected HASH or RANGE"
+        );
+        assert_eq!(
+            generate_essential_key_definitions(&keys(&["id,S,HASH", "date,N,HASH"])).unwrap_err(),
+            "Invalid keys: 'id,S,HASH date,N,HASH'. Exactly one HASH key and at most one RANGE key should be given."
+        );
+        assert!(generate_essential_key_definitions(&keys(&["id,S,RANGE"])).is_err());
+        // Explicit and positional roles cannot be mixed.
+        assert!(generate_essential_key_definitions(&keys(&["id,S", "date,N,RANGE"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        let tags = parse_tags(&[
+            String::from("env=prod"),
+            String::from("expr=a=b"),
+            String::from("empty="),
+        ])
+        .unwrap();
+        assert_eq!(
+            tags.iter()
+                .map(|t| (t.key.as_str(), t.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("env", "prod"), ("expr", "a=b"), ("empty", "")]
+        );
+
+        assert!(parse_tags(&[String::from("env")]).is_err());
+        assert!(parse_tags(&[String::from("=prod")]).is_err());
+        assert!(parse_tags(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pitr_summary() {
+        let disabled = ContinuousBackupsDescription::builder()
+            .continuous_backups_status(ContinuousBackupsStatus::Enabled)
+            .point_in_time_recovery_description(
+                PointInTimeRecoveryDescription::builder()
+                    .point_in_time_recovery_status(PointInTimeRecoveryStatus::Disabled)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(pitr_summary(&disabled), "DISABLED");
+
+        let enabled = ContinuousBackupsDescription::builder()
+            .continuous_backups_status(ContinuousBackupsStatus::Enabled)
+            .point_in_time_recovery_description(
+                PointInTimeRecoveryDescription::builder()
+                    .point_in_time_recovery_status(PointInTimeRecoveryStatus::Enabled)
+                    .earliest_restorable_date_time(
+                        aws_sdk_dynamodb::primitives::DateTime::from_secs(0),
+                    )
+                    .latest_restorable_date_time(aws_sdk_dynamodb::primitives::DateTime::from_secs(
+                        86400,
+                    ))
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            pitr_summary(&enabled),
+            "ENABLED (restorable: 1970-01-01T00:00:00+00:00 - 1970-01-02T00:00:00+00:00)"
+        );
+    }
+
+    #[test]
+    fn test_ttl_summary() {
+        let disabled = TimeToLiveDescription::builder()
+            .time_to_live_status(TimeToLiveStatus::Disabled)
+            .build();
+        assert_eq!(ttl_summary(&disabled), "DISABLED");
+
+        let enabled = TimeToLiveDescription::builder()
+            .time_to_live_status(TimeToLiveStatus::Enabled)
+            .attribute_name("expiredAt")
+            .build();
+        assert_eq!(ttl_summary(&enabled), "ENABLED (expiredAt)");
+    }
+
+    #[test]
+    fn test_table_description_rows() {
+        let desc = TableDescription::builder()
+            .table_name("rows_table")
+            .table_arn("arn:aws:dynamodb:us-east-1:123456789012:table/rows_table")
+            .table_status(TableStatus::Active)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .global_secondary_indexes(
+                GlobalSecondaryIndexDescription::builder()
+                    .index_name("by_pk")
+                    .key_schema(
+                        KeySchemaElement::builder()
+                            .attribute_name("pk")
+                            .key_type(KeyType::Hash)
+                            .build()
+                            .unwrap(),
+                    )
+                    .provisioned_throughput(
+                        ProvisionedThroughputDescription::builder()
+                            .write_capacity_units(1)
+                            .read_capacity_units(2)
+                            .build(),
+                    )
+                    .projection(
+                        Projection::builder()
+                            .projection_type(ProjectionType::Include)
+                            .non_key_attributes("email")
+                            .non_key_attributes("name")
+                            .build(),
+                    )
+                    .index_status(IndexStatus::Creating)
+                    .backfilling(true)
+                    .build(),
+            )
+            .provisioned_throughput(
+                ProvisionedThroughputDescription::builder()
+                    .write_capacity_units(5)
+                    .read_capacity_units(10)
+                    .build(),
+            )
+            .table_size_bytes(100)
+            .item_count(3)
+            .creation_date_time(aws_sdk_dynamodb::primitives::DateTime::from_secs(0))
+            .build();
+
+        let rows =
+            table_description_rows("us-east-1", &desc, &SupplementalSettings::default(), false);
+        let row = |k: &str| {
+            rows.iter()
+                .find(|(key, _)| key == k)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(row("name"), Some("rows_table"));
+        assert_eq!(
+            row("arn"),
+            Some("arn:aws:dynamodb:us-east-1:123456789012:table/rows_table")
+        );
+        assert_eq!(row("pk"), Some("pk (S)"));
+        assert_eq!(row("sk"), Some("~"));
+        assert_eq!(row("composite"), None);
+        assert_eq!(row("mode"), Some("Provisioned"));
+        assert_eq!(row("capacity"), Some("wcu: 5, rcu: 10"));
+        assert_eq!(row("gsi"), Some(""));
+        assert_eq!(
+            row("  by_pk"),
+            Some("pk: pk (S), projection: INCLUDE (email, name), wcu: 1, rcu: 2, CREATING (backfilling)")
+        );
+        assert_eq!(row("lsi"), None);
+        assert_eq!(row("ttl"), None);
+        assert_eq!(row("count"), Some("3"));
+        assert_eq!(row("size_bytes"), Some("100"));
+        assert_eq!(row("size_human"), Some("100 B"));
+    }
+
+    #[test]
+    fn test_compare_table_schemas() {
+        let desc = |name: &str, gsi: Option<&str>| {
+            TableDescription::builder()
+                .table_name(name)
+                .attribute_definitions(
+                    AttributeDefinition::builder()
+                        .attribute_name("pk")
+                        .attribute_type(ScalarAttributeType::S)
+                        .build()
+                        .unwrap(),
+                )
+                .key_schema(
+                    KeySchemaElement::builder()
+                        .attribute_name("pk")
+                        .key_type(KeyType::Hash)
+                        .build()
+                        .unwrap(),
+                )
+                .set_global_secondary_indexes(gsi.map(|gsi| {
+                    vec![GlobalSecondaryIndexDescription::builder()
+                        .index_name(gsi)
+                        .key_schema(
+                            KeySchemaElement::builder()
+                                .attribute_name("pk")
+                                .key_type(KeyType::Hash)
+                                .build()
+                                .unwrap(),
+                        )
+                        .projection(
+                            Projection::builder()
+                                .projection_type(ProjectionType::KeysOnly)
+                                .build(),
+                        )
+                        .build()]
+                }))
+        };
+
+        // Names of the tables and capacity are not a part of the schema.
+        let a = desc("table_a", Some("by_pk")).build();
+        let b = desc("table_b", Some("by_pk"))
+            .provisioned_throughput(
+                ProvisionedThroughputDescription::builder()
+                    .write_capacity_units(5)
+                    .read_capacity_units(10)
+                    .build(),
+            )
+            .build();
+        assert!(compare_table_schemas(&a, &b).is_empty());
+
+        let b = desc("table_b", Some("by_name"))
+            .billing_mode_summary(
+                BillingModeSummary::builder()
+                    .billing_mode(BillingMode::PayPerRequest)
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            compare_table_schemas(&a, &b),
+            vec![
+                "~ mode: Provisioned -> OnDemand",
+                "- gsi.by_pk: pk: pk (S), projection: KEYS_ONLY",
+                "+ gsi.by_name: pk: pk (S), projection: KEYS_ONLY",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_print_table_without_metadata() {
+        // Item count, size and creation time may be missing on DynamoDB Local or mocked endpoints.
+        let desc = TableDescription::builder()
+            .table_name("no_metadata_table")
+            .table_status(TableStatus::Active)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .billing_mode_summary(
+                BillingModeSummary::builder()
+                    .billing_mode(BillingMode::PayPerRequest)
+                    .build(),
+            )
+            .build();
+
+        let json = serde_json::to_value(build_print_table(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+        ))
+        .unwrap();
+        assert_eq!(json["count"], 0);
+        assert_eq!(json["size_bytes"], 0);
+        assert_eq!(json["size_human"], "0 B");
+        assert_eq!(json["created_at"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_build_print_table_without_attribute_definitions() {
+        let desc = TableDescription::builder()
+            .table_name("no_attr_defs_table")
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+
+        let e = check_table_description(&desc).unwrap_err();
+        assert_eq!(e.field, "AttributeDefinitions");
+        assert_eq!(
+            e.to_string(),
+            "DescribeTable response of the table 'no_attr_defs_table' doesn't include 'AttributeDefinitions'."
+        );
+        // Printing doesn't panic nor exit, and the type of the key is shown as unknown.
+        let t = build_print_table("us-east-1", &desc, &SupplementalSettings::default());
+        assert_eq!(t.schema.pk, "pk (~)");
+    }
+
+    #[test]
+    fn test_build_print_table_keys_without_metadata() {
+        // Item count, size and creation time are missing, but --only-keys should work anyway.
+        let desc = TableDescription::builder()
+            .table_name("keys_table")
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("sk")
+                    .attribute_type(ScalarAttributeType::N)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("sk")
+                    .key_type(KeyType::Range)
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+
+        let yaml = serde_yaml::to_string(&build_print(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+            true,
+        ))
+        .unwrap();
+        assert_eq!(
+            yaml,
+            "name: keys_table\nschema:\n  pk: pk (S)\n  sk: sk (N)\ngsi: null\nlsi: null\n"
+        );
+    }
+
+    #[test]
+    fn test_build_print_table_with_replicas() {
+        let builder = TableDescription::builder()
+            .table_name("global_table")
+            .table_status(TableStatus::Active)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            );
+
+        // Replicas are omitted for tables which are not global tables.
+        let desc = builder.clone().build();
+        let json = serde_json::to_value(build_print_table(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+        ))
+        .unwrap();
+        assert!(json.get("replicas").is_none());
+
+        let desc = builder
+            .replicas(
+                ReplicaDescription::builder()
+                    .region_name("us-east-1")
+                    .replica_status(ReplicaStatus::Active)
+                    .build(),
+            )
+            .replicas(
+                ReplicaDescription::builder()
+                    .region_name("eu-west-1")
+                    .replica_status(ReplicaStatus::Creating)
+                    .build(),
+            )
+            .build();
+        let json = serde_json::to_value(build_print_table(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+        ))
+        .unwrap();
+        assert_eq!(
+            json["replicas"],
+            serde_json::json!(["us-east-1 (ACTIVE)", "eu-west-1 (CREATING)"])
+        );
+
+        let rows =
+            table_description_rows("us-east-1", &desc, &SupplementalSettings::default(), false);
+        assert!(rows.contains(&(
+            String::from("replicas"),
+            String::from("us-east-1 (ACTIVE), eu-west-1 (CREATING)")
+        )));
+    }
+
+    #[test]
+    fn test_parse_table_arn() {
+        assert_eq!(
+            parse_table_arn("arn:aws:dynamodb:us-east-1:123456789012:table/app_users"),
+            Ok((String::from("us-east-1"), String::from("app_users")))
+        );
+        assert_eq!(
+            parse_table_arn("arn:aws-cn:dynamodb:cn-north-1:123456789012:table/app.users-1"),
+            Ok((String::from("cn-north-1"), String::from("app.users-1")))
+        );
+        for arn in [
+            "arn:aws:dynamodb:us-east-1:123456789012:table/app_users/index/by_email",
+            "arn:aws:dynamodb:us-east-1:123456789012:table/app_users/stream/2024-01-01T00:00:00.000",
+            "arn:aws:s3:::my-bucket",
+            "arn:aws:dynamodb::123456789012:table/app_users",
+            "arn:aws:dynamodb:us-east-1:123456789012:table/",
+            "arn:aws:dynamodb:us-east-1",
+        ] {
+            assert!(parse_table_arn(arn).is_err(), "{}", arn);
+        }
+    }
+
+    #[test]
+    fn test_composite_keys() {
+        let keys = PrintPrimaryKeys {
+            pk: String::from("userId (S)"),
+            sk: Some(String::from("createdAt (N)")),
+        };
+        assert_eq!(
+            keys.composite().as_deref(),
+            Some("userId (S) / createdAt (N)")
+        );
+        let keys = PrintPrimaryKeys {
+            pk: String::from("userId (S)"),
+            sk: None,
+        };
+        assert_eq!(keys.composite(), None);
+    }
+
+    #[test]
+    fn test_project_fields() {
+        let desc = TableDescription::builder()
+            .table_name("fields_table")
+            .table_status(TableStatus::Active)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let summary = table_summary("us-east-1", &desc).unwrap();
+
+        // Every field of the output can be selected.
+        let value = serde_json::to_value(&summary).unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(DESCRIBE_TABLE_FIELDS.contains(&key.as_str()), "{}", key);
+        }
+
+        let fields = vec![String::from("name"), String::from("ttl")];
+        assert!(validate_fields(&fields).is_ok());
+        assert_eq!(
+            project_fields(&summary, &fields),
+            json!({"name": "fields_table", "ttl": null})
+        );
+        assert!(validate_fields(&[String::from("colour")])
+            .unwrap_err()
+            .starts_with("Unknown field: 'colour'."));
+    }
+
+    #[test]
+    fn test_table_summary() {
+        let desc = TableDescription::builder()
+            .table_name("lib_table")
+            .table_status(TableStatus::Active)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .item_count(3)
+            .build();
+
+        // Fields are accessible without parsing the printed YAML/JSON.
+        let summary = table_summary("us-east-1", &desc).unwrap();
+        assert_eq!(summary.name, "lib_table");
+        assert_eq!(summary.region, "us-east-1");
+        assert_eq!(summary.status, "ACTIVE");
+        assert_eq!(summary.schema.pk, "pk (S)");
+        assert!(summary.schema.sk.is_none());
+        assert_eq!(summary.mode, Mode::Provisioned);
+        assert_eq!(summary.count, 3);
+        assert!(summary.ttl.is_none());
+
+        // An incomplete description is an error rather than a panic or an exit of the host program.
+        let e = table_summary("us-east-1", &TableDescription::builder().build()).unwrap_err();
+        assert_eq!(e.field, "TableName");
+    }
+
+    #[test]
+    fn test_build_print_table_with_table_class() {
+        let desc = TableDescription::builder()
+            .table_name("ia_table")
+            .table_status(TableStatus::Active)
+            .attribute_definitions(
+                AttributeDefinition::builder()
+                    .attribute_name("pk")
+                    .attribute_type(ScalarAttributeType::S)
+                    .build()
+                    .unwrap(),
+            )
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .table_class_summary(
+                TableClassSummary::builder()
+                    .table_class(TableClass::StandardInfrequentAccess)
+                    .build(),
+            )
+            .build();
+
+        let json = serde_json::to_value(build_print_table(
+            "us-east-1",
+            &desc,
+            &SupplementalSettings::default(),
+        ))
+        .unwrap();
+        assert_eq!(json["table_class"], "STANDARD_INFREQUENT_ACCESS");
+
+        let rows =
+            table_description_rows("us-east-1", &desc, &SupplementalSettings::default(), false);
+        assert!(rows.contains(&(
+            String::from("table_class"),
+            String::from("STANDARD_INFREQUENT_ACCESS")
+        )));
+    }
+
+    #[test]
+    fn test_raw_table_description() {
+        let desc = TableDescription::builder()
+            .table_name("restored_table")
+            .table_status(TableStatus::Active)
+            .key_schema(
+                KeySchemaElement::builder()
+                    .attribute_name("pk")
+                    .key_type(KeyType::Hash)
+                    .build()
+                    .unwrap(),
+            )
+            .restore_summary(
+                RestoreSummary::builder()
+                    .source_backup_arn("arn:aws:dynamodb:us-east-1:111111111111:table/t/backup/01")
+                    .restore_date_time(aws_sdk_dynamodb::primitives::DateTime::from_secs(0))
+                    .restore_in_progress(false)
+                    .build()
+                    .unwrap(),
+            )
+            .replicas(
+                ReplicaDescription::builder()
+                    .region_name("us-west-2")
+                    .replica_status(ReplicaStatus::Active)
+                    .build(),
+            )
+            .build();
+
+        let raw = raw_table_description(&desc);
+        assert_eq!(raw["TableName"], "restored_table");
+        assert_eq!(raw["TableStatus"], "ACTIVE");
+        assert_eq!(raw["KeySchema"][0]["AttributeName"], "pk");
+        assert_eq!(raw["KeySchema"][0]["KeyType"], "HASH");
+        assert_eq!(
+            raw["RestoreSummary"]["RestoreDateTime"],
+            "1970-01-01T00:00:00+00:00"
+        );
+        assert_eq!(raw["RestoreSummary"]["RestoreInProgress"], false);
+        assert!(raw["RestoreSummary"].get("SourceTableArn").is_none());
+        assert_eq!(raw["Replicas"][0]["RegionName"], "us-west-2");
+        assert_eq!(raw["Replicas"][0]["ReplicaStatus"], "ACTIVE");
+        // Fields missing in the response are omitted.
+        assert!(raw.get("ArchivalSummary").is_none());
+        assert!(raw.get("ItemCount").is_none());
+    }
 }
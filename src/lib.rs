@@ -0,0 +1,52 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! dynein is a command line tool to interact with DynamoDB tables/data using concise interface.
+//!
+//! Besides the `dy` binary, this crate can be used as a library.
+//! The [`api`] module provides typed async functions for DynamoDB control plane operations,
+//! which take an explicit [`aws_config::SdkConfig`] and return results instead of printing them or exiting the process.
+//! Other modules implement the `dy` command itself and are not intended to be used directly.
+
+extern crate pest;
+
+#[macro_use]
+extern crate pest_derive;
+
+pub mod api;
+
+#[doc(hidden)]
+pub mod app;
+#[doc(hidden)]
+pub mod batch;
+#[doc(hidden)]
+pub mod bootstrap;
+#[doc(hidden)]
+pub mod cmd;
+#[doc(hidden)]
+pub mod control;
+#[doc(hidden)]
+pub mod data;
+#[doc(hidden)]
+pub mod ddb;
+#[doc(hidden)]
+pub mod parser;
+#[doc(hidden)]
+pub mod shell;
+#[doc(hidden)]
+pub mod template;
+#[doc(hidden)]
+pub mod transfer;
@@ -14,62 +14,165 @@
  * limitations under the License.
  */
 
-use crate::data::QueryParams;
 use brotli::Decompressor;
-use std::io::{stdout, Cursor};
+use std::io::{stdout, Cursor, Write};
 
-use log::debug;
+use log::{debug, error};
 use std::error::Error;
+use std::time::{Duration, Instant};
 
-extern crate pest;
-
-#[macro_use]
-extern crate pest_derive;
-
-mod app;
-mod batch;
-mod bootstrap;
-mod cmd;
-mod control;
-mod data;
-mod ddb;
-mod parser;
-mod shell;
-mod transfer;
+use dynein::data::QueryParams;
+use dynein::{app, batch, bootstrap, cmd, control, data, shell, transfer};
 
 /* =================================================
    helper functions
    =================================================
 */
 async fn dispatch(context: &mut app::Context, subcommand: cmd::Sub) -> Result<(), Box<dyn Error>> {
+    if context.dry_run && !subcommand.supports_dry_run() {
+        return Err(Box::new(control::DyneinControlError::InvalidInput(
+            String::from("--dry-run is not supported by this command yet."),
+        )));
+    }
+    app::ensure_interactive(context).map_err(control::DyneinControlError::from)?;
     match subcommand {
         cmd::Sub::Admin { grandchild } => match grandchild {
-            cmd::AdminSub::List { all_regions } => {
-                if all_regions {
-                    control::list_tables_all_regions(context).await
+            cmd::AdminSub::List {
+                all_regions,
+                regions,
+                all_profiles,
+                exclude_prefixes,
+                prefix,
+                sort,
+                show_size,
+                format,
+                output,
+                concurrency,
+            } => {
+                context.output = output;
+                context.concurrency = concurrency;
+                let filter = control::TableNameFilter {
+                    prefix,
+                    exclude_prefixes,
+                };
+                let options = control::ListTablesOptions {
+                    format,
+                    sort,
+                    show_size,
+                };
+                if all_profiles {
+                    control::list_tables_all_profiles(context, &filter).await?
+                } else if all_regions {
+                    control::list_tables_all_regions(context, &regions, &filter, &options).await?
                 } else {
-                    control::list_tables(context, None).await
+                    control::list_tables(context, None, &filter, &options).await?
                 }
             }
             cmd::AdminSub::Desc {
                 target_table_to_desc,
                 all_tables,
+                aggregate,
+                all_profiles,
                 output,
+                format,
+                only_keys,
+                fields,
+                cache_only,
+                refresh_cache,
+                compare,
+                watch,
+                require_active,
+                concurrency,
             } => {
                 context.output = output;
-                if all_tables {
-                    control::describe_all_tables(context).await
-                } else {
-                    control::describe_table(context, target_table_to_desc).await
-                }
+                context.concurrency = concurrency;
+                let options = control::DescribeTableOptions {
+                    format,
+                    only_keys,
+                    fields,
+                    require_active,
+                };
+                dispatch_desc(
+                    context,
+                    target_table_to_desc,
+                    all_tables,
+                    aggregate,
+                    all_profiles,
+                    cache_only,
+                    refresh_cache,
+                    compare,
+                    watch,
+                    &options,
+                )
+                .await?
             }
             cmd::AdminSub::Create { target_type } => match target_type {
                 cmd::CreateSub::Table {
                     new_table_name,
                     keys,
-                } => control::create_table(context, new_table_name, keys).await,
-                cmd::CreateSub::Index { index_name, keys } => {
-                    control::create_index(context, index_name, keys).await
+                    mode,
+                    wcu,
+                    rcu,
+                    gsis,
+                    projection,
+                    gsi_wcu,
+                    gsi_rcu,
+                    sse,
+                    kms_key_id,
+                    tags,
+                    wait,
+                    force,
+                    if_not_exists,
+                } => {
+                    let gsis = gsis
+                        .iter()
+                        .map(|spec| control::IndexSpec::parse_gsi(spec, projection.as_deref()))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(control::DyneinControlError::InvalidInput)?;
+                    control::create_table(
+                        context,
+                        new_table_name,
+                        keys,
+                        mode,
+                        wcu,
+                        rcu,
+                        gsis,
+                        gsi_wcu,
+                        gsi_rcu,
+                        sse,
+                        kms_key_id,
+                        tags,
+                        wait,
+                        force,
+                        if_not_exists,
+                    )
+                    .await?
+                }
+                cmd::CreateSub::Index {
+                    index_name,
+                    keys,
+                    wcu,
+                    rcu,
+                    projection,
+                    non_key_attributes,
+                    indexes,
+                    wait,
+                } => {
+                    // Either the index name argument or --index option is given, which is ensured by clap.
+                    let specs = match index_name {
+                        Some(name) => vec![control::IndexSpec {
+                            name,
+                            keys,
+                            projection,
+                            non_key_attributes,
+                        }],
+                        None => indexes
+                            .iter()
+                            .map(|spec| control::IndexSpec::parse(spec))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(control::DyneinControlError::InvalidInput)?,
+                    };
+                    control::create_index(context, specs, wcu, rcu, wait).await?
                 }
             },
             cmd::AdminSub::Update { target_type } => match target_type {
@@ -78,13 +181,38 @@ async fn dispatch(context: &mut app::Context, subcommand: cmd::Sub) -> Result<()
                     mode,
                     wcu,
                     rcu,
-                } => control::update_table(context, table_name_to_update, mode, wcu, rcu).await,
+                    table_class,
+                    sse_kms_key,
+                    sse_disable,
+                } => {
+                    control::update_table(
+                        context,
+                        table_name_to_update,
+                        mode,
+                        wcu,
+                        rcu,
+                        table_class,
+                        sse_kms_key,
+                        sse_disable,
+                    )
+                    .await?
+                }
             },
             cmd::AdminSub::Delete { target_type } => match target_type {
                 cmd::DeleteSub::Table {
-                    table_name_to_delete,
-                    yes,
-                } => control::delete_table(context, table_name_to_delete, yes).await,
+                    mut table_name_to_delete,
+                    wait,
+                } => {
+                    if table_name_to_delete.len() > 1 {
+                        control::delete_tables(context, table_name_to_delete, wait).await?
+                    } else {
+                        control::delete_table(context, table_name_to_delete.pop().unwrap(), wait)
+                            .await?
+                    }
+                }
+                cmd::DeleteSub::Index {
+                    index_name_to_delete,
+                } => control::delete_index(context, index_name_to_delete).await?,
             },
             cmd::AdminSub::Apply { dev } => {
                 if dev {
@@ -155,7 +283,19 @@ async fn dispatch(context: &mut app::Context, subcommand: cmd::Sub) -> Result<()
             context.output = output;
             data::get_item(context, pval, sval, consistent_read).await
         }
-        cmd::Sub::Put { pval, sval, item } => data::put_item(context, pval, sval, item).await,
+        cmd::Sub::Put {
+            pval,
+            sval,
+            item,
+            items,
+        } => {
+            if let Some(items) = items {
+                batch::put_items(context, items).await?
+            } else {
+                let pval = pval.expect("pval should be given unless --items is given");
+                data::put_item(context, pval, sval, item).await
+            }
+        }
         cmd::Sub::Del { pval, sval } => data::delete_item(context, pval, sval).await,
         cmd::Sub::Upd {
             pval,
@@ -173,24 +313,74 @@ async fn dispatch(context: &mut app::Context, subcommand: cmd::Sub) -> Result<()
         cmd::Sub::Bwrite { puts, dels, input } => {
             batch::batch_write_item(context, puts, dels, input).await?
         }
-        cmd::Sub::List { all_regions } => {
-            if all_regions {
-                control::list_tables_all_regions(context).await
+        cmd::Sub::List {
+            all_regions,
+            regions,
+            all_profiles,
+            exclude_prefixes,
+            prefix,
+            sort,
+            show_size,
+            format,
+            output,
+            concurrency,
+        } => {
+            context.output = output;
+            context.concurrency = concurrency;
+            let filter = control::TableNameFilter {
+                prefix,
+                exclude_prefixes,
+            };
+            let options = control::ListTablesOptions {
+                format,
+                sort,
+                show_size,
+            };
+            if all_profiles {
+                control::list_tables_all_profiles(context, &filter).await?
+            } else if all_regions {
+                control::list_tables_all_regions(context, &regions, &filter, &options).await?
             } else {
-                control::list_tables(context, None).await
+                control::list_tables(context, None, &filter, &options).await?
             }
         }
         cmd::Sub::Desc {
             target_table_to_desc,
             all_tables,
+            aggregate,
+            all_profiles,
             output,
+            format,
+            only_keys,
+            fields,
+            cache_only,
+            refresh_cache,
+            compare,
+            watch,
+            require_active,
+            concurrency,
         } => {
             context.output = output;
-            if all_tables {
-                control::describe_all_tables(context).await
-            } else {
-                control::describe_table(context, target_table_to_desc).await
-            }
+            context.concurrency = concurrency;
+            let options = control::DescribeTableOptions {
+                format,
+                only_keys,
+                fields,
+                require_active,
+            };
+            dispatch_desc(
+                context,
+                target_table_to_desc,
+                all_tables,
+                aggregate,
+                all_profiles,
+                cache_only,
+                refresh_cache,
+                compare,
+                watch,
+                &options,
+            )
+            .await?
         }
         cmd::Sub::Use {
             target_table_to_use,
@@ -208,6 +398,10 @@ async fn dispatch(context: &mut app::Context, subcommand: cmd::Sub) -> Result<()
             }
             cmd::ConfigSub::Clear => app::remove_dynein_files()?,
         },
+        cmd::Sub::Cache { grandchild } => match grandchild {
+            cmd::CacheSub::Export { output_file } => app::export_cache(output_file)?,
+            cmd::CacheSub::Import { input_file } => app::import_cache(input_file)?,
+        },
 
         cmd::Sub::Bootstrap { list, sample } => {
             if list {
@@ -222,49 +416,299 @@ async fn dispatch(context: &mut app::Context, subcommand: cmd::Sub) -> Result<()
             keys_only,
             output_file,
             format,
-        } => transfer::export(context, attributes, keys_only, output_file, format).await?,
+            s3_bucket,
+            s3_prefix,
+            time,
+        } => match s3_bucket {
+            Some(s3_bucket) => control::export_to_s3(context, s3_bucket, s3_prefix, time).await?,
+            None => {
+                let output_file =
+                    output_file.expect("--output-file should be given unless --s3-bucket is given");
+                transfer::export(context, attributes, keys_only, output_file, format).await?
+            }
+        },
         cmd::Sub::Import {
             input_file,
             format,
             enable_set_inference,
-        } => transfer::import(context, input_file, format, enable_set_inference).await?,
-        cmd::Sub::Backup { list, all_tables } => {
+            s3_bucket,
+            s3_prefix,
+            keys,
+        } => match s3_bucket {
+            Some(s3_bucket) => {
+                control::import_from_s3(context, s3_bucket, s3_prefix, format, keys).await?
+            }
+            None => {
+                let input_file =
+                    input_file.expect("--input-file should be given unless --s3-bucket is given");
+                transfer::import(context, input_file, format, enable_set_inference).await?
+            }
+        },
+        cmd::Sub::Backup {
+            list,
+            all_tables,
+            max_items,
+            delete,
+            before,
+            name,
+            wait,
+        } => {
             if list {
-                control::list_backups(context, all_tables).await?
+                control::list_backups(context, all_tables, max_items).await?
+            } else if delete {
+                control::delete_backups(context, all_tables, before).await?
             } else {
                 control::backup(
                     context, all_tables, /* all_tables is simply ignored for "backup" */
+                    name, wait,
                 )
-                .await
+                .await?
             }
         }
         cmd::Sub::Restore {
             backup_name,
             restore_name,
-        } => control::restore(context, backup_name, restore_name).await,
+            mode,
+            wcu,
+            rcu,
+            wait,
+            copy_tags_to_restore,
+        } => {
+            control::restore(
+                context,
+                backup_name,
+                restore_name,
+                mode,
+                wcu,
+                rcu,
+                wait,
+                copy_tags_to_restore,
+            )
+            .await?
+        }
+        cmd::Sub::CloneSchema {
+            source_table,
+            new_table,
+            wait,
+        } => control::clone_schema(context, source_table, new_table, wait).await?,
+        cmd::Sub::CopyTable {
+            source_table,
+            new_table,
+            dest_region,
+            wait,
+        } => control::copy_table(context, source_table, new_table, dest_region, wait).await?,
+        cmd::Sub::CreateReplica { replica_region } => {
+            control::update_replica(context, replica_region, true).await?
+        }
+        cmd::Sub::DeleteReplica { replica_region } => {
+            control::update_replica(context, replica_region, false).await?
+        }
+        cmd::Sub::Pitr { grandchild } => match grandchild {
+            cmd::PitrSub::Enable => control::update_pitr(context, true).await?,
+            cmd::PitrSub::Disable => control::update_pitr(context, false).await?,
+            cmd::PitrSub::Restore {
+                time,
+                restore_name,
+                wait,
+            } => control::restore_to_point_in_time(context, time, restore_name, wait).await?,
+        },
+        cmd::Sub::Insights { grandchild } => match grandchild {
+            cmd::InsightsSub::Enable { index } => {
+                control::update_contributor_insights(context, index, true).await?
+            }
+            cmd::InsightsSub::Disable { index } => {
+                control::update_contributor_insights(context, index, false).await?
+            }
+        },
+        cmd::Sub::Stream { grandchild } => match grandchild {
+            cmd::StreamSub::Enable { view } => {
+                control::update_stream(context, Some(view), true).await?
+            }
+            cmd::StreamSub::Disable => control::update_stream(context, None, false).await?,
+        },
+        cmd::Sub::Tag { grandchild } => match grandchild {
+            cmd::TagSub::Add { tags } => control::tag_resource(context, tags).await?,
+            cmd::TagSub::List => control::list_tags(context).await?,
+        },
+        cmd::Sub::Ttl { attribute, disable } => {
+            control::update_ttl(context, attribute, !disable).await?
+        }
+        cmd::Sub::Completion { shell } => cmd::print_completion(&shell)?,
     }
     Ok(())
 }
 
+/// Dispatches `$ dy desc` and `$ dy admin desc`, which have the same options.
+#[allow(clippy::too_many_arguments)]
+async fn dispatch_desc(
+    context: &app::Context,
+    mut target_table_to_desc: Vec<String>,
+    all_tables: bool,
+    aggregate: bool,
+    all_profiles: bool,
+    cache_only: bool,
+    refresh_cache: bool,
+    compare: bool,
+    watch: Option<u64>,
+    options: &control::DescribeTableOptions,
+) -> Result<(), control::DyneinControlError> {
+    control::validate_desc_fields(context, options)?;
+    if cache_only {
+        control::describe_table_from_cache(context, target_table_to_desc, refresh_cache).await
+    } else if compare {
+        control::compare_tables(context, target_table_to_desc).await
+    } else if all_profiles {
+        if target_table_to_desc.len() > 1 {
+            return Err(control::DyneinControlError::InvalidInput(String::from(
+                "--all-profiles accepts only one table name.",
+            )));
+        }
+        control::describe_table_all_profiles(context, target_table_to_desc.pop(), options).await
+    } else if all_tables {
+        control::describe_all_tables(context, aggregate, options).await
+    } else if let Some(interval) = watch {
+        if target_table_to_desc.len() > 1 {
+            return Err(control::DyneinControlError::InvalidInput(String::from(
+                "--watch accepts only one table name.",
+            )));
+        }
+        control::watch_table(
+            context,
+            target_table_to_desc.pop(),
+            Duration::from_secs(interval),
+            options,
+        )
+        .await
+    } else if target_table_to_desc.len() > 1 {
+        control::describe_tables(context, target_table_to_desc, options).await
+    } else {
+        control::describe_table(context, target_table_to_desc.pop(), options).await
+    }
+}
+
+/// Prints the given error and exits with the corresponding exit code.
+/// SDK errors are passed to app::bye_with_sdk_error so that deadline and throttling errors get their own exit codes.
+fn exit_with_control_error(cx: &app::Context, e: control::DyneinControlError) -> ! {
+    use control::DyneinControlError::*;
+    match e {
+        ListTables(e) => app::bye_with_sdk_error(cx, 1, e),
+        DescribeRegions(e) => app::bye_with_sdk_error(cx, 1, e),
+        CreateTable(e) => app::bye_with_sdk_error(cx, 1, e),
+        DeleteTable(e) => app::bye_with_sdk_error(cx, 1, e),
+        ListBackups(e) => app::bye_with_sdk_error(cx, 1, e),
+        DescribeTable(e) => app::bye_with_sdk_error(cx, 1, e),
+        RestoreTableFromBackup(e) => app::bye_with_sdk_error(cx, 1, e),
+        Api(e) if app::is_throttling_error_code(e.code.as_deref()) => {
+            error!("{}", e);
+            error!("{}", app::THROTTLED_HINT);
+            std::process::exit(app::THROTTLED_EXIT_CODE);
+        }
+        TableNotActive(msg) => {
+            error!("{}", msg);
+            std::process::exit(app::TABLE_NOT_ACTIVE_EXIT_CODE);
+        }
+        e => {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Executes dispatch with the overall deadline given by --deadline option.
+/// When the deadline has passed, the in-flight dispatch future is dropped, i.e. ongoing operations are cancelled.
+async fn dispatch_with_deadline(
+    context: &mut app::Context,
+    subcommand: cmd::Sub,
+    deadline_secs: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    match deadline_secs {
+        None => dispatch(context, subcommand).await,
+        Some(secs) => {
+            let deadline = Instant::now() + Duration::from_secs(secs);
+            context.deadline = Some(deadline);
+            let result =
+                tokio::time::timeout_at(deadline.into(), dispatch(context, subcommand)).await;
+            // An API call can time out before the deadline (e.g. connect timeout), which is reported as an ordinary error.
+            let deadline_exceeded = context.is_deadline_exceeded();
+            context.deadline = None;
+            match result {
+                Ok(Err(e))
+                    if deadline_exceeded
+                        && e.downcast_ref::<control::DyneinControlError>()
+                            .map_or(false, |e| e.is_timeout()) =>
+                {
+                    Err(Box::new(app::DeadlineExceededError(secs)))
+                }
+                Ok(r) => r,
+                Err(_) => Err(Box::new(app::DeadlineExceededError(secs))),
+            }
+        }
+    }
+}
+
+/// Initializes the logger for --log-format. The default `text` format is the one of env_logger,
+/// and `json` writes each record as a line of JSON object, which is easy to ingest by log collectors.
+fn init_logger(log_format: Option<&str>) {
+    let mut builder = env_logger::Builder::from_default_env();
+    if log_format == Some("json") {
+        builder.format(|buf, record| {
+            writeln!(
+                buf,
+                "{}",
+                serde_json::json!({
+                    "timestamp": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+                    "level": record.level().as_str(),
+                    "module": record.module_path().unwrap_or_else(|| record.target()),
+                    "message": record.args().to_string(),
+                })
+            )
+        });
+    }
+    builder.init();
+}
+
 /* =================================================
    main() function
    =================================================
 */
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    env_logger::init();
-
     let c = cmd::initialize_from_args();
+    init_logger(c.log_format.as_deref());
     debug!("Command details: {:?}", c);
 
     // when --region <region-name e.g. ap-northeast-1>, use the region. when --region local, use DynamoDB local.
     // --region/--table option can be passed as a top-level or subcommand-level (i.e. global).
-    let mut context = app::Context::new(c.region, c.port, c.table)?;
+    let mut context = app::Context::new(c.region, c.port, c.table, c.no_cache)?
+        .with_endpoint_url(c.endpoint_url)
+        .with_max_retries(c.max_retries)
+        .with_assume_yes(c.yes)
+        .with_interactive(c.interactive)
+        .with_dry_run(c.dry_run);
+    if let Some(profile) = &c.profile {
+        context = context.with_profile(profile);
+    }
+    if let Some(role_arn) = c.role_arn {
+        context = context.with_assume_role(Some(app::AssumeRole {
+            role_arn,
+            external_id: c.external_id,
+            session_name: c.session_name,
+        }));
+    }
     debug!("Initial command context: {:?}", &context);
 
     if let Some(child) = c.child {
         // subcommand
-        dispatch(&mut context, child).await?
+        if let Err(e) = dispatch_with_deadline(&mut context, child, c.deadline).await {
+            if e.is::<app::DeadlineExceededError>() {
+                error!("{}", e);
+                std::process::exit(app::DEADLINE_EXCEEDED_EXIT_CODE);
+            }
+            match e.downcast::<control::DyneinControlError>() {
+                Ok(e) => exit_with_control_error(&context, *e),
+                Err(e) => return Err(e),
+            }
+        }
     } else if c.shell {
         // shell mode
         use shell::BuiltinCommands;
@@ -280,7 +724,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 Eof => break,
                 Command(child) => {
                     debug!("context before execution of shell command: {:#?}", context);
-                    if let Err(e) = dispatch(&mut context, child).await {
+                    if let Err(e) = dispatch_with_deadline(&mut context, child, c.deadline).await {
                         eprintln!("{}", e)
                     }
                     debug!("context after execution of shell command: {:#?}", context)
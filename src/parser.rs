@@ -1360,7 +1360,7 @@ fn attr_val_ref(idx: usize) -> String {
 }
 
 /// The parser for dynein.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct DyneinParser {
     names: HashMap<String, String>,
     names_inv: HashMap<String, String>,
@@ -0,0 +1,175 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// This module renders user-supplied templates given by --format option, e.g. `dy desc --format '{{.name}} {{.status}}'`.
+use serde_json::Value as JsonValue;
+use std::fmt;
+
+/* =================================================
+struct / enum / const
+================================================= */
+
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    Unclosed(usize /* position of "{{" */),
+    InvalidPlaceholder(String),
+    UnknownField(
+        String,      /* field */
+        Vec<String>, /* available fields */
+    ),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Unclosed(pos) => {
+                write!(f, "'{{{{' at position {} is not closed with '}}}}'", pos)
+            }
+            TemplateError::InvalidPlaceholder(p) => write!(
+                f,
+                "invalid placeholder '{{{{{}}}}}'. Placeholders should be written like '{{{{.name}}}}'",
+                p
+            ),
+            TemplateError::UnknownField(field, available) => write!(
+                f,
+                "unknown field '{}' in the template. Available fields: {}",
+                field,
+                available.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/* =================================================
+Public functions
+================================================= */
+
+/// Renders the template with fields of the given JSON object. Placeholders are written in Go template style,
+/// i.e. `{{.field}}`, and nested fields can be referred with dots (e.g. `{{.schema.pk}}`).
+/// String values are rendered as they are, null values are rendered as blank, and other values are rendered as compact JSON.
+pub fn render(template: &str, fields: &JsonValue) -> Result<String, TemplateError> {
+    let mut rendered = String::new();
+    let mut rest = template;
+    let mut offset = 0;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .ok_or(TemplateError::Unclosed(offset + start))?;
+        let placeholder = &rest[start + 2..start + end];
+        let path = placeholder
+            .trim()
+            .strip_prefix('.')
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| TemplateError::InvalidPlaceholder(placeholder.to_owned()))?;
+
+        match lookup(fields, path) {
+            None => {
+                return Err(TemplateError::UnknownField(
+                    path.to_owned(),
+                    available_fields(fields),
+                ))
+            }
+            Some(JsonValue::Null) => (),
+            Some(JsonValue::String(s)) => rendered.push_str(s),
+            Some(v) => rendered.push_str(&v.to_string()),
+        }
+
+        offset += start + end + 2;
+        rest = &rest[start + end + 2..];
+    }
+    rendered.push_str(rest);
+
+    Ok(rendered)
+}
+
+/* =================================================
+Private functions
+================================================= */
+
+fn lookup<'a>(fields: &'a JsonValue, path: &str) -> Option<&'a JsonValue> {
+    path.split('.')
+        .try_fold(fields, |current, name| current.as_object()?.get(name))
+}
+
+fn available_fields(fields: &JsonValue) -> Vec<String> {
+    fields
+        .as_object()
+        .map(|o| o.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/* =================================================
+Unit Tests
+================================================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render() {
+        let fields = json!({
+            "name": "Music",
+            "status": "ACTIVE",
+            "count": 3,
+            "schema": {"pk": "Artist (S)", "sk": null},
+        });
+        assert_eq!(
+            render("{{.name}} {{ .status }} {{.count}}", &fields),
+            Ok(String::from("Music ACTIVE 3"))
+        );
+        assert_eq!(
+            render("pk={{.schema.pk}},sk={{.schema.sk}}", &fields),
+            Ok(String::from("pk=Artist (S),sk="))
+        );
+        assert_eq!(
+            render("no placeholder", &fields),
+            Ok(String::from("no placeholder"))
+        );
+    }
+
+    #[test]
+    fn test_render_error() {
+        let fields = json!({"name": "Music", "status": "ACTIVE"});
+        assert_eq!(
+            render("{{.mode}}", &fields),
+            Err(TemplateError::UnknownField(
+                String::from("mode"),
+                vec![String::from("name"), String::from("status")]
+            ))
+        );
+        assert_eq!(
+            render("{{.name.first}}", &fields),
+            Err(TemplateError::UnknownField(
+                String::from("name.first"),
+                vec![String::from("name"), String::from("status")]
+            ))
+        );
+        assert_eq!(
+            render("{{.name}} {{.status", &fields),
+            Err(TemplateError::Unclosed(10))
+        );
+        assert_eq!(
+            render("{{name}}", &fields),
+            Err(TemplateError::InvalidPlaceholder(String::from("name")))
+        );
+    }
+}
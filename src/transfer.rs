@@ -24,7 +24,6 @@ use std::{
     path::Path,
 };
 
-use dialoguer::Confirm;
 use log::{debug, error};
 use serde_json::{de::StrRead, Deserializer, StreamDeserializer, Value as JsonValue};
 
@@ -45,14 +44,8 @@ pub enum DyneinExportError {
     IO(#[from] std::io::Error),
     #[error("serde error")]
     SerdeError(#[from] serde_json::Error),
-}
-
-impl From<dialoguer::Error> for DyneinExportError {
-    fn from(e: dialoguer::Error) -> Self {
-        match e {
-            dialoguer::Error::IO(e) => DyneinExportError::IO(e),
-        }
-    }
+    #[error(transparent)]
+    Confirmation(#[from] app::DyneinConfirmationError),
 }
 
 #[derive(Debug)]
@@ -153,7 +146,7 @@ pub async fn export(
 
     if ts.mode == table::Mode::Provisioned {
         let msg = "WARN: For the best performance on import/export, dynein recommends OnDemand mode. However the target table is Provisioned mode now. Proceed anyway?";
-        if !Confirm::new().with_prompt(msg).interact()? {
+        if !app::confirm(cx, msg)? {
             app::bye(0, "Operation has been cancelled.");
         }
     }
@@ -162,9 +155,7 @@ pub async fn export(
     let attributes: Option<String> = match format_str {
         Some("csv") => {
             if !keys_only && given_attributes.is_none() {
-                overwrite_attributes_or_exit(cx, &ts)
-                    .await
-                    .expect("failed to overwrite attributes based on a scanned item")
+                overwrite_attributes_or_exit(cx, &ts).await?
             } else {
                 given_attributes
             }
@@ -184,7 +175,7 @@ pub async fn export(
     // Though final output file is created here, it would be blank until scan all items. You can see progress in temporary output file.
     let f: fs::File = if Path::new(&output_file).exists() {
         let msg = "Specified output file already exists. Is it OK to truncate contents?";
-        if !Confirm::new().with_prompt(msg).interact()? {
+        if !app::confirm(cx, msg)? {
             app::bye(0, "Operation has been cancelled.");
         }
         debug!("truncating existing output file.");
@@ -297,11 +288,18 @@ pub async fn import(
     enable_set_inference: bool,
 ) -> Result<(), batch::DyneinBatchError> {
     let format_str: Option<&str> = format.as_deref();
+    if let Some(f @ ("dynamodb_json" | "ion")) = format_str {
+        error!(
+            "--format '{}' is acceptable only with --s3-bucket. Available values for local files: [csv, json, jsonl, json-compact].",
+            f
+        );
+        std::process::exit(1);
+    }
 
     let ts: app::TableSchema = app::table_schema(cx).await;
     if ts.mode == table::Mode::Provisioned {
         let msg = "WARN: For the best performance on import/export, dynein recommends OnDemand mode. However the target table is Provisioned mode now. Proceed anyway?";
-        if !Confirm::new().with_prompt(msg).interact()? {
+        if !app::confirm(cx, msg)? {
             println!("Operation has been cancelled.");
             return Ok(());
         }
@@ -371,7 +369,7 @@ Private functions
 async fn overwrite_attributes_or_exit(
     cx: &app::Context,
     ts: &app::TableSchema,
-) -> Result<Option<String>, dialoguer::Error> {
+) -> Result<Option<String>, app::DyneinConfirmationError> {
     println!("As neither --keys-only nor --attributes options are given, fetching an item to understand attributes to export...");
     let suggested_attributes: Vec<SuggestedAttribute> = suggest_attributes(cx, ts).await;
 
@@ -384,7 +382,7 @@ async fn overwrite_attributes_or_exit(
         );
     }
     let msg = "Are you OK to export items in CSV with columns(attributes) above?";
-    if !Confirm::new().with_prompt(msg).interact()? {
+    if !app::confirm(cx, msg)? {
         app::bye(0, "Operation has been cancelled. You can use --keys-only or --attributes option to specify columns explicitly.");
     }
 
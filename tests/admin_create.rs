@@ -47,6 +47,7 @@ async fn test_admin_create_table() -> Result<(), Box<dyn std::error::Error>> {
         .stdout(predicate::str::is_match(format!(
             "name: {TBL}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -56,14 +57,306 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*"
         ))?);
 
     Ok(())
 }
 
+#[tokio::test]
+async fn test_admin_create_table_provisioned() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    const TBL: &str = "table--test_admin_create_table_provisioned";
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            TBL,
+            "--keys",
+            "pk",
+            "--mode",
+            "provisioned",
+            "--wcu",
+            "3",
+            "--rcu",
+            "7",
+        ])
+        .assert()
+        .success();
+    tm.add_tables_to_delete([TBL]);
+
+    tm.command()?
+        .args(["-r", "local", "desc", "--table", TBL])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "mode: Provisioned
+capacity:
+  wcu: 3
+  rcu: 7",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+    const TBL: &str = "table--test_admin_create_table_dry_run";
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "--dry-run",
+            "admin",
+            "create",
+            "table",
+            TBL,
+            "--keys",
+            "pk,S",
+            "--mode",
+            "provisioned",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains(
+                "[dry-run] CreateTable request has not been sent:\nCreateTableInput {",
+            )
+            .and(predicate::str::contains(format!("\"{}\"", TBL)))
+            .and(predicate::str::contains("Provisioned")),
+        );
+
+    // The table should not be created.
+    tm.command()?
+        .args(["-r", "local", "ls"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(TBL).not());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_ondemand_with_capacity() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tm = setup().await?;
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            "table--test_admin_create_table_ondemand_with_capacity",
+            "--keys",
+            "pk",
+            "--wcu",
+            "3",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wcu/--rcu options are acceptable only with --mode provisioned.",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_invalid_key_type() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            "table--test_admin_create_table_invalid_key_type",
+            "--keys",
+            "pk,X",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid key type 'X', expected S, N, or B",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_invalid_key_name() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+    let long_name = "a".repeat(256);
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            "table--test_admin_create_table_invalid_key_name",
+            "--keys",
+            &long_name,
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "it should be 1 to 255 bytes long, but it's 256 bytes.",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_reserved_word_key() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    let table_name = "table--test_admin_create_table_reserved_word_key";
+    tm.command()?
+        .args([
+            "-r", "local", "admin", "create", "table", table_name, "--keys", "name,S",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "WARN: 'name' is a reserved word of DynamoDB.",
+        ));
+    tm.add_tables_to_delete([table_name]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_already_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            &table_name,
+            "--keys",
+            "pk",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "Table '{}' already exists in local region.",
+            table_name
+        )));
+
+    // With --force, CreateTable API is called without the check and DynamoDB returns an error.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            &table_name,
+            "--keys",
+            "pk",
+            "--force",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ResourceInUseException"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_if_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // Both the check beforehand and ResourceInUseException of CreateTable API (with --force) are regarded as success.
+    for extra_args in [vec![], vec!["--force"]] {
+        let mut args = vec![
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            &table_name,
+            "--keys",
+            "pk",
+            "--if-not-exists",
+        ];
+        args.extend(extra_args);
+        tm.command()?.args(args).assert().success().stdout(
+            predicate::str::contains(format!(
+                "Table '{}' already exists in local region with the same schema.",
+                table_name
+            ))
+            .and(predicate::str::contains(format!("name: {}", table_name))),
+        );
+    }
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            &table_name,
+            "--keys",
+            "pk,N",
+            "--if-not-exists",
+        ])
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains(format!(
+                "WARN: Table '{}' already exists in local region with a different schema:",
+                table_name
+            ))
+            .and(predicate::str::contains("~ pk: pk (S) -> pk (N)")),
+        );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_kms_key_without_sse() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            "table--test_admin_create_table_kms_key_without_sse",
+            "--keys",
+            "pk",
+            "--kms-key-id",
+            "alias/my-key",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ))
+        .stderr(predicate::str::contains("--sse"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_table_with_region_local_and_port_number_options(
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -109,6 +402,7 @@ async fn test_create_table_with_region_local_and_port_number_options(
         .stdout(predicate::str::is_match(format!(
             "name: {TBL}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -118,8 +412,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*"
         ))?);
 
@@ -145,6 +441,7 @@ async fn test_admin_create_table_with_type() -> Result<(), Box<dyn std::error::E
         .stdout(predicate::str::is_match(format!(
             "name: {TBL}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(N\\)
@@ -154,8 +451,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*"
         ))?);
 
@@ -181,6 +480,7 @@ async fn test_admin_create_table_with_sk() -> Result<(), Box<dyn std::error::Err
         .stdout(predicate::str::is_match(format!(
             "name: {TBL}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -190,8 +490,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*"
         ))?);
 
@@ -217,6 +519,7 @@ async fn test_admin_create_table_with_sk_type() -> Result<(), Box<dyn std::error
         .stdout(predicate::str::is_match(format!(
             "name: {TBL}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(B\\)
@@ -226,8 +529,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*"
         ))?);
 
@@ -263,6 +568,7 @@ async fn test_admin_create_index() -> Result<(), Box<dyn std::error::Error>> {
         .stdout(predicate::str::is_match(format!(
             "name: {tbl}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -275,10 +581,15 @@ gsi:
     pk: gsi \\(N\\)
     sk: null
   capacity: null
-lsi: null
+  projection: ALL
+  status: ACTIVE
+(  backfilling: false
+)?lsi: null
 stream: null
+ttl: DISABLED
 count: 2
 size_bytes: \\d+
+size_human: .+
 created_at: .*"
         ))?);
 
@@ -333,6 +644,7 @@ async fn test_admin_create_index_with_gsi() -> Result<(), Box<dyn std::error::Er
         .stdout(predicate::str::is_match(format!(
             "name: {tbl}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -345,10 +657,15 @@ gsi:
     pk: pk \\(S\\)
     sk: gsi \\(N\\)
   capacity: null
-lsi: null
+  projection: ALL
+  status: ACTIVE
+(  backfilling: false
+)?lsi: null
 stream: null
+ttl: DISABLED
 count: 2
 size_bytes: \\d+
+size_human: .+
 created_at: .*"
         ))?);
 
@@ -372,3 +689,301 @@ created_at: .*"
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_admin_create_index_on_provisioned_table() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    const TBL: &str = "table--test_admin_create_index_on_provisioned_table";
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            TBL,
+            "--keys",
+            "pk",
+            "--mode",
+            "provisioned",
+            "--wcu",
+            "3",
+            "--rcu",
+            "7",
+        ])
+        .assert()
+        .success();
+    tm.add_tables_to_delete([TBL]);
+
+    // WCU is overridden, and RCU is inherited from the base table.
+    tm.command()?
+        .args([
+            "-r", "local", "admin", "create", "index", "--table", TBL, "idx", "--keys", "gsi,N",
+            "--wcu", "4",
+        ])
+        .assert()
+        .success();
+
+    sleep(Duration::from_secs(1)).await;
+
+    tm.command()?
+        .args(["-r", "local", "desc", "--table", TBL])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "gsi:
+- name: idx
+  schema:
+    pk: gsi (N)
+    sk: null
+  capacity:
+    wcu: 4
+    rcu: 7",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_index_with_include_projection() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut tm = setup().await?;
+    let tbl = tm
+        .create_temporary_table_with_items(
+            "pk",
+            None,
+            [TemporaryItem::new(
+                "pk1",
+                None,
+                Some(r#"{"gsi":1,"a":"x","b":"y"}"#),
+            )],
+        )
+        .await?;
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "index",
+            "--table",
+            &tbl,
+            "idx",
+            "--keys",
+            "gsi,N",
+            "--projection",
+            "include",
+            "--non-key-attributes",
+            "a",
+        ])
+        .assert()
+        .success();
+
+    sleep(Duration::from_secs(1)).await;
+
+    assert_eq_json_ignore_order(
+        tm.command()?.args([
+            "-r", "local", "--table", &tbl, "scan", "--index", "idx", "--output", "raw",
+        ]),
+        r#"[
+            {"pk":{"S":"pk1"}, "gsi":{"N":"1"}, "a":{"S":"x"}}
+        ]"#,
+    );
+
+    tm.command()?
+        .args(["-r", "local", "desc", &tbl])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  projection: INCLUDE (a)\n"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_multiple_indexes() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    let tbl = tm.create_temporary_table("pk", None).await?;
+
+    // Indexes are created one by one, and the table description is printed after the last one.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "index",
+            "--table",
+            &tbl,
+            "--index",
+            "rank_index:rank,N",
+            "--index",
+            "user_date_index:user,S+date,S:keys_only",
+        ])
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("Creating the index 'rank_index' has been started. (1/2)")
+                .and(predicate::str::contains(
+                    "Creating the index 'user_date_index' has been started. (2/2)",
+                )),
+        )
+        .stdout(
+            predicate::str::contains("- name: rank_index")
+                .and(predicate::str::contains("- name: user_date_index")),
+        );
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "index",
+            "--table",
+            &tbl,
+            "--index",
+            "idx:gsi,N:none",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid projection 'none' in --index option",
+        ));
+
+    // The index name argument and --index cannot be given together.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "index",
+            "--table",
+            &tbl,
+            "idx",
+            "--index",
+            "idx2:gsi,N",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_index_with_invalid_projection() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tm = setup().await?;
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "index",
+            "--table",
+            "dummy",
+            "idx",
+            "--keys",
+            "gsi,N",
+            "--non-key-attributes",
+            "a,b",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--non-key-attributes option is acceptable only with --projection include.",
+        ));
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "index",
+            "--table",
+            "dummy",
+            "idx",
+            "--keys",
+            "gsi,N",
+            "--projection",
+            "include",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--non-key-attributes option is required with --projection include.",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_with_gsi_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+    const TBL: &str = "table--test_admin_create_table_with_gsi_dry_run";
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "--dry-run",
+            "admin",
+            "create",
+            "table",
+            TBL,
+            "--keys",
+            "pk,S",
+            "--mode",
+            "provisioned",
+            "--wcu",
+            "10",
+            "--rcu",
+            "20",
+            "--gsi",
+            "idx:pk,S:score,N",
+            "--projection",
+            "include:a,b",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains(
+                "[dry-run] CreateTable request has not been sent:\nCreateTableInput {",
+            )
+            .and(predicate::str::contains("\"idx\""))
+            .and(predicate::str::contains("Include"))
+            .and(predicate::str::contains("\"score\""))
+            // The GSI inherits capacity units of the table.
+            .and(predicate::str::contains("read_capacity_units: 20").count(2))
+            .and(predicate::str::contains("write_capacity_units: 10").count(2)),
+        );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_create_table_with_conflicting_gsi() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            "table--test_admin_create_table_with_conflicting_gsi",
+            "--keys",
+            "pk,S",
+            "--gsi",
+            "idx:pk,N",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "The attribute 'pk' is defined with different types: S and N.",
+        ));
+
+    Ok(())
+}
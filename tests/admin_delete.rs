@@ -40,6 +40,187 @@ async fn test_admin_delete_non_existent_table() -> Result<(), Box<dyn std::error
     Ok(())
 }
 
+#[tokio::test]
+async fn test_admin_delete_table_requires_yes_without_terminal(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // stdin of the command is not a terminal, so dynein should refuse to proceed instead of waiting for input.
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "admin", "delete", "table", &table_name]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Confirmation is required but stdin is not a terminal. Pass --yes to proceed without confirmation.",
+    ));
+
+    // --yes is a global option, so it can be given before the subcommand as well.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "-y",
+        "admin",
+        "delete",
+        "table",
+        &table_name,
+    ]);
+    cmd.assert().success().stdout(format!(
+        "Delete operation for the table '{}' has been started.\n",
+        table_name
+    ));
+    tm.remove_temporary_table(&table_name);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_delete_table_interactive() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // --yes and --interactive are rejected at parse time.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--interactive",
+        "admin",
+        "delete",
+        "table",
+        &table_name,
+        "--yes",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    // --interactive fails without a terminal before sending any request.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--interactive",
+        "admin",
+        "delete",
+        "table",
+        &table_name,
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "--interactive is given but stdin is not a terminal.",
+    ));
+
+    // --assume-yes is an alias of --yes.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "admin",
+        "delete",
+        "table",
+        &table_name,
+        "--assume-yes",
+    ]);
+    cmd.assert().success().stdout(format!(
+        "Delete operation for the table '{}' has been started.\n",
+        table_name
+    ));
+    tm.remove_temporary_table(&table_name);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_delete_table_wait() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "admin",
+        "delete",
+        "table",
+        &table_name,
+        "--wait",
+        "--yes",
+    ]);
+    cmd.assert().success().stdout(format!(
+        "Delete operation for the table '{0}' has been started.\nThe table '{0}' has been deleted.\n",
+        table_name
+    ));
+
+    // The table can be recreated with the same name right after. It's cleaned up as a temporary table.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "admin",
+        "create",
+        "table",
+        &table_name,
+        "--keys",
+        "pk",
+    ]);
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_delete_table_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // Confirmation is not required as nothing is deleted.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--dry-run",
+        "admin",
+        "delete",
+        "table",
+        &table_name,
+    ]);
+    cmd.assert().success().stdout(
+        predicate::str::starts_with(
+            "[dry-run] DeleteTable request has not been sent:\nDeleteTableInput {",
+        )
+        .and(predicate::str::contains(format!("\"{}\"", table_name))),
+    );
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", table_name)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dry_run_unsupported_command() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "--dry-run",
+        "put",
+        "42",
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "--dry-run is not supported by this command yet.",
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_admin_delete_existent_table() -> Result<(), Box<dyn std::error::Error>> {
     let mut tm = util::setup_with_lock().await?;
@@ -76,3 +257,104 @@ async fn test_admin_delete_existent_table() -> Result<(), Box<dyn std::error::Er
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_admin_delete_index() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "admin",
+        "create",
+        "index",
+        "idx",
+        "--keys",
+        "gsi,N",
+    ]);
+    cmd.assert().success();
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "admin",
+        "delete",
+        "index",
+        "idx",
+        "--yes",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", table_name)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_delete_non_existent_index() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "admin",
+        "delete",
+        "index",
+        "dummy-index",
+        "--yes",
+    ]);
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_delete_multiple_tables() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup_with_lock().await?;
+    let table_name1 = tm.create_temporary_table("pk", None).await?;
+    let table_name2 = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "admin",
+            "delete",
+            "table",
+            &table_name1,
+            "dummy-table-doesnt-exist",
+            &table_name2,
+            "--yes",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(format!(
+            "Delete operation for the table '{}' has been started.",
+            table_name1
+        )))
+        .stdout(predicate::str::contains(format!(
+            "Delete operation for the table '{}' has been started.",
+            table_name2
+        )))
+        .stdout(predicate::str::contains("Started deleting 2 of 3 tables."))
+        .stderr(predicate::str::contains(
+            "Failed to delete: dummy-table-doesnt-exist",
+        ));
+
+    // To prevent double deletion in the Drop trait, exclude the tables here
+    tm.remove_temporary_table(&table_name1);
+    tm.remove_temporary_table(&table_name2);
+
+    Ok(())
+}
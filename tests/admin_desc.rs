@@ -30,6 +30,7 @@ async fn test_admin_desc_table_from_options() -> Result<(), Box<dyn std::error::
         predicate::str::is_match(format!(
             "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -39,8 +40,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
             table_name
         ))
@@ -62,6 +65,7 @@ async fn test_admin_desc_table_from_args() -> Result<(), Box<dyn std::error::Err
             predicate::str::is_match(format!(
                 "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -71,8 +75,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
                 table_name
             ))
@@ -95,6 +101,7 @@ async fn test_admin_desc_all_tables() -> Result<(), Box<dyn std::error::Error>>
         predicate::str::is_match(format!(
             "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -106,6 +113,7 @@ lsi: null
 stream: null
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
             table_name1
         ))
@@ -114,6 +122,7 @@ created_at: .*",
             predicate::str::is_match(format!(
                 "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -125,6 +134,7 @@ lsi: null
 stream: null
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
                 table_name2
             ))
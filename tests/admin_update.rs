@@ -117,3 +117,164 @@ async fn test_admin_update_table_mode_with_index() -> Result<(), Box<dyn std::er
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_admin_update_table_mode_already_in_mode() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let tbl = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "--region", "local", "admin", "update", "table", &tbl, "--mode", "ondemand",
+        ])
+        .assert()
+        .success()
+        .stdout(format!(
+            "The table '{}' is already in OnDemand mode.\n",
+            tbl
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_update_table_mode_ondemand_with_capacity(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let tbl = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "--region", "local", "admin", "update", "table", &tbl, "--mode", "ondemand", "--wcu",
+            "10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wcu/--rcu options cannot be used with --mode ondemand.",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_update_table_class_already_in_class() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut tm = util::setup().await?;
+    let tbl = tm.create_temporary_table("pk", None).await?;
+
+    // Tables are created in STANDARD class by default.
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "admin",
+            "update",
+            "table",
+            &tbl,
+            "--table-class",
+            "standard",
+        ])
+        .assert()
+        .success()
+        .stdout(format!(
+            "The table '{}' is already in STANDARD class.\n",
+            tbl
+        ));
+
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "admin",
+            "update",
+            "table",
+            &tbl,
+            "--table-class",
+            "glacier",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'glacier'"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_update_table_sse_disable_already_aws_owned(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let tbl = tm.create_temporary_table("pk", None).await?;
+
+    // Tables are encrypted with an AWS owned key by default, hence nothing to update nor to confirm.
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "admin",
+            "update",
+            "table",
+            &tbl,
+            "--sse-disable",
+        ])
+        .assert()
+        .success()
+        .stdout(format!(
+            "The table '{}' is already encrypted with an AWS owned key.\n",
+            tbl
+        ));
+
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "admin",
+            "update",
+            "table",
+            &tbl,
+            "--sse-disable",
+            "--sse-kms-key",
+            "alias/my-key",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_update_table_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let tbl = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "--dry-run",
+            "admin",
+            "update",
+            "table",
+            &tbl,
+            "--mode",
+            "provisioned",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] UpdateTable request has not been sent:\nUpdateTableInput {",
+            )
+            .and(predicate::str::contains("Provisioned")),
+        );
+
+    // The table is left as it is.
+    tm.command()?
+        .args(["--region", "local", "desc", &tbl])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("mode: OnDemand"));
+
+    Ok(())
+}
@@ -31,6 +31,25 @@ async fn test_help() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_completion() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args(["completion", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("_dy()").and(predicate::str::contains("copy-table")));
+
+    tm.command()?
+        .args(["completion", "tcsh"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'tcsh'"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_custom_config_location() -> Result<(), Box<dyn std::error::Error>> {
     let tm = util::setup().await?;
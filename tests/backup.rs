@@ -44,3 +44,98 @@ async fn test_backup() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_backup_delete_with_invalid_before() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "backup",
+            "--table",
+            "non-existent-table",
+            "--delete",
+            "--before",
+            "yesterday",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --before 'yesterday'"));
+
+    tm.command()?
+        .args(["-r", "local", "backup", "--list", "--delete"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backup_wait_with_list() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+
+    // --wait is only for creating a backup.
+    tm.command()?
+        .args(["-r", "local", "backup", "--list", "--wait"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    // --max-items is only for listing backups.
+    tm.command()?
+        .args(["-r", "local", "backup", "--max-items", "5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--list"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backup_with_name() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // DynamoDB Local doesn't support backups, hence the request is checked with --dry-run.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "--dry-run",
+            "backup",
+            "--table",
+            &table_name,
+            "--name",
+            "before-release_v1.0",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] CreateBackup request has not been sent:\nCreateBackupInput {",
+            )
+            .and(predicate::str::contains("\"before-release_v1.0\"")),
+        );
+
+    // Invalid names are rejected before calling any API.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "backup",
+            "--table",
+            "non-existent-table",
+            "--name",
+            "before release",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Backup name can contain only alphanumerics",
+        ));
+
+    Ok(())
+}
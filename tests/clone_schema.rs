@@ -0,0 +1,109 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use crate::util::{setup, TemporaryItem};
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_clone_schema() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = setup().await?;
+    const TBL: &str = "table--test_clone_schema";
+    let src = tm
+        .create_temporary_table_with_items(
+            "pk",
+            Some("sk,N"),
+            [TemporaryItem::new("pk1", Some("1"), Some(r#"{"gsi":"a"}"#))],
+        )
+        .await?;
+    tm.command()?
+        .args([
+            "-r", "local", "admin", "create", "index", "--table", &src, "idx", "--keys", "gsi,S",
+        ])
+        .assert()
+        .success();
+
+    sleep(Duration::from_secs(1)).await;
+
+    tm.command()?
+        .args(["-r", "local", "clone-schema", &src, TBL, "--wait"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Started creating the table '{TBL}' with the same schema as '{src}'."
+        )));
+    tm.add_tables_to_delete([TBL]);
+
+    // Schema is copied, but items are not.
+    tm.command()?
+        .args(["-r", "local", "desc", "--table", TBL])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(format!(
+            "name: {TBL}
+region: local
+arn: arn:aws:dynamodb:.+
+status: ACTIVE
+schema:
+  pk: pk \\(S\\)
+  sk: sk \\(N\\)
+mode: OnDemand
+capacity: null
+gsi:
+- name: idx
+  schema:
+    pk: gsi \\(S\\)
+    sk: null
+  capacity: null
+  projection: ALL
+  status: ACTIVE
+(  backfilling: false
+)?lsi: null
+stream: null
+ttl: DISABLED
+count: 0
+size_bytes: 0
+size_human: 0 B
+created_at: .*"
+        ))?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clone_schema_source_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = setup().await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "clone-schema",
+            "table--test_clone_schema_not_found",
+            "table--test_clone_schema_not_found_new",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Cannot do operations on a non-existent table",
+        ));
+
+    Ok(())
+}
@@ -17,6 +17,7 @@
 pub mod util;
 
 use assert_cmd::prelude::*; // Add methods on commands
+use predicates::prelude::*; // Used for writing assertions
 
 #[tokio::test]
 async fn test_config_dump() -> Result<(), Box<dyn std::error::Error>> {
@@ -83,8 +84,10 @@ async fn assert_config_use_dump(
 
     let mut c = tm.command()?;
     let cmd = c.args(["config", "dump"]);
-    cmd.assert().success().stdout(format!(
-        "tables:
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match(format!(
+            "^tables:
   local/{table_name}:
     region: local
     name: {table_name}
@@ -94,6 +97,7 @@ async fn assert_config_use_dump(
     sk: null
     indexes: null
     mode: OnDemand
+    cached_at: .+
 
 using_region: local
 using_table: {table_name}
@@ -102,8 +106,8 @@ query:
   strict_mode: false
 retry: null
 
-"
-    ));
+$"
+        ))?);
 
     Ok(())
 }
@@ -39,6 +39,31 @@ async fn test_desc_non_existent_table() -> Result<(), Box<dyn std::error::Error>
     Ok(())
 }
 
+#[tokio::test]
+async fn test_desc_invalid_region() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    // The region is validated before any API call, while aliases are accepted.
+    tm.command()?
+        .args(["--region", "nonsense", "desc", "foo"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown region: 'nonsense'."));
+    tm.command()?
+        .args([
+            "--region",
+            "tokyo",
+            "--dry-run",
+            "admin",
+            "delete",
+            "table",
+            "foo",
+        ])
+        .assert()
+        .success();
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_desc_table_from_options() -> Result<(), Box<dyn std::error::Error>> {
     let mut tm = util::setup().await?;
@@ -51,6 +76,7 @@ async fn test_desc_table_from_options() -> Result<(), Box<dyn std::error::Error>
             predicate::str::is_match(format!(
                 "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -60,8 +86,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
                 table_name
             ))
@@ -83,6 +111,7 @@ async fn test_desc_table_from_args() -> Result<(), Box<dyn std::error::Error>> {
         predicate::str::is_match(format!(
             "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -92,8 +121,10 @@ capacity: null
 gsi: null
 lsi: null
 stream: null
+ttl: DISABLED
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
             table_name
         ))
@@ -115,6 +146,7 @@ async fn test_desc_all_tables() -> Result<(), Box<dyn std::error::Error>> {
         predicate::str::is_match(format!(
             "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -126,6 +158,7 @@ lsi: null
 stream: null
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
             table_name1
         ))
@@ -134,6 +167,7 @@ created_at: .*",
             predicate::str::is_match(format!(
                 "name: {}
 region: local
+arn: arn:aws:dynamodb:.+
 status: ACTIVE
 schema:
   pk: pk \\(S\\)
@@ -145,6 +179,7 @@ lsi: null
 stream: null
 count: 0
 size_bytes: 0
+size_human: 0 B
 created_at: .*",
                 table_name2
             ))
@@ -153,3 +188,428 @@ created_at: .*",
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn test_desc_all_tables_aggregate() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup_with_lock().await?;
+    let table_name1 = tm.create_temporary_table("pk", None).await?;
+    let table_name2 = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        "--all-tables",
+        "--aggregate",
+        "--output",
+        "json",
+    ]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    // All tables are printed as one JSON array sorted by name.
+    let descs: serde_json::Value = serde_json::from_slice(&output)?;
+    let names: Vec<&str> = descs
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|desc| desc["name"].as_str().unwrap())
+        .collect();
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(names, sorted);
+    assert!(names.contains(&table_name1.as_str()));
+    assert!(names.contains(&table_name2.as_str()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_multiple_tables() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name1 = tm.create_temporary_table("pk", None).await?;
+    let table_name2 = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        &table_name1,
+        "dummy-table-doent-exist",
+        &table_name2,
+        "--output",
+        "json",
+    ]);
+    cmd.assert()
+        .failure()
+        .stdout(
+            predicate::str::starts_with("[")
+                .and(predicate::str::contains(format!(
+                    "\"name\": \"{}\"",
+                    table_name1
+                )))
+                .and(predicate::str::contains(format!(
+                    "\"name\": \"{}\"",
+                    table_name2
+                ))),
+        )
+        .stderr(predicate::str::contains(
+            "described 2/3 tables; 1 failed: dummy-table-doent-exist (not found)",
+        ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_only_keys() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--only-keys"]);
+    cmd.assert().success().stdout(format!(
+        "name: {}
+schema:
+  pk: pk (S)
+  sk: sk (N)
+gsi: null
+lsi: null
+
+",
+        table_name
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_compare() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_a = tm.create_temporary_table("pk,S", None).await?;
+    let table_b = tm.create_temporary_table("pk,S", None).await?;
+    let table_c = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", "--compare", &table_a, &table_b]);
+    cmd.assert().success().stdout(format!(
+        "Schemas of the tables '{}' and '{}' are identical.\n",
+        table_a, table_b
+    ));
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", "--compare", &table_a, &table_c]);
+    cmd.assert()
+        .failure()
+        .stdout(format!(
+            "--- {}\n+++ {}\n~ sk: ~ -> sk (N)\n",
+            table_a, table_c
+        ))
+        .stderr(predicate::str::contains(format!(
+            "Schemas of the tables '{}' and '{}' differ in 1 field(s).",
+            table_a, table_c
+        )));
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", "--compare", &table_a]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "--compare requires exactly two table names.",
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_cache_only() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--cache-only"]);
+    cmd.assert().failure().stderr(predicate::str::contains(format!(
+        "No cached schema found for '{}' table in local region. Run `dy desc {}` without --cache-only first to cache it.",
+        table_name, table_name
+    )));
+
+    let mut c = tm.command()?;
+    c.args(["--region", "local", "desc", &table_name])
+        .assert()
+        .success();
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--cache-only"]);
+    cmd.assert().success().stdout(format!(
+        "name: {}
+schema:
+  pk: pk (S)
+  sk: sk (N)
+gsi: null
+lsi: null
+
+",
+        table_name
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_cache_only_stale() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", None).await?;
+
+    // A schema without cached_at, e.g. cached by older versions, is regarded as stale.
+    let mut cache_path = tm.default_config_dir();
+    cache_path.push("cache.yml");
+    std::fs::write(
+        &cache_path,
+        format!(
+            "tables:
+  local/{table_name}:
+    region: local
+    name: {table_name}
+    pk:
+      name: pk
+      kind: S
+    sk: null
+    indexes: null
+    mode: OnDemand
+"
+        ),
+    )?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--cache-only"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", table_name)))
+        .stderr(predicate::str::contains(format!(
+            "WARN: The cached schema of '{}' table in local region was cached at unknown time",
+            table_name
+        )));
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        &table_name,
+        "--cache-only",
+        "--refresh-cache",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", table_name)))
+        .stderr(predicate::str::contains("WARN").not());
+
+    // The refreshed schema is fresh, hence no warning.
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--cache-only"]);
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("WARN").not());
+    assert!(std::fs::read_to_string(&cache_path)?.contains("cached_at: "));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_no_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "--no-cache", "desc", &table_name]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", table_name)));
+
+    // The described schema must not be written to the cache.
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--cache-only"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "No cached schema found for '{}' table in local region.",
+            table_name
+        )));
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--no-cache",
+        "desc",
+        &table_name,
+        "--cache-only",
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "--cache-only cannot be used with --no-cache.",
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_table_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--output", "json"]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let desc: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(desc["name"], table_name.as_str());
+    assert_eq!(desc["mode"], "OnDemand");
+    assert!(desc["arn"]
+        .as_str()
+        .expect("arn should be a string")
+        .ends_with(&format!(":table/{}", table_name)));
+    assert_eq!(desc["schema"]["pk"], "pk (S)");
+    assert_eq!(desc["schema"]["sk"], "sk (N)");
+    assert_eq!(desc["capacity"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_table_table_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        &table_name,
+        "--output",
+        "table",
+    ]);
+    cmd.assert().success().stdout(
+        predicate::str::is_match(format!(
+            "name +{}\nregion +local\narn +arn:aws:dynamodb:.+\nstatus +ACTIVE\npk +pk \\(S\\)\nsk +sk \\(N\\)\ncomposite +pk \\(S\\) / sk \\(N\\)\nmode +OnDemand\nstream +~\n",
+            table_name
+        ))
+        .unwrap(),
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_output_raw() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--output", "raw"]);
+    cmd.assert().success().stdout(
+        predicate::str::contains(format!("\"TableName\": \"{}\"", table_name))
+            .and(predicate::str::contains("\"AttributeName\": \"pk\""))
+            .and(predicate::str::contains("\"KeyType\": \"HASH\""))
+            .and(predicate::str::contains("\"TableStatus\": \"ACTIVE\"")),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        &table_name,
+        "--fields",
+        "name,status,count",
+        "--output",
+        "json",
+    ]);
+    util::assert_eq_cmd_json(
+        cmd,
+        &format!(
+            r#"{{"name": "{}", "status": "ACTIVE", "count": 0}}"#,
+            table_name
+        ),
+    );
+
+    // Unknown fields are rejected before describing the table.
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "desc",
+            &table_name,
+            "--fields",
+            "name,colour",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown field: 'colour'."));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_watch() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", None).await?;
+
+    // A table on DynamoDB Local is ACTIVE right after creation, hence it's described just once.
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--watch", "1"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("name: {}", table_name)))
+        .stderr(predicate::str::contains(format!(
+            "The table '{}' is ACTIVE.",
+            table_name
+        )));
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", "--all-tables", "--watch"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_require_active() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk,S", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "desc", &table_name, "--require-active"]);
+    cmd.assert().success();
+
+    // Multiple tables are checked one by one, and a missing table fails with the summary rather than code 3.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        &table_name,
+        "another_table",
+        "--require-active",
+    ]);
+    cmd.assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "described 1/2 tables; 1 failed: another_table (not found)",
+        ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_desc_invalid_table_arn() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "desc",
+        "arn:aws:dynamodb:us-east-1:123456789012:table/app_users/index/by_email",
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Invalid table ARN: 'arn:aws:dynamodb:us-east-1:123456789012:table/app_users/index/by_email'.",
+    ));
+    Ok(())
+}
@@ -116,3 +116,58 @@ async fn test_export_with_items() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_export_to_s3_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // DynamoDB Local doesn't support exports to S3, hence the request is checked with --dry-run.
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "--table",
+            &table_name,
+            "--dry-run",
+            "export",
+            "--s3-bucket",
+            "my-bucket",
+            "--s3-prefix",
+            "exports/",
+            "--time",
+            "2024-01-01T00:00:00Z",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] ExportTableToPointInTime request has not been sent:\nExportTableToPointInTimeInput {",
+            )
+            .and(predicate::str::contains(&table_name))
+            .and(predicate::str::contains("\"my-bucket\""))
+            .and(predicate::str::contains("\"exports/\"")),
+        );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_export_to_s3_invalid_time() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "--table",
+            "dummy",
+            "export",
+            "--s3-bucket",
+            "my-bucket",
+            "--time",
+            "yesterday",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --time 'yesterday'"));
+    Ok(())
+}
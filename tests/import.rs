@@ -230,3 +230,66 @@ async fn test_import_jsonl_with_set_inference() -> Result<(), Box<dyn std::error
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_import_from_s3_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    // DynamoDB Local doesn't support imports from S3, hence the request is checked with --dry-run.
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "--table",
+            "imported",
+            "--dry-run",
+            "import",
+            "--s3-bucket",
+            "my-bucket",
+            "--s3-prefix",
+            "exports/",
+            "--keys",
+            "date,N,RANGE",
+            "id,S,HASH",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] ImportTable request has not been sent:\nImportTableInput {",
+            )
+            .and(predicate::str::contains("\"imported\""))
+            .and(predicate::str::contains("\"my-bucket\""))
+            .and(predicate::str::contains("DynamodbJson"))
+            .and(predicate::str::contains("PayPerRequest")),
+        );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_from_s3_invalid_format() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args([
+            "--region",
+            "local",
+            "--table",
+            "imported",
+            "import",
+            "--s3-bucket",
+            "my-bucket",
+            "--format",
+            "jsonl",
+            "--keys",
+            "id,S",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--format 'jsonl' is not supported for imports from S3.",
+        ));
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_insights_enable_index_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    // DynamoDB Local doesn't support Contributor Insights, hence the request is checked with --dry-run.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "-t",
+            "dummy",
+            "--dry-run",
+            "insights",
+            "enable",
+            "--index",
+            "idx",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] UpdateContributorInsights request has not been sent:\nUpdateContributorInsightsInput {",
+            )
+            .and(predicate::str::contains("\"dummy\""))
+            .and(predicate::str::contains("\"idx\""))
+            .and(predicate::str::contains("Enable")),
+        );
+
+    Ok(())
+}
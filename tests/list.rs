@@ -16,6 +16,7 @@
 pub mod util;
 use assert_cmd::prelude::*; // Add methods on commands
 use predicates::prelude::*; // Used for writing assertions
+use tempfile::tempdir;
 
 #[tokio::test]
 async fn test_list_table_with_no_table() -> Result<(), Box<dyn std::error::Error>> {
@@ -71,3 +72,308 @@ async fn test_list_table_ls_alias() -> Result<(), Box<dyn std::error::Error>> {
         .stdout(predicate::str::contains(format!("* {table_name}")));
     Ok(())
 }
+
+#[tokio::test]
+async fn test_list_table_with_exclude_prefix() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup_with_lock().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+    let table_name2 = tm.create_temporary_table("pk", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "list",
+        "--exclude-prefix",
+        &table_name2,
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(table_name))
+        .stdout(predicate::str::contains(table_name2).not());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_all_regions_with_invalid_region() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "list",
+        "--all-regions",
+        "--regions",
+        "us-east-1,useast2",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown region: 'useast2'"));
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "list", "--regions", "us-east-1"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "the following required arguments were not provided",
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_with_endpoint_url() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // --endpoint-url takes precedence over --port, which points to nowhere here.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--port",
+        "1",
+        "--endpoint-url",
+        "http://localhost:8000",
+        "ls",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(table_name));
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--endpoint-url",
+        "localhost:8000",
+        "ls",
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "endpoint URL should start with 'http://' or 'https://'",
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_with_profile() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    let tmpdir = tempdir()?;
+    let config_file = tmpdir.path().join("config");
+    let credentials_file = tmpdir.path().join("credentials");
+    std::fs::write(&config_file, "[profile dynein-test]\nregion = us-east-1\n")?;
+    std::fs::write(
+        &credentials_file,
+        "[dynein-test]\naws_access_key_id = AKIDEXAMPLE\naws_secret_access_key = SECRETEXAMPLE\n",
+    )?;
+
+    let mut c = tm.command()?;
+    let cmd = c
+        .env("AWS_CONFIG_FILE", &config_file)
+        .env("AWS_SHARED_CREDENTIALS_FILE", &credentials_file)
+        .args(["--region", "local", "--profile", "dynein-test", "ls"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(table_name));
+
+    // A profile which is not defined in the config files fails to resolve credentials.
+    let mut c = tm.command()?;
+    let cmd = c
+        .env("AWS_CONFIG_FILE", &config_file)
+        .env("AWS_SHARED_CREDENTIALS_FILE", &credentials_file)
+        .args(["--region", "local", "--profile", "no-such-profile", "ls"]);
+    cmd.assert().failure();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_with_role_arn() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    // The role is not assumed for DynamoDB Local, which doesn't check credentials.
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--role-arn",
+        "arn:aws:iam::123456789012:role/DyneinRole",
+        "--session-name",
+        "dynein-test",
+        "ls",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(table_name));
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "--role-arn", "DyneinRole", "ls"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "role ARN should be in the form of 'arn:aws:iam::<account-id>:role/<role-name>'",
+    ));
+
+    // --external-id is meaningless without --role-arn.
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "--external-id", "secret", "ls"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--role-arn <ROLE_ARN>"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_with_json_log_format() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    let mut c = tm.command()?;
+    let cmd = c
+        .env("RUST_LOG", "debug")
+        .args(["--region", "local", "--log-format", "json", "ls"]);
+    let output = cmd.assert().success().get_output().stderr.clone();
+
+    // Every log line is a JSON object with the fixed set of fields.
+    let stderr = String::from_utf8(output)?;
+    assert!(!stderr.is_empty());
+    for line in stderr.lines() {
+        let log: serde_json::Value = serde_json::from_str(line)?;
+        for field in ["timestamp", "level", "module", "message"] {
+            assert!(log.get(field).is_some(), "{} is missing in {}", field, line);
+        }
+    }
+    assert!(stderr.contains(r#""level":"DEBUG""#));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_with_prefix_and_sort() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm
+        .create_temporary_table_with_items(
+            "pk",
+            None,
+            vec![util::TemporaryItem::new("a", None, None)],
+        )
+        .await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "ls", "--prefix", &table_name]);
+    cmd.assert().success().stdout(format!(
+        "DynamoDB tables in region: local\n  {}\n",
+        table_name
+    ));
+
+    // Tables are described for sorting by items, after filtered by the prefix.
+    for sort in ["name", "size", "items"] {
+        let mut c = tm.command()?;
+        let cmd = c.args([
+            "--region",
+            "local",
+            "ls",
+            "--prefix",
+            &table_name,
+            "--sort",
+            sort,
+        ]);
+        cmd.assert().success().stdout(format!(
+            "DynamoDB tables in region: local\n  {}\n",
+            table_name
+        ));
+    }
+
+    let mut c = tm.command()?;
+    let cmd = c.args(["--region", "local", "ls", "--sort", "created"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'created'"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_show_size() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm
+        .create_temporary_table_with_items(
+            "pk",
+            None,
+            vec![util::TemporaryItem::new("a", None, None)],
+        )
+        .await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "ls",
+        "--prefix",
+        &table_name,
+        "--show-size",
+        "--sort",
+        "size",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match(format!(
+            "^DynamoDB tables in region: local\n  Table +Size +Items\n  {} +\\d+(\\.\\d)? [KMGTP]?B +\\d+\n$",
+            table_name
+        ))?);
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "ls",
+        "--prefix",
+        &table_name,
+        "--show-size",
+        "--format",
+        "{{.name}} {{.item_count}}",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match(format!(
+            "^{} \\d+\n$",
+            table_name
+        ))?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_table_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "ls",
+        "--prefix",
+        &table_name,
+        "--output",
+        "json",
+    ]);
+    cmd.assert().success().stdout(format!(
+        "[\n  {{\n    \"name\": \"{}\",\n    \"region\": \"local\",\n    \"is_current\": false\n  }}\n]\n",
+        table_name
+    ));
+
+    // The table switched by `dy use` is marked as current.
+    let mut c = tm.command()?;
+    c.args(["--region", "local", "use", &table_name])
+        .assert()
+        .success();
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "ls",
+        "--prefix",
+        &table_name,
+        "-o",
+        "json",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"is_current\": true"));
+    Ok(())
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_pitr_restore_invalid_time() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "-t",
+            "dummy",
+            "pitr",
+            "restore",
+            "--time",
+            "yesterday",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --time 'yesterday'"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pitr_enable_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args(["-r", "local", "-t", "dummy", "--dry-run", "pitr", "enable"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] UpdateContinuousBackups request has not been sent:\nUpdateContinuousBackupsInput {",
+            )
+            .and(predicate::str::contains("\"dummy\""))
+            .and(predicate::str::contains("point_in_time_recovery_enabled: true")),
+        );
+
+    Ok(())
+}
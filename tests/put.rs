@@ -485,3 +485,73 @@ async fn test_multiple_put_same_pk_different_sk() -> Result<(), Box<dyn std::err
     util::assert_eq_json_ignore_order(get_cmd, &expected);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_put_items() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "put",
+        "--items",
+        r#"[{"pk": "a", "sk": 1, "x": "foo"}, {"pk": {"S": "b"}, "sk": {"N": "2"}}]"#,
+    ]);
+    cmd.assert().success().stdout(format!(
+        "Successfully put 2 items to the table '{}'.\n",
+        table_name
+    ));
+
+    let mut c = tm.command()?;
+    let scan_cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "scan",
+        "-o",
+        "raw",
+    ]);
+    util::assert_eq_json_ignore_order(
+        scan_cmd,
+        r#"
+        [{
+            "pk": { "S": "a" },
+            "sk": { "N": "1" },
+            "x": { "S": "foo" }
+        },
+        {
+            "pk": { "S": "b" },
+            "sk": { "N": "2" }
+        }]
+        "#,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_put_items_missing_key() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", Some("sk,N")).await?;
+
+    let mut c = tm.command()?;
+    let cmd = c.args([
+        "--region",
+        "local",
+        "--table",
+        &table_name,
+        "put",
+        "--items",
+        r#"[{"pk": "a"}]"#,
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "must provide the sort key attribute sk",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_create_replica_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    // DynamoDB Local doesn't support global tables, hence the request is checked with --dry-run.
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "-t",
+            "dummy",
+            "--dry-run",
+            "create-replica",
+            "oregon",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] UpdateTable request has not been sent:\nUpdateTableInput {",
+            )
+            .and(predicate::str::contains("\"dummy\""))
+            .and(predicate::str::contains("CreateReplicationGroupMemberAction"))
+            .and(predicate::str::contains("\"us-west-2\"")),
+        );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_replica_invalid_region() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args(["-r", "local", "-t", "dummy", "delete-replica", "us-west"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown region: 'us-west'."));
+
+    Ok(())
+}
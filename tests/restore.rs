@@ -44,3 +44,67 @@ async fn test_restore() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_restore_capacity_without_provisioned_mode() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tm = setup().await?;
+
+    tm.command()?
+        .args([
+            "-r", "local", "restore", "--table", "dummy", "--mode", "ondemand", "--wcu", "10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wcu/--rcu options are acceptable only with --mode provisioned.",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_provisioned_mode_requires_capacity() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tm = setup().await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "restore",
+            "--table",
+            "dummy",
+            "--mode",
+            "provisioned",
+            "--wcu",
+            "10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wcu and --rcu options are required with --mode provisioned.",
+        ));
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "restore",
+            "--table",
+            "dummy",
+            "--mode",
+            "provisioned",
+            "--wcu",
+            "10",
+            "--rcu",
+            "0",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--rcu should be greater than 0, but 0 was given.",
+        ));
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_stream_enable_and_disable() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "-t",
+            &table_name,
+            "stream",
+            "enable",
+            "--view",
+            "KEYS_ONLY",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match("stream: .+ \\(KEYS_ONLY\\)")?);
+
+    tm.command()?
+        .args(["-r", "local", "-t", &table_name, "stream", "disable"])
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_enable_unknown_view() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args([
+            "-r", "local", "-t", "dummy", "stream", "enable", "--view", "ALL",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value 'ALL' for '--view <VIEW>'",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_enable_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "-t",
+            "dummy",
+            "--dry-run",
+            "stream",
+            "enable",
+            "--view",
+            "KEYS_ONLY",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with(
+                "[dry-run] UpdateTable request has not been sent:\nUpdateTableInput {",
+            )
+            .and(predicate::str::contains("\"dummy\""))
+            .and(predicate::str::contains("KeysOnly")),
+        );
+
+    Ok(())
+}
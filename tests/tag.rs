@@ -0,0 +1,59 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_tag_add_malformed() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args(["-r", "local", "-t", "dummy", "tag", "add", "env=prod", "team"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid tag: 'team'. Tags should be given in the form of 'key=value', e.g. 'env=prod'.",
+        ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_table_with_malformed_tag() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "admin",
+            "create",
+            "table",
+            "table--test_create_table_with_malformed_tag",
+            "--keys",
+            "pk",
+            "--tag",
+            "=prod",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid tag: '=prod'."));
+
+    Ok(())
+}
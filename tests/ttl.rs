@@ -0,0 +1,83 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License").
+ * You may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+pub mod util;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_ttl() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args([
+            "-r",
+            "local",
+            "-t",
+            &table_name,
+            "ttl",
+            "--attribute",
+            "expiredAt",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "TTL has been enabled on the table '{}' with the attribute 'expiredAt'.",
+            table_name
+        )));
+
+    tm.command()?
+        .args(["-r", "local", "desc", &table_name])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ttl: ENABLED (expiredAt)"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ttl_disable_not_enabled() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tm = util::setup().await?;
+    let table_name = tm.create_temporary_table("pk", None).await?;
+
+    tm.command()?
+        .args(["-r", "local", "-t", &table_name, "ttl", "--disable"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "TTL is not enabled on the table '{}'.",
+            table_name
+        )));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ttl_without_attribute() -> Result<(), Box<dyn std::error::Error>> {
+    let tm = util::setup().await?;
+
+    tm.command()?
+        .args(["-r", "local", "-t", "dummy", "ttl"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+
+    Ok(())
+}